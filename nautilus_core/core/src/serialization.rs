@@ -13,7 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize, Serializer};
 
 /// Represents types which are serializable for JSON and `MsgPack` specifications.
 pub trait Serializable: Serialize + for<'de> Deserialize<'de> {
@@ -37,3 +39,21 @@ pub trait Serializable: Serialize + for<'de> Deserialize<'de> {
         rmp_serde::to_vec_named(self)
     }
 }
+
+/// Serializes an optional map with its entries ordered by key, so that the output is
+/// deterministic regardless of the iteration order of the map.
+///
+/// For use with `#[serde(serialize_with = "serialize_optional_sorted_map")]`.
+pub fn serialize_optional_sorted_map<S, K, V>(
+    map: &Option<HashMap<K, V>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Ord + Serialize,
+    V: Serialize,
+{
+    map.as_ref()
+        .map(|map| map.iter().collect::<BTreeMap<_, _>>())
+        .serialize(serializer)
+}
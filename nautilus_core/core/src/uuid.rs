@@ -86,18 +86,17 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        self.to_string().serialize(serializer)
     }
 }
 
 impl<'de> Deserialize<'de> for UUID4 {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let uuid4_str: &str = Deserialize::deserialize(_deserializer)?;
-        let uuid4: Self = uuid4_str.into();
-        Ok(uuid4)
+        let uuid4_str = String::deserialize(deserializer)?;
+        UUID4::from_str(&uuid4_str).map_err(serde::de::Error::custom)
     }
 }
 
@@ -199,6 +198,16 @@ mod tests {
         assert_eq!(result_string, uuid_string);
     }
 
+    #[test]
+    fn test_serde_json_round_trip() {
+        let uuid = UUID4::new();
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, format!("\"{uuid}\""));
+
+        let deserialized: UUID4 = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, uuid);
+    }
+
     #[test]
     fn test_c_api_uuid4_new() {
         let uuid = uuid4_new();
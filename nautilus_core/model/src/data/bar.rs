@@ -26,7 +26,8 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror;
 
 use crate::{
-    enums::{AggregationSource, BarAggregation, PriceType},
+    data::trade::TradeTick,
+    enums::{AggregationSource, AggressorSide, BarAggregation, PriceType},
     identifiers::instrument_id::InstrumentId,
     types::{price::Price, quantity::Quantity},
 };
@@ -224,6 +225,40 @@ impl Bar {
 
 impl Serializable for Bar {}
 
+/// Returns the order flow imbalance of each of the given `bars`, calculated as
+/// `(buy_volume - sell_volume) / total_volume` of the `trades` within the bar.
+///
+/// The `bars` and `trades` must both be sorted by `ts_event`. Each bar covers the trades
+/// after the previous bar's `ts_event` up to and including its own (the first bar covers
+/// all trades up to its `ts_event`), and any trades after the last bar are ignored.
+/// Trades without an aggressor side only add to the total volume, and a bar with zero
+/// volume has an imbalance of zero.
+#[must_use]
+pub fn bar_imbalances(bars: &[Bar], trades: &[TradeTick]) -> Vec<f64> {
+    let mut trades = trades.iter().peekable();
+    bars.iter()
+        .map(|bar| {
+            let mut buy_raw: u128 = 0;
+            let mut sell_raw: u128 = 0;
+            let mut total_raw: u128 = 0;
+            while let Some(trade) = trades.next_if(|trade| trade.ts_event <= bar.ts_event) {
+                let size_raw = u128::from(trade.size.raw);
+                match trade.aggressor_side {
+                    AggressorSide::Buyer => buy_raw += size_raw,
+                    AggressorSide::Seller => sell_raw += size_raw,
+                    AggressorSide::NoAggressor => {}
+                }
+                total_raw += size_raw;
+            }
+            if total_raw == 0 {
+                0.0
+            } else {
+                (buy_raw as f64 - sell_raw as f64) / total_raw as f64
+            }
+        })
+        .collect()
+}
+
 impl Display for Bar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -371,14 +406,11 @@ mod tests {
     use super::*;
     use crate::{
         enums::BarAggregation,
-        identifiers::{symbol::Symbol, venue::Venue},
+        identifiers::{symbol::Symbol, trade_id::TradeId, venue::Venue},
     };
 
     fn create_stub_bar() -> Bar {
-        let instrument_id = InstrumentId {
-            symbol: Symbol::new("AUDUSD"),
-            venue: Venue::new("SIM"),
-        };
+        let instrument_id = InstrumentId::new(Symbol::new("AUDUSD"), Venue::new("SIM"));
         let bar_spec = BarSpecification {
             step: 1,
             aggregation: BarAggregation::Minute,
@@ -498,14 +530,8 @@ mod tests {
 
     #[test]
     fn test_bar_type_equality() {
-        let instrument_id1 = InstrumentId {
-            symbol: Symbol::new("AUD/USD"),
-            venue: Venue::new("SIM"),
-        };
-        let instrument_id2 = InstrumentId {
-            symbol: Symbol::new("GBP/USD"),
-            venue: Venue::new("SIM"),
-        };
+        let instrument_id1 = InstrumentId::new(Symbol::new("AUD/USD"), Venue::new("SIM"));
+        let instrument_id2 = InstrumentId::new(Symbol::new("GBP/USD"), Venue::new("SIM"));
         let bar_spec = BarSpecification {
             step: 1,
             aggregation: BarAggregation::Minute,
@@ -533,15 +559,9 @@ mod tests {
 
     #[test]
     fn test_bar_type_comparison() {
-        let instrument_id1 = InstrumentId {
-            symbol: Symbol::new("AUD/USD"),
-            venue: Venue::new("SIM"),
-        };
+        let instrument_id1 = InstrumentId::new(Symbol::new("AUD/USD"), Venue::new("SIM"));
 
-        let instrument_id2 = InstrumentId {
-            symbol: Symbol::new("GBP/USD"),
-            venue: Venue::new("SIM"),
-        };
+        let instrument_id2 = InstrumentId::new(Symbol::new("GBP/USD"), Venue::new("SIM"));
         let bar_spec = BarSpecification {
             step: 1,
             aggregation: BarAggregation::Minute,
@@ -571,10 +591,7 @@ mod tests {
 
     #[test]
     fn test_bar_equality() {
-        let instrument_id = InstrumentId {
-            symbol: Symbol::new("AUDUSD"),
-            venue: Venue::new("SIM"),
-        };
+        let instrument_id = InstrumentId::new(Symbol::new("AUDUSD"), Venue::new("SIM"));
         let bar_spec = BarSpecification {
             step: 1,
             aggregation: BarAggregation::Minute,
@@ -651,4 +668,75 @@ mod tests {
         let deserialized = Bar::from_msgpack_bytes(serialized).unwrap();
         assert_eq!(deserialized, bar);
     }
+
+    fn create_stub_trade(
+        size: &str,
+        aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::new(Symbol::new("AUDUSD"), Venue::new("SIM")),
+            Price::from("1.00001"),
+            Quantity::from(size),
+            aggressor_side,
+            TradeId::new("1"),
+            ts_event,
+            ts_event,
+        )
+    }
+
+    fn create_stub_bar_at(ts_event: UnixNanos) -> Bar {
+        Bar {
+            ts_event,
+            ts_init: ts_event,
+            ..create_stub_bar()
+        }
+    }
+
+    #[test]
+    fn test_bar_imbalances() {
+        let bars = vec![create_stub_bar_at(60), create_stub_bar_at(120)];
+        let trades = vec![
+            create_stub_trade("300", AggressorSide::Buyer, 10),
+            create_stub_trade("100", AggressorSide::Seller, 60),
+            create_stub_trade("100", AggressorSide::Buyer, 70),
+            create_stub_trade("200", AggressorSide::Seller, 80),
+            create_stub_trade("100", AggressorSide::NoAggressor, 90),
+            create_stub_trade("500", AggressorSide::Buyer, 130),
+        ];
+
+        let imbalances = bar_imbalances(&bars, &trades);
+
+        assert_eq!(imbalances, vec![0.5, -0.25]);
+    }
+
+    #[test]
+    fn test_bar_imbalances_with_one_sided_flow() {
+        let bars = vec![create_stub_bar_at(60), create_stub_bar_at(120)];
+        let trades = vec![
+            create_stub_trade("100", AggressorSide::Buyer, 30),
+            create_stub_trade("100", AggressorSide::Seller, 90),
+        ];
+
+        let imbalances = bar_imbalances(&bars, &trades);
+
+        assert_eq!(imbalances, vec![1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_bar_imbalances_with_zero_volume_bar() {
+        let bars = vec![create_stub_bar_at(60), create_stub_bar_at(120)];
+        let trades = vec![create_stub_trade("100", AggressorSide::Buyer, 90)];
+
+        let imbalances = bar_imbalances(&bars, &trades);
+
+        assert_eq!(imbalances, vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_bar_imbalances_with_no_bars() {
+        let trades = vec![create_stub_trade("100", AggressorSide::Buyer, 90)];
+
+        assert!(bar_imbalances(&[], &trades).is_empty());
+    }
 }
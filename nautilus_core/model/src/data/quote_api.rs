@@ -61,10 +61,13 @@ pub extern "C" fn quote_tick_eq(lhs: &QuoteTick, rhs: &QuoteTick) -> u8 {
     assert_eq!(lhs.ts_event, rhs.ts_event);
     assert_eq!(lhs.ts_init, rhs.ts_init);
     assert_eq!(
-        lhs.instrument_id.symbol.value,
-        rhs.instrument_id.symbol.value
+        lhs.instrument_id.symbol().value,
+        rhs.instrument_id.symbol().value
+    );
+    assert_eq!(
+        lhs.instrument_id.venue().value,
+        rhs.instrument_id.venue().value
     );
-    assert_eq!(lhs.instrument_id.venue.value, rhs.instrument_id.venue.value);
     u8::from(lhs == rhs)
 }
 
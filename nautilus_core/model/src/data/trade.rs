@@ -19,11 +19,12 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use nautilus_core::{correctness, serialization::Serializable, time::UnixNanos};
 use pyo3::{exceptions::PyValueError, prelude::*, pyclass::CompareOp, types::PyDict};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    data::quote::QuoteTick,
     enums::AggressorSide,
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
     types::{price::Price, quantity::Quantity},
@@ -73,6 +74,45 @@ impl TradeTick {
         }
     }
 
+    /// Returns a synthetic quote approximated from the trade, with the bid and ask
+    /// separated by a spread of `spread_ticks` ticks of `price_increment` around the
+    /// trade price.
+    ///
+    /// The bid is set half the spread below the trade price (rounded down to a whole
+    /// tick), and the ask the remaining ticks above it, so both stay on the tick grid
+    /// of the trade price. Both sizes are set to the trade size.
+    ///
+    /// # Panics
+    ///
+    /// If `spread_ticks` is less than 2 (so the bid and ask cannot both differ from the
+    /// trade price), or `price_increment` is not positive.
+    #[must_use]
+    pub fn to_quote_approximation(&self, spread_ticks: i64, price_increment: Price) -> QuoteTick {
+        correctness::i64_in_range_inclusive(spread_ticks, 2, i64::MAX, "`spread_ticks`");
+        correctness::i64_in_range_inclusive(
+            price_increment.raw,
+            1,
+            i64::MAX,
+            "`price_increment` raw",
+        );
+
+        let bid_ticks = spread_ticks / 2;
+        let bid = self.price.add_ticks(-bid_ticks, price_increment);
+        let ask = self
+            .price
+            .add_ticks(spread_ticks - bid_ticks, price_increment);
+
+        QuoteTick::new(
+            self.instrument_id,
+            bid,
+            ask,
+            self.size,
+            self.size,
+            self.ts_event,
+            self.ts_init,
+        )
+    }
+
     pub fn get_metadata(
         instrument_id: &InstrumentId,
         price_precision: u8,
@@ -262,6 +302,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_quote_approximation() {
+        let tick = create_stub_trade_tick();
+
+        let quote = tick.to_quote_approximation(10, Price::from("0.0001"));
+
+        assert!(quote.bid < tick.price);
+        assert!(tick.price < quote.ask);
+        assert_eq!(quote.bid, Price::from("9999.9995"));
+        assert_eq!(quote.ask, Price::from("10000.0005"));
+        assert_eq!(quote.bid.precision, tick.price.precision);
+        assert_eq!(quote.bid_size, tick.size);
+        assert_eq!(quote.ask_size, tick.size);
+        assert_eq!(quote.instrument_id, tick.instrument_id);
+        assert_eq!(quote.ts_event, tick.ts_event);
+        assert_eq!(quote.ts_init, tick.ts_init);
+    }
+
+    #[test]
+    fn test_to_quote_approximation_with_odd_ticks_spread() {
+        let tick = create_stub_trade_tick();
+
+        let quote = tick.to_quote_approximation(3, Price::from("0.0001"));
+
+        assert_eq!(quote.bid, Price::from("9999.9999"));
+        assert_eq!(quote.ask, Price::from("10000.0002"));
+        assert_eq!(quote.ask.raw - quote.bid.raw, Price::from("0.0003").raw);
+    }
+
+    #[test]
+    fn test_to_quote_approximation_stays_on_tick_grid() {
+        let tick = create_stub_trade_tick();
+        let price_increment = Price::from("0.0005");
+
+        let quote = tick.to_quote_approximation(2, price_increment);
+
+        assert_eq!(quote.bid, Price::from("9999.9995"));
+        assert_eq!(quote.ask, Price::from("10000.0005"));
+        assert_eq!(quote.bid.raw % price_increment.raw, 0);
+        assert_eq!(quote.ask.raw % price_increment.raw, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "`spread_ticks`")]
+    fn test_to_quote_approximation_with_one_tick_spread() {
+        let tick = create_stub_trade_tick();
+        let _ = tick.to_quote_approximation(1, Price::from("0.0001"));
+    }
+
     #[test]
     fn test_to_string() {
         let tick = create_stub_trade_tick();
@@ -20,9 +20,9 @@ use std::{ffi::c_char, str::FromStr};
 use nautilus_core::string::{cstr_to_string, str_to_cstr};
 use pyo3::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use strum::{AsRefStr, Display, EnumString, FromRepr};
+use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
 
-use crate::strum_serde;
+use crate::{enum_for_python, instruments::Instrument, strum_serde};
 
 pub trait FromU8 {
     fn from_u8(value: u8) -> Option<Self>
@@ -85,6 +85,8 @@ pub enum AggregationSource {
 }
 
 /// The side for the aggressing order of a trade in a market.
+///
+/// The discriminant values are part of the C API and must not be changed.
 #[repr(C)]
 #[derive(
     Copy,
@@ -127,6 +129,7 @@ pub enum AggressorSide {
     AsRefStr,
     FromRepr,
     EnumString,
+    EnumIter,
 )]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
@@ -168,6 +171,7 @@ pub enum AssetClass {
     AsRefStr,
     FromRepr,
     EnumString,
+    EnumIter,
 )]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
@@ -341,6 +345,7 @@ impl FromU8 for BookType {
     Ord,
     AsRefStr,
     FromRepr,
+    EnumIter,
     EnumString,
 )]
 #[strum(ascii_case_insensitive)]
@@ -357,6 +362,22 @@ pub enum ContingencyType {
     Ouo = 3,
 }
 
+impl ContingencyType {
+    /// Returns whether a fill of an order cancels its linked (sibling) orders, which
+    /// is the case for `Oco` and `Ouo` contingencies.
+    #[must_use]
+    pub fn cancels_siblings_on_fill(self) -> bool {
+        matches!(self, ContingencyType::Oco | ContingencyType::Ouo)
+    }
+
+    /// Returns whether a fill of an order activates its linked (child) orders, which
+    /// is the case for an `Oto` contingency.
+    #[must_use]
+    pub fn activates_children_on_fill(self) -> bool {
+        matches!(self, ContingencyType::Oto)
+    }
+}
+
 /// The broad currency type.
 #[repr(C)]
 #[derive(
@@ -527,6 +548,8 @@ pub enum OptionKind {
 }
 
 /// The order side for a specific order, or action related to orders.
+///
+/// The discriminant values are part of the C API and must not be changed.
 #[repr(C)]
 #[derive(
     Copy,
@@ -587,6 +610,8 @@ impl FromU8 for OrderSide {
 ///  - `CANCELED`
 ///  - `EXPIRED`
 ///  - `FILLED`
+///
+/// The discriminant values are part of the C API and must not be changed.
 #[repr(C)]
 #[derive(
     Copy,
@@ -646,6 +671,7 @@ pub enum OrderStatus {
     Ord,
     AsRefStr,
     FromRepr,
+    EnumIter,
     EnumString,
 )]
 #[strum(ascii_case_insensitive)]
@@ -672,6 +698,35 @@ pub enum OrderType {
     TrailingStopLimit = 9,
 }
 
+impl OrderType {
+    /// Returns the names of the optional [`OrderInitialized`](crate::events::order::OrderInitialized)
+    /// fields which must be set to initialize an order of this type.
+    #[must_use]
+    pub fn required_fields(&self) -> &'static [&'static str] {
+        match self {
+            OrderType::Market | OrderType::MarketToLimit => &[],
+            OrderType::Limit => &["price"],
+            OrderType::StopMarket | OrderType::MarketIfTouched => {
+                &["trigger_price", "trigger_type"]
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched => {
+                &["price", "trigger_price", "trigger_type"]
+            }
+            OrderType::TrailingStopMarket => {
+                &["trigger_type", "trailing_offset", "trailing_offset_type"]
+            }
+            OrderType::TrailingStopLimit => &[
+                "price",
+                "trigger_price",
+                "trigger_type",
+                "limit_offset",
+                "trailing_offset",
+                "trailing_offset_type",
+            ],
+        }
+    }
+}
+
 /// The market side for a specific position, or action related to positions.
 #[repr(C)]
 #[derive(
@@ -734,6 +789,8 @@ pub enum PriceType {
 }
 
 /// The 'Time in Force' instruction for an order in the financial market.
+///
+/// The discriminant values are part of the C API and must not be changed.
 #[repr(C)]
 #[derive(
     Copy,
@@ -748,6 +805,7 @@ pub enum PriceType {
     AsRefStr,
     FromRepr,
     EnumString,
+    EnumIter,
 )]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
@@ -769,6 +827,16 @@ pub enum TimeInForce {
     AtTheClose = 7,
 }
 
+impl TimeInForce {
+    /// Returns whether the time in force is for a session auction (`AtTheOpen` or
+    /// `AtTheClose`), in which case the order is only filled or expired at the
+    /// auction time.
+    #[must_use]
+    pub fn is_auction(self) -> bool {
+        matches!(self, TimeInForce::AtTheOpen | TimeInForce::AtTheClose)
+    }
+}
+
 /// The trading state for a node.
 #[repr(C)]
 #[derive(
@@ -784,12 +852,14 @@ pub enum TimeInForce {
     AsRefStr,
     FromRepr,
     EnumString,
+    Default,
 )]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[pyclass]
 pub enum TradingState {
     /// Normal trading operations.
+    #[default]
     Active = 1,
     /// Trading is completely halted, no new order commands will be emitted.
     Halted = 2,
@@ -830,6 +900,8 @@ pub enum TrailingOffsetType {
 }
 
 /// The trigger type for the stop/trigger price of an order.
+///
+/// The discriminant values are part of the C API and must not be changed.
 #[repr(C)]
 #[derive(
     Copy,
@@ -843,6 +915,7 @@ pub enum TrailingOffsetType {
     Ord,
     AsRefStr,
     FromRepr,
+    EnumIter,
     EnumString,
 )]
 #[strum(ascii_case_insensitive)]
@@ -871,6 +944,34 @@ pub enum TriggerType {
     IndexPrice = 9,
 }
 
+impl TriggerType {
+    /// Returns the trigger type to use for the given `instrument` when an order
+    /// does not specify one (a trigger type of `Default` or `NoTrigger`).
+    ///
+    /// Crypto derivatives trigger from the mark price, FX from the quoted
+    /// bid/ask prices, and all other instruments from the last traded price.
+    #[must_use]
+    pub fn resolve_default(instrument: &dyn Instrument) -> TriggerType {
+        match (instrument.asset_class(), instrument.asset_type()) {
+            (AssetClass::Cryptocurrency, AssetType::Swap | AssetType::Future) => {
+                TriggerType::MarkPrice
+            }
+            (AssetClass::FX, _) => TriggerType::BidAsk,
+            _ => TriggerType::LastTrade,
+        }
+    }
+
+    /// Returns this trigger type, or the default for the given `instrument`
+    /// when this is `Default` or `NoTrigger`.
+    #[must_use]
+    pub fn resolve(self, instrument: &dyn Instrument) -> TriggerType {
+        match self {
+            TriggerType::Default | TriggerType::NoTrigger => Self::resolve_default(instrument),
+            _ => self,
+        }
+    }
+}
+
 strum_serde!(AccountType);
 strum_serde!(AggregationSource);
 strum_serde!(AggressorSide);
@@ -896,6 +997,10 @@ strum_serde!(TradingState);
 strum_serde!(TrailingOffsetType);
 strum_serde!(TriggerType);
 
+enum_for_python!(ContingencyType);
+enum_for_python!(OrderType);
+enum_for_python!(TriggerType);
+
 #[no_mangle]
 pub extern "C" fn account_type_to_cstr(value: AccountType) -> *const c_char {
     str_to_cstr(value.as_ref())
@@ -1282,3 +1387,242 @@ pub unsafe extern "C" fn trigger_type_from_cstr(ptr: *const c_char) -> TriggerTy
     TriggerType::from_str(&value)
         .unwrap_or_else(|_| panic!("invalid `TriggerType` enum string value, was '{value}'"))
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::{
+        events::order::OrderInitializedBuilder, orders::base::validate_initialized,
+        types::price::Price,
+    };
+
+    #[rstest(
+        value,
+        expected,
+        case(AssetClass::FX, "FX"),
+        case(AssetClass::Equity, "EQUITY"),
+        case(AssetClass::SportsBetting, "SPORTS_BETTING")
+    )]
+    fn test_asset_class_display(value: AssetClass, expected: &str) {
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[rstest(
+        value,
+        expected,
+        case(AssetType::Spot, "SPOT"),
+        case(AssetType::Future, "FUTURE"),
+        case(AssetType::Option, "OPTION")
+    )]
+    fn test_asset_type_display(value: AssetType, expected: &str) {
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[test]
+    fn test_asset_class_round_trip_all_variants() {
+        for value in AssetClass::iter() {
+            let s = value.to_string();
+            assert_eq!(AssetClass::from_str(&s).unwrap(), value);
+
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, format!("\"{s}\""));
+            assert_eq!(serde_json::from_str::<AssetClass>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_asset_type_round_trip_all_variants() {
+        for value in AssetType::iter() {
+            let s = value.to_string();
+            assert_eq!(AssetType::from_str(&s).unwrap(), value);
+
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, format!("\"{s}\""));
+            assert_eq!(serde_json::from_str::<AssetType>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_asset_type_from_str_is_case_insensitive() {
+        assert_eq!(AssetType::from_str("option").unwrap(), AssetType::Option);
+    }
+
+    #[test]
+    fn test_asset_type_deserialize_unknown_token() {
+        let result = serde_json::from_str::<AssetType>("\"PERPETUAL\"");
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("'PERPETUAL'"), "{error}");
+        assert!(error.contains("AssetType"), "{error}");
+    }
+
+    #[test]
+    fn test_asset_class_deserialize_unknown_token() {
+        let result = serde_json::from_str::<AssetClass>("\"CRYPTO\"");
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("'CRYPTO'"), "{error}");
+    }
+
+    #[test]
+    fn test_contingency_type_fill_behavior_all_variants() {
+        for value in ContingencyType::iter() {
+            let (cancels_siblings, activates_children) = match value {
+                ContingencyType::NoContingency => (false, false),
+                ContingencyType::Oco | ContingencyType::Ouo => (true, false),
+                ContingencyType::Oto => (false, true),
+            };
+
+            assert_eq!(
+                value.cancels_siblings_on_fill(),
+                cancels_siblings,
+                "{value}"
+            );
+            assert_eq!(
+                value.activates_children_on_fill(),
+                activates_children,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ffi_discriminant_values() {
+        // These values are relied upon by C API consumers, reordering or
+        // renumbering any variant breaks the ABI.
+        assert_eq!(AggressorSide::NoAggressor as u8, 0);
+        assert_eq!(AggressorSide::Buyer as u8, 1);
+        assert_eq!(AggressorSide::Seller as u8, 2);
+
+        assert_eq!(OrderSide::NoOrderSide as u8, 0);
+        assert_eq!(OrderSide::Buy as u8, 1);
+        assert_eq!(OrderSide::Sell as u8, 2);
+
+        assert_eq!(OrderStatus::Initialized as u8, 1);
+        assert_eq!(OrderStatus::Denied as u8, 2);
+        assert_eq!(OrderStatus::Submitted as u8, 3);
+        assert_eq!(OrderStatus::Accepted as u8, 4);
+        assert_eq!(OrderStatus::Rejected as u8, 5);
+        assert_eq!(OrderStatus::Canceled as u8, 6);
+        assert_eq!(OrderStatus::Expired as u8, 7);
+        assert_eq!(OrderStatus::Triggered as u8, 8);
+        assert_eq!(OrderStatus::PendingUpdate as u8, 9);
+        assert_eq!(OrderStatus::PendingCancel as u8, 10);
+        assert_eq!(OrderStatus::PartiallyFilled as u8, 11);
+        assert_eq!(OrderStatus::Filled as u8, 12);
+
+        assert_eq!(TimeInForce::Gtc as u8, 1);
+        assert_eq!(TimeInForce::Ioc as u8, 2);
+        assert_eq!(TimeInForce::Fok as u8, 3);
+        assert_eq!(TimeInForce::Gtd as u8, 4);
+        assert_eq!(TimeInForce::Day as u8, 5);
+        assert_eq!(TimeInForce::AtTheOpen as u8, 6);
+        assert_eq!(TimeInForce::AtTheClose as u8, 7);
+
+        assert_eq!(TriggerType::NoTrigger as u8, 0);
+        assert_eq!(TriggerType::Default as u8, 1);
+        assert_eq!(TriggerType::BidAsk as u8, 2);
+        assert_eq!(TriggerType::LastTrade as u8, 3);
+        assert_eq!(TriggerType::DoubleLast as u8, 4);
+        assert_eq!(TriggerType::DoubleBidAsk as u8, 5);
+        assert_eq!(TriggerType::LastOrBidAsk as u8, 6);
+        assert_eq!(TriggerType::MidPoint as u8, 7);
+        assert_eq!(TriggerType::MarkPrice as u8, 8);
+        assert_eq!(TriggerType::IndexPrice as u8, 9);
+    }
+
+    #[test]
+    fn test_ffi_enum_size_matches_c_enum() {
+        // The enums are `#[repr(C)]` so they match the `enum` declarations in the
+        // generated C header (and Cython `cpdef enum` definitions)
+        assert_eq!(
+            std::mem::size_of::<AggressorSide>(),
+            std::mem::size_of::<u32>()
+        );
+        assert_eq!(std::mem::size_of::<OrderSide>(), std::mem::size_of::<u32>());
+        assert_eq!(
+            std::mem::size_of::<OrderStatus>(),
+            std::mem::size_of::<u32>()
+        );
+        assert_eq!(
+            std::mem::size_of::<TimeInForce>(),
+            std::mem::size_of::<u32>()
+        );
+        assert_eq!(
+            std::mem::size_of::<TriggerType>(),
+            std::mem::size_of::<u32>()
+        );
+    }
+
+    #[test]
+    fn test_py_enums_from_str_and_value() {
+        assert_eq!(
+            TriggerType::py_from_str("BID_ASK").unwrap(),
+            TriggerType::BidAsk
+        );
+        assert_eq!(TriggerType::BidAsk.py_value(), 2);
+        assert_eq!(TriggerType::BidAsk.py_name(), "BID_ASK");
+        assert_eq!(
+            OrderType::py_from_str("stop_market").unwrap(),
+            OrderType::StopMarket
+        );
+        assert_eq!(OrderType::StopMarket.py_name(), "STOP_MARKET");
+        assert_eq!(
+            ContingencyType::py_from_str("OCO").unwrap(),
+            ContingencyType::Oco
+        );
+        assert_eq!(ContingencyType::Oco.py_value(), ContingencyType::Oco as u8);
+    }
+
+    #[test]
+    fn test_py_enums_round_trip_all_variants() {
+        for value in TriggerType::iter() {
+            assert_eq!(TriggerType::py_from_str(value.py_name()).unwrap(), value);
+        }
+        for value in OrderType::iter() {
+            assert_eq!(OrderType::py_from_str(value.py_name()).unwrap(), value);
+        }
+        for value in ContingencyType::iter() {
+            assert_eq!(
+                ContingencyType::py_from_str(value.py_name()).unwrap(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_stop_market_config_from_python_strings() {
+        // As passed by an emulator config from Python
+        let (order_type, trigger_type, emulation_trigger) =
+            ("STOP_MARKET", "LAST_TRADE", "BID_ASK");
+
+        let event = OrderInitializedBuilder::default()
+            .order_type(OrderType::py_from_str(order_type).unwrap())
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::py_from_str(trigger_type).unwrap()))
+            .emulation_trigger(Some(TriggerType::py_from_str(emulation_trigger).unwrap()))
+            .build()
+            .unwrap();
+
+        assert_eq!(event.order_type, OrderType::StopMarket);
+        assert_eq!(event.trigger_type, Some(TriggerType::LastTrade));
+        assert_eq!(event.emulation_trigger, Some(TriggerType::BidAsk));
+        assert!(validate_initialized(&event).is_ok());
+    }
+
+    #[test]
+    fn test_py_enum_from_str_with_unknown_token() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|_| {
+            let error = TriggerType::py_from_str("LAST_PRICE").unwrap_err();
+            assert!(error.to_string().contains("'LAST_PRICE'"));
+            assert!(error.to_string().contains("TriggerType"));
+        });
+    }
+}
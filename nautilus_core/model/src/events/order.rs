@@ -16,7 +16,10 @@
 use std::collections::HashMap;
 
 use derive_builder::{self, Builder};
-use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    datetime::unix_nanos_to_iso8601, serialization::serialize_optional_sorted_map, time::UnixNanos,
+    uuid::UUID4,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -33,7 +36,7 @@ use crate::{
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum OrderEvent {
     OrderInitialized(OrderInitialized),
     OrderDenied(OrderDenied),
@@ -43,6 +46,7 @@ pub enum OrderEvent {
     OrderCanceled(OrderCanceled),
     OrderExpired(OrderExpired),
     OrderTriggered(OrderTriggered),
+    OrderReleased(OrderReleased),
     OrderPendingUpdate(OrderPendingUpdate),
     OrderPendingCancel(OrderPendingCancel),
     OrderModifyRejected(OrderModifyRejected),
@@ -52,6 +56,202 @@ pub enum OrderEvent {
     OrderFilled(OrderFilled),
 }
 
+impl OrderEvent {
+    /// Returns the client order ID of the order the event relates to.
+    #[must_use]
+    pub fn client_order_id(&self) -> ClientOrderId {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.client_order_id,
+            OrderEvent::OrderDenied(event) => event.client_order_id,
+            OrderEvent::OrderSubmitted(event) => event.client_order_id,
+            OrderEvent::OrderAccepted(event) => event.client_order_id,
+            OrderEvent::OrderRejected(event) => event.client_order_id,
+            OrderEvent::OrderCanceled(event) => event.client_order_id,
+            OrderEvent::OrderExpired(event) => event.client_order_id,
+            OrderEvent::OrderTriggered(event) => event.client_order_id,
+            OrderEvent::OrderReleased(event) => event.client_order_id,
+            OrderEvent::OrderPendingUpdate(event) => event.client_order_id,
+            OrderEvent::OrderPendingCancel(event) => event.client_order_id,
+            OrderEvent::OrderModifyRejected(event) => event.client_order_id,
+            OrderEvent::OrderCancelRejected(event) => event.client_order_id,
+            OrderEvent::OrderUpdated(event) => event.client_order_id,
+            OrderEvent::OrderPartiallyFilled(event) => event.client_order_id,
+            OrderEvent::OrderFilled(event) => event.client_order_id,
+        }
+    }
+
+    /// Returns the unique identifier of the event.
+    #[must_use]
+    pub fn event_id(&self) -> UUID4 {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.event_id,
+            OrderEvent::OrderDenied(event) => event.event_id,
+            OrderEvent::OrderSubmitted(event) => event.event_id,
+            OrderEvent::OrderAccepted(event) => event.event_id,
+            OrderEvent::OrderRejected(event) => event.event_id,
+            OrderEvent::OrderCanceled(event) => event.event_id,
+            OrderEvent::OrderExpired(event) => event.event_id,
+            OrderEvent::OrderTriggered(event) => event.event_id,
+            OrderEvent::OrderReleased(event) => event.event_id,
+            OrderEvent::OrderPendingUpdate(event) => event.event_id,
+            OrderEvent::OrderPendingCancel(event) => event.event_id,
+            OrderEvent::OrderModifyRejected(event) => event.event_id,
+            OrderEvent::OrderCancelRejected(event) => event.event_id,
+            OrderEvent::OrderUpdated(event) => event.event_id,
+            OrderEvent::OrderPartiallyFilled(event) => event.event_id,
+            OrderEvent::OrderFilled(event) => event.event_id,
+        }
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) when the event occurred.
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.ts_event,
+            OrderEvent::OrderDenied(event) => event.ts_event,
+            OrderEvent::OrderSubmitted(event) => event.ts_event,
+            OrderEvent::OrderAccepted(event) => event.ts_event,
+            OrderEvent::OrderRejected(event) => event.ts_event,
+            OrderEvent::OrderCanceled(event) => event.ts_event,
+            OrderEvent::OrderExpired(event) => event.ts_event,
+            OrderEvent::OrderTriggered(event) => event.ts_event,
+            OrderEvent::OrderReleased(event) => event.ts_event,
+            OrderEvent::OrderPendingUpdate(event) => event.ts_event,
+            OrderEvent::OrderPendingCancel(event) => event.ts_event,
+            OrderEvent::OrderModifyRejected(event) => event.ts_event,
+            OrderEvent::OrderCancelRejected(event) => event.ts_event,
+            OrderEvent::OrderUpdated(event) => event.ts_event,
+            OrderEvent::OrderPartiallyFilled(event) => event.ts_event,
+            OrderEvent::OrderFilled(event) => event.ts_event,
+        }
+    }
+
+    /// Sets the UNIX timestamp (nanoseconds) when the event occurred.
+    pub fn set_ts_event(&mut self, ts_event: UnixNanos) {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.ts_event = ts_event,
+            OrderEvent::OrderDenied(event) => event.ts_event = ts_event,
+            OrderEvent::OrderSubmitted(event) => event.ts_event = ts_event,
+            OrderEvent::OrderAccepted(event) => event.ts_event = ts_event,
+            OrderEvent::OrderRejected(event) => event.ts_event = ts_event,
+            OrderEvent::OrderCanceled(event) => event.ts_event = ts_event,
+            OrderEvent::OrderExpired(event) => event.ts_event = ts_event,
+            OrderEvent::OrderTriggered(event) => event.ts_event = ts_event,
+            OrderEvent::OrderReleased(event) => event.ts_event = ts_event,
+            OrderEvent::OrderPendingUpdate(event) => event.ts_event = ts_event,
+            OrderEvent::OrderPendingCancel(event) => event.ts_event = ts_event,
+            OrderEvent::OrderModifyRejected(event) => event.ts_event = ts_event,
+            OrderEvent::OrderCancelRejected(event) => event.ts_event = ts_event,
+            OrderEvent::OrderUpdated(event) => event.ts_event = ts_event,
+            OrderEvent::OrderPartiallyFilled(event) => event.ts_event = ts_event,
+            OrderEvent::OrderFilled(event) => event.ts_event = ts_event,
+        }
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) when the event was initialized.
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.ts_init,
+            OrderEvent::OrderDenied(event) => event.ts_init,
+            OrderEvent::OrderSubmitted(event) => event.ts_init,
+            OrderEvent::OrderAccepted(event) => event.ts_init,
+            OrderEvent::OrderRejected(event) => event.ts_init,
+            OrderEvent::OrderCanceled(event) => event.ts_init,
+            OrderEvent::OrderExpired(event) => event.ts_init,
+            OrderEvent::OrderTriggered(event) => event.ts_init,
+            OrderEvent::OrderReleased(event) => event.ts_init,
+            OrderEvent::OrderPendingUpdate(event) => event.ts_init,
+            OrderEvent::OrderPendingCancel(event) => event.ts_init,
+            OrderEvent::OrderModifyRejected(event) => event.ts_init,
+            OrderEvent::OrderCancelRejected(event) => event.ts_init,
+            OrderEvent::OrderUpdated(event) => event.ts_init,
+            OrderEvent::OrderPartiallyFilled(event) => event.ts_init,
+            OrderEvent::OrderFilled(event) => event.ts_init,
+        }
+    }
+
+    /// Returns a human-readable single line summary of the event, for audit reports.
+    ///
+    /// For example `FILLED 50 @ 1.2345 via trade T-123 at 2024-01-01T00:00:01.000000000Z`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let detail = match self {
+            OrderEvent::OrderInitialized(event) => {
+                let mut detail = format!(
+                    "INITIALIZED {} {} {} {}",
+                    event.order_side, event.order_type, event.quantity, event.time_in_force
+                );
+                if let Some(price) = event.price {
+                    detail.push_str(&format!(" @ {price}"));
+                }
+                if let Some(trigger_price) = event.trigger_price {
+                    detail.push_str(&format!(" trigger {trigger_price}"));
+                }
+                detail
+            }
+            OrderEvent::OrderDenied(event) => format!("DENIED: {}", event.reason),
+            OrderEvent::OrderSubmitted(event) => format!("SUBMITTED to {}", event.account_id),
+            OrderEvent::OrderAccepted(event) => format!("ACCEPTED as {}", event.venue_order_id),
+            OrderEvent::OrderRejected(event) => format!("REJECTED: {}", event.reason),
+            OrderEvent::OrderCanceled(_) => "CANCELED".to_string(),
+            OrderEvent::OrderExpired(_) => "EXPIRED".to_string(),
+            OrderEvent::OrderTriggered(_) => "TRIGGERED".to_string(),
+            OrderEvent::OrderReleased(event) => format!("RELEASED @ {}", event.released_price),
+            OrderEvent::OrderPendingUpdate(_) => "PENDING_UPDATE".to_string(),
+            OrderEvent::OrderPendingCancel(_) => "PENDING_CANCEL".to_string(),
+            OrderEvent::OrderModifyRejected(event) => format!("MODIFY_REJECTED: {}", event.reason),
+            OrderEvent::OrderCancelRejected(event) => format!("CANCEL_REJECTED: {}", event.reason),
+            OrderEvent::OrderUpdated(event) => {
+                let mut detail = format!("UPDATED {}", event.quantity);
+                if let Some(price) = event.price {
+                    detail.push_str(&format!(" @ {price}"));
+                }
+                if let Some(trigger_price) = event.trigger_price {
+                    detail.push_str(&format!(" trigger {trigger_price}"));
+                }
+                detail
+            }
+            OrderEvent::OrderPartiallyFilled(event) => format!(
+                "PARTIALLY_FILLED {} @ {} via trade {}",
+                event.last_qty, event.last_px, event.trade_id
+            ),
+            OrderEvent::OrderFilled(event) => format!(
+                "FILLED {} @ {} via trade {}",
+                event.last_qty, event.last_px, event.trade_id
+            ),
+        };
+        format!("{detail} at {}", unix_nanos_to_iso8601(self.ts_event()))
+    }
+}
+
+/// Provides a compact binary (MessagePack) encoding of order events for the event log.
+///
+/// Each event struct is encoded as a map keyed by field name (in declaration order),
+/// rather than positionally, as the `type` tag on the event structs cannot be read
+/// back from a positional encoding. Keying by name also keeps existing logs readable
+/// as fields are added, provided existing fields are never renamed or removed.
+#[cfg(feature = "binary-events")]
+impl OrderEvent {
+    /// Returns the compact binary encoding of the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be encoded.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    /// Decodes an event from its compact binary encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid encoding of an event.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Eq, Debug, Builder, Serialize, Deserialize)]
 #[builder(default)]
@@ -82,6 +282,7 @@ pub struct OrderInitialized {
     pub linked_order_ids: Option<Vec<ClientOrderId>>,
     pub parent_order_id: Option<ClientOrderId>,
     pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    #[serde(serialize_with = "serialize_optional_sorted_map")]
     pub exec_algorithm_params: Option<HashMap<String, String>>,
     pub exec_spawn_id: Option<ClientOrderId>,
     pub tags: Option<String>,
@@ -229,6 +430,21 @@ pub struct OrderExpired {
     pub reconciliation: bool,
 }
 
+#[repr(C)]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[builder(default)]
+#[serde(tag = "type")]
+pub struct OrderReleased {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub released_price: Price,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
@@ -360,3 +576,188 @@ pub struct OrderFilled {
     pub ts_init: UnixNanos,
     pub reconciliation: bool,
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::USD;
+
+    #[test]
+    fn test_summary_for_fill() {
+        let event = OrderEvent::OrderFilled(OrderFilled {
+            trader_id: TraderId::default(),
+            strategy_id: StrategyId::default(),
+            instrument_id: InstrumentId::default(),
+            client_order_id: ClientOrderId::default(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new("T-123"),
+            position_id: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            last_qty: Quantity::from(50),
+            last_px: Price::from("1.2345"),
+            currency: *USD,
+            commission: Money::new(0.0, *USD),
+            liquidity_side: LiquiditySide::Maker,
+            event_id: UUID4::default(),
+            ts_event: 1_704_067_201_000_000_000,
+            ts_init: 1_704_067_201_000_000_000,
+            reconciliation: false,
+        });
+
+        assert_eq!(
+            event.summary(),
+            "FILLED 50 @ 1.2345 via trade T-123 at 2024-01-01T00:00:01.000000000Z"
+        );
+    }
+
+    #[test]
+    fn test_summary_for_cancel() {
+        let event = OrderEvent::OrderCanceled(
+            OrderCanceledBuilder::default()
+                .ts_event(1_704_067_201_500_000_000)
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            event.summary(),
+            "CANCELED at 2024-01-01T00:00:01.500000000Z"
+        );
+    }
+
+    #[test]
+    fn test_summary_for_initialized() {
+        let event = OrderEvent::OrderInitialized(
+            OrderInitializedBuilder::default()
+                .order_side(OrderSide::Sell)
+                .order_type(OrderType::Limit)
+                .quantity(Quantity::from(100))
+                .price(Some(Price::from("1.00100")))
+                .time_in_force(TimeInForce::Gtc)
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            event.summary(),
+            "INITIALIZED SELL LIMIT 100 GTC @ 1.00100 at 1970-01-01T00:00:00.000000000Z"
+        );
+    }
+
+    fn exec_algorithm_params(keys: impl Iterator<Item = usize>) -> HashMap<String, String> {
+        keys.map(|i| (format!("key_{i}"), format!("value_{i}")))
+            .collect()
+    }
+
+    #[test]
+    fn test_initialized_serialization_is_deterministic() {
+        let event = OrderInitializedBuilder::default().build().unwrap();
+        let serialize = |params: HashMap<String, String>| {
+            let event = OrderInitialized {
+                exec_algorithm_params: Some(params),
+                ..event.clone()
+            };
+            serde_json::to_vec(&event).unwrap()
+        };
+
+        // Separately constructed maps have their own random hash state
+        let first = serialize(exec_algorithm_params(0..32));
+        let second = serialize(exec_algorithm_params((0..32).rev()));
+
+        assert_eq!(first, second);
+        let json = String::from_utf8(first).unwrap();
+        assert!(json.find("\"key_0\"").unwrap() < json.find("\"key_1\"").unwrap());
+    }
+
+    #[test]
+    fn test_initialized_serde_round_trip_with_exec_algorithm_params() {
+        let event = OrderInitializedBuilder::default()
+            .exec_algorithm_params(Some(exec_algorithm_params(0..3)))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&event).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<OrderInitialized>(&json).unwrap(),
+            event
+        );
+    }
+
+    #[cfg(feature = "binary-events")]
+    fn order_filled() -> OrderFilled {
+        use crate::currencies::USD;
+
+        OrderFilled {
+            trader_id: TraderId::default(),
+            strategy_id: StrategyId::default(),
+            instrument_id: InstrumentId::default(),
+            client_order_id: ClientOrderId::default(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new("T-001"),
+            position_id: Some(PositionId::new("P-001")),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            last_qty: Quantity::from(100_000),
+            last_px: Price::from("1.00010"),
+            currency: *USD,
+            commission: Money::new(2.50, *USD),
+            liquidity_side: LiquiditySide::Maker,
+            event_id: UUID4::default(),
+            ts_event: 1_000,
+            ts_init: 1_001,
+            reconciliation: false,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "binary-events")]
+    fn test_to_bytes_round_trip() {
+        let events = vec![
+            OrderEvent::OrderInitialized(
+                OrderInitializedBuilder::default()
+                    .order_type(OrderType::Limit)
+                    .price(Some(Price::from("1.00000")))
+                    .build()
+                    .unwrap(),
+            ),
+            OrderEvent::OrderAccepted(OrderAcceptedBuilder::default().build().unwrap()),
+            OrderEvent::OrderPartiallyFilled(order_filled()),
+            OrderEvent::OrderFilled(order_filled()),
+        ];
+
+        for event in events {
+            let bytes = event.to_bytes().unwrap();
+
+            assert_eq!(OrderEvent::from_bytes(&bytes).unwrap(), event);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "binary-events")]
+    fn test_to_bytes_is_smaller_than_json() {
+        let event = OrderEvent::OrderFilled(order_filled());
+
+        let bytes = event.to_bytes().unwrap();
+        let json = serde_json::to_vec(&event).unwrap();
+
+        assert!(
+            bytes.len() < json.len(),
+            "binary was {} bytes, JSON was {} bytes",
+            bytes.len(),
+            json.len()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "binary-events")]
+    fn test_from_bytes_with_invalid_data() {
+        assert!(OrderEvent::from_bytes(&[0xc1]).is_err());
+    }
+}
@@ -17,12 +17,16 @@ use std::{
     ffi::{c_char, CStr},
     fmt::{Debug, Display, Formatter},
     hash::Hash,
+    str::FromStr,
 };
 
 use nautilus_core::correctness;
 use pyo3::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ustr::Ustr;
 
+use super::IdError;
+
 #[repr(C)]
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[pyclass]
@@ -31,6 +35,15 @@ pub struct ClientId {
 }
 
 impl ClientId {
+    /// Creates a new [`ClientId`] from the given value.
+    ///
+    /// This is permissive, as client IDs (such as for custom data clients) are not
+    /// always venue identifiers. Use [`ClientId::new_checked`] (or [`FromStr`]) to
+    /// restrict the value to venue-safe characters.
+    ///
+    /// # Panics
+    ///
+    /// If `s` is empty, all whitespace or contains a non-ASCII character.
     #[must_use]
     pub fn new(s: &str) -> Self {
         correctness::valid_string(s, "`ClientId` value");
@@ -39,6 +52,65 @@ impl ClientId {
             value: Ustr::from(s),
         }
     }
+
+    /// Creates a new [`ClientId`] from the given value, which must be non-empty and
+    /// contain only the venue-safe characters `[A-Z0-9._-]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdError`] if `s` is empty or contains any other character.
+    pub fn new_checked(s: &str) -> Result<Self, IdError> {
+        if s.is_empty() {
+            return Err(IdError::Empty("ClientId"));
+        }
+        let is_venue_safe =
+            |c: char| c.is_ascii_uppercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-');
+        if !s.chars().all(is_venue_safe) {
+            return Err(IdError::InvalidChar("ClientId", s.to_string()));
+        }
+
+        Ok(Self {
+            value: Ustr::from(s),
+        })
+    }
+}
+
+/// Parses a [`ClientId`] as by [`ClientId::new_checked`], returning an error rather than
+/// panicking for an invalid value.
+impl FromStr for ClientId {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new_checked(s)
+    }
+}
+
+impl Serialize for ClientId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+/// Deserializes as permissively as [`ClientId::new`] (rather than as by [`FromStr`]), so
+/// any client ID which can be constructed round trips.
+impl<'de> Deserialize<'de> for ClientId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value_str: &str = Deserialize::deserialize(deserializer)?;
+        if value_str.trim().is_empty() || !value_str.is_ascii() {
+            return Err(serde::de::Error::custom(format!(
+                "invalid `ClientId` value '{value_str}'"
+            )));
+        }
+        Ok(Self {
+            value: Ustr::from(value_str),
+        })
+    }
 }
 
 impl Debug for ClientId {
@@ -86,6 +158,117 @@ mod tests {
         assert_eq!(format!("{id}"), "BINANCE");
     }
 
+    #[test]
+    fn test_new_checked() {
+        let id = ClientId::new_checked("BINANCE-SPOT_2.0").unwrap();
+
+        assert_eq!(id.to_string(), "BINANCE-SPOT_2.0");
+    }
+
+    #[test]
+    fn test_new_checked_with_lowercase() {
+        assert_eq!(
+            ClientId::new_checked("Binance"),
+            Err(IdError::InvalidChar("ClientId", "Binance".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_new_checked_with_spaces_and_slashes() {
+        assert!(matches!(
+            ClientId::new_checked("BINANCE SPOT"),
+            Err(IdError::InvalidChar(..))
+        ));
+        assert!(matches!(
+            ClientId::new_checked("BINANCE/SPOT"),
+            Err(IdError::InvalidChar(..))
+        ));
+    }
+
+    #[test]
+    fn test_new_checked_with_empty_string() {
+        assert_eq!(ClientId::new_checked(""), Err(IdError::Empty("ClientId")));
+    }
+
+    #[test]
+    fn test_new_allows_mixed_case() {
+        let id = ClientId::new("NewsClient");
+
+        assert_eq!(id.to_string(), "NewsClient");
+        assert!(ClientId::new_checked("NewsClient").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid string for `ClientId` value, was empty")]
+    fn test_new_with_empty_string() {
+        let _ = ClientId::new("");
+    }
+
+    #[test]
+    fn test_client_id_new_c_allows_mixed_case() {
+        let s = std::ffi::CString::new("NewsClient").unwrap();
+        let id = unsafe { client_id_new(s.as_ptr()) };
+        assert_eq!(id.to_string(), "NewsClient");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let id = ClientId::from_str("BINANCE").unwrap();
+        let parsed: ClientId = "BINANCE".parse().unwrap();
+
+        assert_eq!(id, ClientId::new("BINANCE"));
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn test_from_str_with_empty_string() {
+        let result = ClientId::from_str("");
+
+        assert_eq!(result, Err(IdError::Empty("ClientId")));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid `ClientId` value, was empty"
+        );
+    }
+
+    #[test]
+    fn test_serde_json_round_trip() {
+        let id = ClientId::new("BINANCE");
+
+        let json = serde_json::to_string(&id).unwrap();
+        let deserialized: ClientId = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "\"BINANCE\"");
+        assert_eq!(deserialized, id);
+    }
+
+    #[test]
+    fn test_serde_json_round_trip_with_mixed_case() {
+        let id = ClientId::new("NewsClient");
+
+        let json = serde_json::to_string(&id).unwrap();
+        let deserialized: ClientId = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, id);
+    }
+
+    #[test]
+    fn test_serde_json_deserialize_invalid_value() {
+        assert!(serde_json::from_str::<ClientId>("\"\"").is_err());
+        assert!(serde_json::from_str::<ClientId>("\"   \"").is_err());
+    }
+
+    #[test]
+    fn test_msgpack_round_trip() {
+        let id = ClientId::new("BINANCE");
+
+        let bytes = rmp_serde::to_vec(&id).unwrap();
+        let deserialized: ClientId = rmp_serde::from_slice(&bytes).unwrap();
+
+        assert_eq!(bytes, rmp_serde::to_vec("BINANCE").unwrap());
+        assert_eq!(deserialized, id);
+    }
+
     #[test]
     fn test_client_id_to_cstr_c() {
         let id = ClientId::new("BINANCE");
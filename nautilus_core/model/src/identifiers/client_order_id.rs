@@ -23,6 +23,8 @@ use nautilus_core::correctness;
 use pyo3::prelude::*;
 use ustr::Ustr;
 
+use super::{strategy_id::StrategyId, trader_id::TraderId};
+
 #[repr(C)]
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[pyclass]
@@ -39,6 +41,39 @@ impl ClientOrderId {
             value: Ustr::from(s),
         }
     }
+
+    /// Generates a deterministic client order ID for the `seq` (sequence number) of
+    /// orders from the given trader and strategy, in the form
+    /// `O-{len}-{trader_id}-{len}-{strategy_id}-{seq}`.
+    ///
+    /// Trader and strategy IDs both contain '-', so each is prefixed with its length
+    /// to keep the encoding unambiguous. IDs generated for different traders or
+    /// strategies (or different sequence numbers) therefore never collide.
+    #[must_use]
+    pub fn generate(trader_id: TraderId, strategy_id: StrategyId, seq: u64) -> Self {
+        Self::new(&format!(
+            "{}{seq}",
+            Self::generated_prefix(trader_id, strategy_id)
+        ))
+    }
+
+    /// Returns the sequence number of an ID generated by [`ClientOrderId::generate`]
+    /// for the given trader and strategy, or `None` if it was not generated for them.
+    #[must_use]
+    pub fn generated_seq(&self, trader_id: TraderId, strategy_id: StrategyId) -> Option<u64> {
+        self.value
+            .strip_prefix(&Self::generated_prefix(trader_id, strategy_id))?
+            .parse()
+            .ok()
+    }
+
+    fn generated_prefix(trader_id: TraderId, strategy_id: StrategyId) -> String {
+        format!(
+            "O-{}-{trader_id}-{}-{strategy_id}-",
+            trader_id.value.len(),
+            strategy_id.value.len()
+        )
+    }
 }
 
 impl Default for ClientOrderId {
@@ -85,7 +120,9 @@ pub extern "C" fn client_order_id_hash(id: &ClientOrderId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::ClientOrderId;
+    use std::collections::HashSet;
+
+    use super::*;
 
     #[test]
     fn test_string_reprs() {
@@ -93,4 +130,55 @@ mod tests {
         assert_eq!(id.to_string(), "O-20200814-102234-001-001-1");
         assert_eq!(format!("{id}"), "O-20200814-102234-001-001-1");
     }
+
+    #[test]
+    fn test_generate() {
+        let id = ClientOrderId::generate(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 1);
+
+        assert_eq!(id, ClientOrderId::new("O-10-TRADER-001-5-S-001-1"));
+    }
+
+    #[test]
+    fn test_generate_with_ambiguous_trader_and_strategy_ids() {
+        let trader_id = TraderId::new("TRADER-001-S");
+        let strategy_id = StrategyId::new("001-X");
+        let other_trader_id = TraderId::new("TRADER-001");
+        let other_strategy_id = StrategyId::new("S-001-X");
+
+        let id = ClientOrderId::generate(trader_id, strategy_id, 1);
+        let other = ClientOrderId::generate(other_trader_id, other_strategy_id, 1);
+
+        assert_ne!(id, other);
+        assert_eq!(id.generated_seq(trader_id, strategy_id), Some(1));
+        assert_eq!(id.generated_seq(other_trader_id, other_strategy_id), None);
+        assert_eq!(other.generated_seq(trader_id, strategy_id), None);
+    }
+
+    #[test]
+    fn test_generate_is_unique_across_sequences() {
+        let trader_id = TraderId::new("TRADER-001");
+        let strategy_id = StrategyId::new("S-001");
+
+        let ids: HashSet<ClientOrderId> = (0..1_000)
+            .map(|seq| ClientOrderId::generate(trader_id, strategy_id, seq))
+            .collect();
+        let other = ClientOrderId::generate(trader_id, StrategyId::new("S-002"), 1);
+
+        assert_eq!(ids.len(), 1_000);
+        assert!(!ids.contains(&other));
+    }
+
+    #[test]
+    fn test_generated_seq() {
+        let trader_id = TraderId::new("TRADER-001");
+        let strategy_id = StrategyId::new("S-001");
+        let id = ClientOrderId::generate(trader_id, strategy_id, 42);
+
+        assert_eq!(id.generated_seq(trader_id, strategy_id), Some(42));
+        assert_eq!(id.generated_seq(trader_id, StrategyId::new("S-002")), None);
+        assert_eq!(
+            ClientOrderId::new("O-123456789").generated_seq(trader_id, strategy_id),
+            None
+        );
+    }
 }
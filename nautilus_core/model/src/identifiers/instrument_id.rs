@@ -14,7 +14,6 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    collections::hash_map::DefaultHasher,
     ffi::c_char,
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
@@ -25,15 +24,30 @@ use nautilus_core::string::{cstr_to_string, str_to_cstr};
 use pyo3::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror;
+use ustr::Ustr;
 
-use crate::identifiers::{symbol::Symbol, venue::Venue};
+use crate::{
+    identifiers::{symbol::Symbol, venue::Venue},
+    types::currency::Currency,
+};
 
 #[repr(C)]
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[pyclass]
 pub struct InstrumentId {
-    pub symbol: Symbol,
-    pub venue: Venue,
+    symbol: Symbol,
+    venue: Venue,
+    /// The cached `SYMBOL.VENUE` string, so that formatting the ID is a single borrow.
+    ///
+    /// This interns one additional string per distinct instrument ID (the symbol and venue are
+    /// already interned), which lives for the duration of the process. This is negligible for
+    /// typical instrument universes, though will grow with very high-cardinality sets of IDs
+    /// (such as many option chains).
+    ///
+    /// The fields are private so the value is always derived from the symbol and venue. Note
+    /// this field changes the FFI layout of `InstrumentId_t`, so C and Cython code must be
+    /// rebuilt against the regenerated headers.
+    value: Ustr,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -45,12 +59,55 @@ pub struct InstrumentIdParseError {
 impl InstrumentId {
     #[must_use]
     pub fn new(symbol: Symbol, venue: Venue) -> Self {
-        Self { symbol, venue }
+        Self::from_parts(symbol, venue)
+    }
+
+    /// Creates a new [`InstrumentId`] directly from its `symbol` and `venue`.
+    ///
+    /// The `SYMBOL.VENUE` value is built by concatenating the interned strings into a
+    /// buffer of the exact length, rather than through the formatting machinery.
+    #[must_use]
+    pub fn from_parts(symbol: Symbol, venue: Venue) -> Self {
+        let (symbol_str, venue_str) = (symbol.value.as_str(), venue.value.as_str());
+        let mut value = String::with_capacity(symbol_str.len() + 1 + venue_str.len());
+        value.push_str(symbol_str);
+        value.push('.');
+        value.push_str(venue_str);
+
+        Self {
+            symbol,
+            venue,
+            value: Ustr::from(&value),
+        }
+    }
+
+    /// Returns the symbol of the instrument ID.
+    #[must_use]
+    pub fn symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+
+    /// Returns the venue of the instrument ID.
+    #[must_use]
+    pub fn venue(&self) -> &Venue {
+        &self.venue
     }
 
     pub fn is_synthetic(&self) -> bool {
         self.venue.is_synthetic()
     }
+
+    /// Returns the base and quote currencies of an FX symbol of the form `BASE/QUOTE`
+    /// (such as `EUR/USD`), as found in the currency registry.
+    ///
+    /// Returns `None` if the symbol has no `/`, or either code is not a registered currency.
+    #[must_use]
+    pub fn fx_currencies(&self) -> Option<(Currency, Currency)> {
+        let (base, quote) = self.symbol.value.as_str().split_once('/')?;
+        let base = Currency::from_str(base).ok()?;
+        let quote = Currency::from_str(quote).ok()?;
+        Some((base, quote))
+    }
 }
 
 impl FromStr for InstrumentId {
@@ -61,6 +118,7 @@ impl FromStr for InstrumentId {
             Some((symbol_part, venue_part)) => Ok(Self {
                 symbol: Symbol::new(symbol_part),
                 venue: Venue::new(venue_part),
+                value: Ustr::from(s),
             }),
             None => Err(InstrumentIdParseError {
                 input: s.to_string(),
@@ -69,15 +127,29 @@ impl FromStr for InstrumentId {
     }
 }
 
+impl Default for InstrumentId {
+    fn default() -> Self {
+        Self::new(Symbol::default(), Venue::default())
+    }
+}
+
+/// Hashes the ID by the precomputed hash of its interned `value`, rather than hashing
+/// the symbol and venue components on every lookup.
+impl Hash for InstrumentId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
 impl Debug for InstrumentId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "\"{}.{}\"", self.symbol, self.venue)
+        write!(f, "\"{}\"", self.value)
     }
 }
 
 impl Display for InstrumentId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}.{}", self.symbol, self.venue)
+        f.write_str(self.value.as_str())
     }
 }
 
@@ -122,14 +194,12 @@ pub unsafe extern "C" fn instrument_id_new_from_cstr(ptr: *const c_char) -> Inst
 /// Returns an [`InstrumentId`] as a C string pointer.
 #[no_mangle]
 pub extern "C" fn instrument_id_to_cstr(instrument_id: &InstrumentId) -> *const c_char {
-    str_to_cstr(&instrument_id.to_string())
+    str_to_cstr(instrument_id.value.as_str())
 }
 
 #[no_mangle]
 pub extern "C" fn instrument_id_hash(instrument_id: &InstrumentId) -> u64 {
-    let mut h = DefaultHasher::new();
-    instrument_id.hash(&mut h);
-    h.finish()
+    instrument_id.value.precomputed_hash()
 }
 
 #[no_mangle]
@@ -142,22 +212,61 @@ pub extern "C" fn instrument_id_is_synthetic(instrument_id: &InstrumentId) -> u8
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{ffi::CStr, str::FromStr};
+    use std::{collections::HashMap, ffi::CStr, str::FromStr};
 
     use super::InstrumentId;
-    use crate::identifiers::{
-        instrument_id::{
-            instrument_id_new_from_cstr, instrument_id_to_cstr, InstrumentIdParseError,
+    use crate::{
+        currencies::{EUR, USD},
+        identifiers::{
+            instrument_id::{
+                instrument_id_hash, instrument_id_new_from_cstr, instrument_id_to_cstr,
+                InstrumentIdParseError,
+            },
+            symbol::Symbol,
+            venue::Venue,
         },
-        symbol::Symbol,
-        venue::Venue,
     };
 
     #[test]
     fn test_instrument_id_parse_success() {
         let instrument_id = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
-        assert_eq!(instrument_id.symbol.to_string(), "ETH/USDT");
-        assert_eq!(instrument_id.venue.to_string(), "BINANCE");
+        assert_eq!(instrument_id.symbol().to_string(), "ETH/USDT");
+        assert_eq!(instrument_id.venue().to_string(), "BINANCE");
+    }
+
+    #[test]
+    fn test_from_parts_equals_from_str() {
+        let from_parts = InstrumentId::from_parts(Symbol::new("ETH/USDT"), Venue::new("BINANCE"));
+        let from_str = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
+
+        assert_eq!(from_parts, from_str);
+        assert_eq!(from_parts.value, from_str.value);
+        assert_eq!(from_parts.to_string(), "ETH/USDT.BINANCE");
+
+        let mut hashes = HashMap::new();
+        hashes.insert(from_parts, 1);
+        assert_eq!(hashes.get(&from_str), Some(&1));
+    }
+
+    #[test]
+    fn test_fx_currencies() {
+        let instrument_id = InstrumentId::from_str("EUR/USD.SIM").unwrap();
+
+        assert_eq!(instrument_id.fx_currencies(), Some((*EUR, *USD)));
+    }
+
+    #[test]
+    fn test_fx_currencies_for_non_fx_symbol() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+
+        assert_eq!(instrument_id.fx_currencies(), None);
+    }
+
+    #[test]
+    fn test_fx_currencies_with_unknown_currency() {
+        let instrument_id = InstrumentId::from_str("EUR/XYZ.SIM").unwrap();
+
+        assert_eq!(instrument_id.fx_currencies(), None);
     }
 
     #[test]
@@ -187,6 +296,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_display_equals_from_str_input() {
+        let input = "ETH/USDT.BINANCE";
+        let id = InstrumentId::from_str(input).unwrap();
+        assert_eq!(id.to_string(), input);
+        assert_eq!(id.value.as_str(), input);
+    }
+
+    #[test]
+    fn test_new_caches_value() {
+        let id = InstrumentId::new(Symbol::new("ETH/USDT"), Venue::new("BINANCE"));
+        assert_eq!(id.value.as_str(), "ETH/USDT.BINANCE");
+        assert_eq!(id, InstrumentId::from_str("ETH/USDT.BINANCE").unwrap());
+    }
+
+    #[test]
+    fn test_default_caches_value() {
+        let id = InstrumentId::default();
+        assert_eq!(id.to_string(), format!("{}.{}", id.symbol(), id.venue()));
+    }
+
+    #[test]
+    fn test_instrument_id_hash() {
+        let id1 = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
+        let id2 = InstrumentId::new(Symbol::new("ETH/USDT"), Venue::new("BINANCE"));
+        let id3 = InstrumentId::from_str("BTC/USDT.BINANCE").unwrap();
+
+        assert_eq!(instrument_id_hash(&id1), instrument_id_hash(&id2));
+        assert_ne!(instrument_id_hash(&id1), instrument_id_hash(&id3));
+    }
+
+    #[test]
+    fn test_equal_ids_are_equal_map_keys() {
+        let id1 = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
+        let id2 = InstrumentId::new(Symbol::new("ETH/USDT"), Venue::new("BINANCE"));
+
+        let mut map = HashMap::new();
+        map.insert(id1, 1);
+
+        assert_eq!(map.get(&id2), Some(&1));
+    }
+
     #[test]
     fn test_string_reprs() {
         let id = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
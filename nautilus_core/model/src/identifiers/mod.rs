@@ -20,6 +20,15 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[macro_use]
 mod macros;
 
+/// Represents an error constructing an identifier from an invalid value.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum IdError {
+    #[error("Invalid `{0}` value, was empty")]
+    Empty(&'static str),
+    #[error("Invalid `{0}` value '{1}', contained a character outside [A-Z0-9._-]")]
+    InvalidChar(&'static str, String),
+}
+
 pub mod account_id;
 pub mod client_id;
 pub mod client_order_id;
@@ -36,7 +45,6 @@ pub mod venue;
 pub mod venue_order_id;
 
 impl_from_str_for_identifier!(account_id::AccountId);
-impl_from_str_for_identifier!(client_id::ClientId);
 impl_from_str_for_identifier!(client_order_id::ClientOrderId);
 impl_from_str_for_identifier!(component_id::ComponentId);
 impl_from_str_for_identifier!(exec_algorithm_id::ExecAlgorithmId);
@@ -50,7 +58,6 @@ impl_from_str_for_identifier!(venue::Venue);
 impl_from_str_for_identifier!(venue_order_id::VenueOrderId);
 
 impl_serialization_for_identifier!(account_id::AccountId);
-impl_serialization_for_identifier!(client_id::ClientId);
 impl_serialization_for_identifier!(client_order_id::ClientOrderId);
 impl_serialization_for_identifier!(component_id::ComponentId);
 impl_serialization_for_identifier!(exec_algorithm_id::ExecAlgorithmId);
@@ -15,7 +15,10 @@
 
 #![allow(dead_code)] // Allow for development
 
-use std::hash::{Hash, Hasher};
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
 
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
@@ -24,7 +27,7 @@ use serde::{Deserialize, Serialize};
 
 use super::Instrument;
 use crate::{
-    enums::{AssetClass, AssetType},
+    enums::{AssetClass, AssetType, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -51,6 +54,8 @@ pub struct CryptoFuture {
     pub margin_maint: Decimal,
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
+    #[serde(default)]
+    pub trading_state: TradingState,
 }
 
 impl CryptoFuture {
@@ -95,6 +100,7 @@ impl CryptoFuture {
             margin_maint,
             maker_fee,
             taker_fee,
+            trading_state: TradingState::Active,
         }
     }
 }
@@ -201,4 +207,16 @@ impl Instrument for CryptoFuture {
     fn taker_fee(&self) -> Decimal {
         self.taker_fee
     }
+
+    fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
@@ -15,15 +15,20 @@
 
 #![allow(dead_code)] // Allow for development
 
-use std::hash::{Hash, Hasher};
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use super::Instrument;
+use super::{default_multiplier, Instrument};
 use crate::{
-    enums::{AssetClass, AssetType},
+    currencies::{BTC, USDT},
+    enums::{AssetClass, AssetType, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -37,10 +42,14 @@ pub struct CryptoPerpetual {
     pub quote_currency: Currency,
     pub base_currency: Currency,
     pub settlement_currency: Currency,
+    #[serde(default)]
+    pub is_inverse: bool,
     pub price_precision: u8,
     pub size_precision: u8,
     pub price_increment: Price,
     pub size_increment: Quantity,
+    #[serde(default = "default_multiplier")]
+    pub multiplier: Quantity,
     pub lot_size: Option<Quantity>,
     pub max_quantity: Option<Quantity>,
     pub min_quantity: Option<Quantity>,
@@ -50,6 +59,8 @@ pub struct CryptoPerpetual {
     pub margin_maint: Decimal,
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
+    #[serde(default)]
+    pub trading_state: TradingState,
 }
 
 impl CryptoPerpetual {
@@ -74,6 +85,8 @@ impl CryptoPerpetual {
         margin_maint: Decimal,
         maker_fee: Decimal,
         taker_fee: Decimal,
+        is_inverse: bool,
+        multiplier: Quantity,
     ) -> Self {
         Self {
             id,
@@ -81,10 +94,12 @@ impl CryptoPerpetual {
             base_currency,
             quote_currency,
             settlement_currency,
+            is_inverse,
             price_precision,
             size_precision,
             price_increment,
             size_increment,
+            multiplier,
             lot_size,
             max_quantity,
             min_quantity,
@@ -94,10 +109,39 @@ impl CryptoPerpetual {
             margin_maint,
             maker_fee,
             taker_fee,
+            trading_state: TradingState::Active,
         }
     }
 }
 
+/// Provides a default linear [`CryptoPerpetual`] used for testing.
+impl Default for CryptoPerpetual {
+    fn default() -> Self {
+        CryptoPerpetual::new(
+            InstrumentId::from_str("BTCUSDT-PERP.BINANCE").unwrap(),
+            Symbol::new("BTCUSDT"),
+            *BTC,
+            *USDT,
+            *USDT,
+            1,
+            3,
+            Price::from("0.1"),
+            Quantity::from("0.001"),
+            None,
+            Some(Quantity::from("1000.000")),
+            Some(Quantity::from("0.001")),
+            Some(Price::from("1000000.0")),
+            Some(Price::from("0.1")),
+            Decimal::new(1, 2),
+            Decimal::new(35, 4),
+            Decimal::new(2, 4),
+            Decimal::new(4, 4),
+            false,
+            Quantity::from(1),
+        )
+    }
+}
+
 impl PartialEq<Self> for CryptoPerpetual {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -134,7 +178,7 @@ impl Instrument for CryptoPerpetual {
     }
 
     fn base_currency(&self) -> Option<&Currency> {
-        None
+        Some(&self.base_currency)
     }
 
     fn settlement_currency(&self) -> &Currency {
@@ -142,7 +186,7 @@ impl Instrument for CryptoPerpetual {
     }
 
     fn is_inverse(&self) -> bool {
-        false
+        self.is_inverse
     }
 
     fn price_precision(&self) -> u8 {
@@ -162,7 +206,7 @@ impl Instrument for CryptoPerpetual {
     }
 
     fn multiplier(&self) -> Quantity {
-        Quantity::new(1.0, 0)
+        self.multiplier
     }
 
     fn lot_size(&self) -> Option<Quantity> {
@@ -200,4 +244,12 @@ impl Instrument for CryptoPerpetual {
     fn taker_fee(&self) -> Decimal {
         self.taker_fee
     }
+
+    fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
@@ -15,7 +15,10 @@
 
 #![allow(dead_code)] // Allow for development
 
-use std::hash::{Hash, Hasher};
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
 
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
@@ -23,7 +26,7 @@ use serde::{Deserialize, Serialize};
 
 use super::Instrument;
 use crate::{
-    enums::{AssetClass, AssetType},
+    enums::{AssetClass, AssetType, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -49,6 +52,8 @@ pub struct CurrencyPair {
     pub margin_maint: Decimal,
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
+    #[serde(default)]
+    pub trading_state: TradingState,
 }
 
 impl CurrencyPair {
@@ -91,6 +96,7 @@ impl CurrencyPair {
             margin_maint,
             maker_fee,
             taker_fee,
+            trading_state: TradingState::Active,
         }
     }
 }
@@ -197,4 +203,12 @@ impl Instrument for CurrencyPair {
     fn taker_fee(&self) -> Decimal {
         self.taker_fee
     }
+
+    fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
@@ -15,7 +15,11 @@
 
 #![allow(dead_code)] // Allow for development
 
-use std::hash::{Hash, Hasher};
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
@@ -23,7 +27,8 @@ use serde::{Deserialize, Serialize};
 
 use super::Instrument;
 use crate::{
-    enums::{AssetClass, AssetType},
+    currencies::USD,
+    enums::{AssetClass, AssetType, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -49,6 +54,8 @@ pub struct Equity {
     pub margin_maint: Decimal,
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
+    #[serde(default)]
+    pub trading_state: TradingState,
 }
 
 impl Equity {
@@ -89,10 +96,35 @@ impl Equity {
             margin_maint,
             maker_fee,
             taker_fee,
+            trading_state: TradingState::Active,
         }
     }
 }
 
+/// Provides a default [`Equity`] used for testing.
+impl Default for Equity {
+    fn default() -> Self {
+        Equity::new(
+            InstrumentId::from_str("AAPL.XNAS").unwrap(),
+            Symbol::new("AAPL"),
+            String::from("US0378331005"),
+            *USD,
+            2,
+            Price::from("0.01"),
+            Quantity::from(1),
+            Some(Quantity::from(100)),
+            None,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )
+    }
+}
+
 impl PartialEq<Self> for Equity {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -195,4 +227,12 @@ impl Instrument for Equity {
     fn taker_fee(&self) -> Decimal {
         self.taker_fee
     }
+
+    fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
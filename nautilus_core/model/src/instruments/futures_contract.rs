@@ -15,16 +15,21 @@
 
 #![allow(dead_code)] // Allow for development
 
-use std::hash::{Hash, Hasher};
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use super::Instrument;
+use super::{default_multiplier, Instrument};
 use crate::{
-    enums::{AssetClass, AssetType},
+    currencies::USD,
+    enums::{AssetClass, AssetType, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -41,6 +46,8 @@ pub struct FuturesContract {
     pub currency: Currency,
     pub price_precision: u8,
     pub price_increment: Price,
+    #[serde(default = "default_multiplier")]
+    pub multiplier: Quantity,
     pub lot_size: Option<Quantity>,
     pub max_quantity: Option<Quantity>,
     pub min_quantity: Option<Quantity>,
@@ -50,6 +57,8 @@ pub struct FuturesContract {
     pub margin_maint: Decimal,
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
+    #[serde(default)]
+    pub trading_state: TradingState,
 }
 
 impl FuturesContract {
@@ -73,6 +82,7 @@ impl FuturesContract {
         margin_maint: Decimal,
         maker_fee: Decimal,
         taker_fee: Decimal,
+        multiplier: Quantity,
     ) -> Self {
         Self {
             id,
@@ -83,6 +93,7 @@ impl FuturesContract {
             currency,
             price_precision,
             price_increment,
+            multiplier,
             lot_size,
             max_quantity,
             min_quantity,
@@ -92,10 +103,37 @@ impl FuturesContract {
             margin_maint,
             maker_fee,
             taker_fee,
+            trading_state: TradingState::Active,
         }
     }
 }
 
+/// Provides a default [`FuturesContract`] used for testing.
+impl Default for FuturesContract {
+    fn default() -> Self {
+        FuturesContract::new(
+            InstrumentId::from_str("ESZ21.GLBX").unwrap(),
+            Symbol::new("ESZ21"),
+            AssetClass::Index,
+            String::from("ES"),
+            1_639_699_200_000_000_000,
+            *USD,
+            2,
+            Price::from("0.25"),
+            Some(Quantity::from(1)),
+            None,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Quantity::from(50),
+        )
+    }
+}
+
 impl PartialEq<Self> for FuturesContract {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -160,7 +198,7 @@ impl Instrument for FuturesContract {
     }
 
     fn multiplier(&self) -> Quantity {
-        Quantity::new(1.0, 0)
+        self.multiplier
     }
 
     fn lot_size(&self) -> Option<Quantity> {
@@ -198,4 +236,16 @@ impl Instrument for FuturesContract {
     fn taker_fee(&self) -> Decimal {
         self.taker_fee
     }
+
+    fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
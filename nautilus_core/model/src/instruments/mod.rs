@@ -13,30 +13,64 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-mod crypto_future;
-mod crypto_perpetual;
-mod currency_pair;
-mod equity;
-mod futures_contract;
-mod options_contract;
-mod synthetic;
-mod synthetic_api;
+pub mod crypto_future;
+pub mod crypto_perpetual;
+pub mod currency_pair;
+pub mod equity;
+pub mod futures_contract;
+pub mod options_contract;
+pub mod synthetic;
+pub mod synthetic_api;
 
+use std::any::Any;
+
+use nautilus_core::time::UnixNanos;
 use rust_decimal::Decimal;
+use thiserror;
 
 use crate::{
-    enums::{AssetClass, AssetType},
+    enums::{AssetClass, AssetType, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
-    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency,
+        fixed::{FIXED_PRECISION, FIXED_SCALAR},
+        money::Money,
+        price::Price,
+        quantity::Quantity,
+    },
 };
 
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum InstrumentError {
+    #[error("Quantity {0} is below the minimum {1}")]
+    QuantityBelowMin(Quantity, Quantity),
+    #[error("Quantity {0} is above the maximum {1}")]
+    QuantityAboveMax(Quantity, Quantity),
+    #[error("Quantity {0} is not a multiple of the size increment {1}")]
+    QuantityOffIncrement(Quantity, Quantity),
+    #[error("Price {0} is below the minimum {1}")]
+    PriceBelowMin(Price, Price),
+    #[error("Price {0} is above the maximum {1}")]
+    PriceAboveMax(Price, Price),
+    #[error("Price {0} is not a multiple of the price increment {1}")]
+    PriceOffIncrement(Price, Price),
+    #[error("Price is required for inverse instrument {0}")]
+    MissingInversePrice(InstrumentId),
+}
+
+/// Returns the contract multiplier of 1, the serde default for instruments serialized
+/// before the multiplier was a field.
+pub(crate) fn default_multiplier() -> Quantity {
+    Quantity::from(1)
+}
+
 pub trait Instrument {
     fn id(&self) -> &InstrumentId;
     fn symbol(&self) -> &Symbol {
-        &self.id().symbol
+        self.id().symbol()
     }
     fn venue(&self) -> &Venue {
-        &self.id().venue
+        self.id().venue()
     }
     fn raw_symbol(&self) -> &Symbol;
     fn asset_class(&self) -> AssetClass;
@@ -59,6 +93,40 @@ pub trait Instrument {
     fn margin_maint(&self) -> Decimal;
     fn maker_fee(&self) -> Decimal;
     fn taker_fee(&self) -> Decimal;
+    /// Returns the trading state of the instrument, which is `Active` unless it is halted
+    /// or only reducing.
+    fn trading_state(&self) -> TradingState {
+        TradingState::Active
+    }
+    /// Returns the UNIX timestamp (nanoseconds) from which the instrument can be traded, if any.
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+    /// Returns the UNIX timestamp (nanoseconds) at which the instrument expires, if any.
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+    /// Returns the instrument as [`Any`], so a `dyn Instrument` can be downcast to its
+    /// concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns whether the instrument can be traded at `now`: its trading state is not
+    /// `Halted`, and `now` is from its activation (if any, inclusive) until its
+    /// expiration (if any, exclusive).
+    ///
+    /// An instrument in the `Reducing` state is still tradable, for orders which reduce
+    /// positions.
+    fn is_tradable_now(&self, now: UnixNanos) -> bool {
+        let is_activated = match self.activation_ns() {
+            Some(activation) => now >= activation,
+            None => true,
+        };
+        let is_expired = match self.expiration_ns() {
+            Some(expiration) => now >= expiration,
+            None => false,
+        };
+        self.trading_state() != TradingState::Halted && is_activated && !is_expired
+    }
 
     /// Creates a new price from the given `value` with the correct price precision for the instrument.
     fn make_price(&self, value: f64) -> Price {
@@ -104,9 +172,780 @@ pub trait Instrument {
         Money::new(amount, currency)
     }
 
-    /// Returns the equivalent quantity of the base asset.
+    /// Calculates the notional value of the given `quantity` at `price`, as by
+    /// [`Instrument::calculate_notional_value`] without `use_quote_for_inverse`.
+    ///
+    /// For linear instruments this is `quantity * multiplier * price` in the quote
+    /// currency, and for inverse instruments `quantity * multiplier / price` in the base
+    /// currency (for typical instruments, the settlement currency in either case).
+    ///
+    /// # Panics
+    ///
+    /// If instrument is inverse with no base currency.
+    fn notional_value(&self, quantity: Quantity, price: Price) -> Money {
+        self.calculate_notional_value(quantity, price, None)
+    }
+
+    /// Validates the order `quantity` and optional `price` against the limits of the
+    /// instrument, so an invalid order can be rejected before it is sent to a venue.
+    ///
+    /// Each must be within the configured (inclusive) minimum and maximum, if any, and
+    /// a multiple of the size or price increment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InstrumentError`] for the first limit which is not met.
+    fn validate_order(
+        &self,
+        quantity: Quantity,
+        price: Option<Price>,
+    ) -> Result<(), InstrumentError> {
+        if let Some(min_quantity) = self.min_quantity() {
+            if quantity < min_quantity {
+                return Err(InstrumentError::QuantityBelowMin(quantity, min_quantity));
+            }
+        }
+        if let Some(max_quantity) = self.max_quantity() {
+            if quantity > max_quantity {
+                return Err(InstrumentError::QuantityAboveMax(quantity, max_quantity));
+            }
+        }
+        let size_increment = self.size_increment();
+        let remainder = quantity.raw.checked_rem(size_increment.raw);
+        if remainder.is_some_and(|remainder| remainder != 0) {
+            return Err(InstrumentError::QuantityOffIncrement(
+                quantity,
+                size_increment,
+            ));
+        }
+
+        let Some(price) = price else {
+            return Ok(());
+        };
+        if let Some(min_price) = self.min_price() {
+            if price < min_price {
+                return Err(InstrumentError::PriceBelowMin(price, min_price));
+            }
+        }
+        if let Some(max_price) = self.max_price() {
+            if price > max_price {
+                return Err(InstrumentError::PriceAboveMax(price, max_price));
+            }
+        }
+        let price_increment = self.price_increment();
+        let remainder = price.raw.checked_rem(price_increment.raw);
+        if remainder.is_some_and(|remainder| remainder != 0) {
+            return Err(InstrumentError::PriceOffIncrement(price, price_increment));
+        }
+        Ok(())
+    }
+
+    /// Creates a new commission from the given `amount` and `currency`.
+    ///
+    /// Fills may report a commission amount without a currency, in which case the
+    /// commission defaults to the settlement currency of the instrument.
+    fn make_commission(&self, amount: f64, currency: Option<Currency>) -> Money {
+        Money::new(
+            amount,
+            currency.unwrap_or_else(|| *self.settlement_currency()),
+        )
+    }
+
+    /// Calculates the value of a move of one price increment (tick) for the given
+    /// `quantity`, in the settlement currency of the instrument.
+    ///
+    /// For linear instruments this is `price_increment * quantity * multiplier`. For
+    /// inverse instruments the value of a tick depends on the price level, so the
+    /// `price` the move is from must be given, and the value is
+    /// `quantity * multiplier * (1 / price - 1 / (price + price_increment))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InstrumentError::MissingInversePrice`] if the instrument is inverse
+    /// and no `price` is given.
+    fn tick_value(
+        &self,
+        quantity: Quantity,
+        price: Option<Price>,
+    ) -> Result<Money, InstrumentError> {
+        let tick = self.price_increment().as_f64();
+        let size = quantity.as_f64() * self.multiplier().as_f64();
+        let amount = if self.is_inverse() {
+            let price = price
+                .ok_or(InstrumentError::MissingInversePrice(*self.id()))?
+                .as_f64();
+            size * (1.0 / price - 1.0 / (price + tick))
+        } else {
+            size * tick
+        };
+
+        Ok(Money::new(amount, *self.settlement_currency()))
+    }
+
+    /// Returns the equivalent quantity of the base asset for the quote-denominated
+    /// `quantity` at `last_px`, as `quantity / last_px`.
+    ///
+    /// The result is rounded (half up) to the nearest multiple of the size increment,
+    /// at the size precision of the instrument, so it can be submitted as an order
+    /// quantity.
+    ///
+    /// # Panics
+    ///
+    /// If `last_px` is not positive.
     fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
-        let value = quantity.as_f64() * (1.0 / last_px.as_f64());
-        Quantity::new(value, self.size_precision())
+        assert!(last_px.raw > 0, "invalid `last_px`, was {last_px}");
+
+        let size_precision = self.size_precision();
+        let precision_step = 10_u128.pow(u32::from(FIXED_PRECISION - size_precision));
+        let step = u128::from(self.size_increment().raw).max(precision_step);
+        let base_raw = u128::from(quantity.raw) * FIXED_SCALAR as u128 / last_px.raw as u128;
+        let raw = (base_raw + step / 2) / step * step;
+        Quantity::from_raw(
+            u64::try_from(raw).expect("Error: base quantity overflowed"),
+            size_precision,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{
+        crypto_perpetual::CryptoPerpetual, equity::Equity, futures_contract::FuturesContract,
+        options_contract::OptionsContract, Instrument, InstrumentError,
+    };
+    use crate::{
+        currencies::{BNB, BTC, USD, USDT},
+        enums::{AssetType, OptionKind, TradingState, TriggerType},
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+
+    /// Returns an inverse (coin-margined) perpetual, settled in the base currency.
+    fn inverse_perpetual() -> CryptoPerpetual {
+        CryptoPerpetual {
+            quote_currency: *USD,
+            settlement_currency: *BTC,
+            is_inverse: true,
+            price_increment: Price::from("0.5"),
+            size_precision: 0,
+            size_increment: Quantity::from(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_make_commission_with_explicit_currency() {
+        let instrument = CryptoPerpetual::default();
+
+        let commission = instrument.make_commission(0.5, Some(*BNB));
+
+        assert_eq!(commission, Money::new(0.5, *BNB));
+    }
+
+    #[test]
+    fn test_make_commission_defaults_to_settlement_currency() {
+        let instrument = CryptoPerpetual::default();
+
+        let commission = instrument.make_commission(1.25, None);
+
+        assert_eq!(commission, Money::new(1.25, *USDT));
+        assert_eq!(commission.currency, *USDT);
+    }
+
+    #[test]
+    fn test_trigger_type_resolve_default_for_equity() {
+        let instrument = Equity::default();
+
+        assert_eq!(
+            TriggerType::resolve_default(&instrument),
+            TriggerType::LastTrade
+        );
+    }
+
+    #[test]
+    fn test_trigger_type_resolve_default_for_crypto_perpetual() {
+        let instrument = CryptoPerpetual::default();
+
+        assert_eq!(
+            TriggerType::resolve_default(&instrument),
+            TriggerType::MarkPrice
+        );
+    }
+
+    #[test]
+    fn test_trigger_type_resolve() {
+        let instrument = CryptoPerpetual::default();
+
+        assert_eq!(
+            TriggerType::Default.resolve(&instrument),
+            TriggerType::MarkPrice
+        );
+        assert_eq!(
+            TriggerType::NoTrigger.resolve(&instrument),
+            TriggerType::MarkPrice
+        );
+        assert_eq!(
+            TriggerType::BidAsk.resolve(&instrument),
+            TriggerType::BidAsk
+        );
+    }
+
+    #[test]
+    fn test_options_contract_calculate_margin() {
+        let instrument = OptionsContract {
+            margin_init: Decimal::new(10, 2), // 10%
+            margin_maint: Decimal::new(5, 2), // 5%
+            ..Default::default()
+        };
+
+        let quantity = Quantity::from(10);
+        let price = Price::from("5.00");
+
+        // Notional is 10 * 5.00 = 50.00 USD
+        let margin_init = instrument.calculate_margin_init(quantity, price);
+        let margin_maint = instrument.calculate_margin_maint(quantity, price);
+
+        assert_eq!(margin_init, Money::new(5.0, *USD));
+        assert_eq!(margin_maint, Money::new(2.5, *USD));
+        assert_eq!(margin_init.currency, instrument.currency);
+    }
+
+    #[test]
+    fn test_options_contract_calculate_margin_with_zero_rate() {
+        let instrument = OptionsContract::default();
+
+        let margin = instrument.calculate_margin_init(Quantity::from(10), Price::from("5.00"));
+
+        assert_eq!(margin, Money::new(0.0, *USD));
+    }
+
+    #[test]
+    fn test_options_contract_intrinsic_value_for_call() {
+        let instrument = OptionsContract::default(); // Strike 149.00
+
+        // In the money
+        assert_eq!(instrument.intrinsic_value(Price::from("152.50")), 3.5);
+        assert!(instrument.is_in_the_money(Price::from("152.50")));
+        // At the money
+        assert_eq!(instrument.intrinsic_value(Price::from("149.00")), 0.0);
+        assert!(!instrument.is_in_the_money(Price::from("149.00")));
+        // Out of the money
+        assert_eq!(instrument.intrinsic_value(Price::from("145.25")), 0.0);
+        assert!(!instrument.is_in_the_money(Price::from("145.25")));
+    }
+
+    #[test]
+    fn test_options_contract_intrinsic_value_for_put() {
+        let instrument = OptionsContract {
+            option_kind: OptionKind::Put,
+            ..Default::default()
+        };
+
+        // In the money
+        assert_eq!(instrument.intrinsic_value(Price::from("145.25")), 3.75);
+        assert!(instrument.is_in_the_money(Price::from("145.25")));
+        // At the money
+        assert_eq!(instrument.intrinsic_value(Price::from("149.00")), 0.0);
+        assert!(!instrument.is_in_the_money(Price::from("149.00")));
+        // Out of the money
+        assert_eq!(instrument.intrinsic_value(Price::from("152.50")), 0.0);
+        assert!(!instrument.is_in_the_money(Price::from("152.50")));
+    }
+
+    #[test]
+    fn test_options_contract_intrinsic_value_rounded_to_price_precision() {
+        let instrument = OptionsContract::default();
+
+        assert_eq!(instrument.intrinsic_value(Price::from("149.1234")), 0.12);
+        assert!(!instrument.is_in_the_money(Price::from("149.004")));
+    }
+
+    #[test]
+    fn test_options_contract_expiring_in_future() {
+        let instrument = OptionsContract::default();
+        let now = instrument.expiration - 86_400_000_000_000; // One day before
+
+        assert_eq!(instrument.time_to_expiry_secs(now), 86_400.0);
+        assert!(!instrument.is_expired(now));
+    }
+
+    #[test]
+    fn test_options_contract_expiring_now() {
+        let instrument = OptionsContract::default();
+        let now = instrument.expiration;
+
+        assert_eq!(instrument.time_to_expiry_secs(now), 0.0);
+        assert!(instrument.is_expired(now));
+    }
+
+    #[test]
+    fn test_options_contract_expired_in_past() {
+        let instrument = OptionsContract::default();
+        let now = instrument.expiration + 1_500_000_000; // 1.5 seconds after
+
+        assert_eq!(instrument.time_to_expiry_secs(now), -1.5);
+        assert!(instrument.is_expired(now));
+    }
+
+    #[test]
+    fn test_options_contract_before_activation() {
+        let instrument = OptionsContract {
+            activation: Some(1_600_000_000_000_000_000),
+            ..Default::default()
+        };
+
+        assert!(!instrument.is_tradable(1_599_999_999_999_999_999));
+    }
+
+    #[test]
+    fn test_options_contract_tradable() {
+        let instrument = OptionsContract {
+            activation: Some(1_600_000_000_000_000_000),
+            ..Default::default()
+        };
+
+        assert!(instrument.is_tradable(1_600_000_000_000_000_000));
+        assert!(instrument.is_tradable(instrument.expiration - 1));
+        assert!(OptionsContract::default().is_tradable(0)); // No activation
+    }
+
+    #[test]
+    fn test_options_contract_not_tradable_when_expired() {
+        let instrument = OptionsContract {
+            activation: Some(1_600_000_000_000_000_000),
+            ..Default::default()
+        };
+
+        assert!(!instrument.is_tradable(instrument.expiration));
+        assert!(!OptionsContract::default().is_tradable(instrument.expiration));
+    }
+
+    #[test]
+    fn test_is_tradable_now_when_active() {
+        let equity = Equity::default();
+        let future = FuturesContract::default();
+
+        assert_eq!(equity.trading_state(), TradingState::Active);
+        assert!(equity.is_tradable_now(0));
+        assert!(future.is_tradable_now(future.expiration - 1));
+        assert!(!future.is_tradable_now(future.expiration));
+    }
+
+    #[test]
+    fn test_is_tradable_now_when_halted() {
+        let equity = Equity {
+            trading_state: TradingState::Halted,
+            ..Default::default()
+        };
+        let option = OptionsContract {
+            activation: Some(1_600_000_000_000_000_000),
+            trading_state: TradingState::Halted,
+            ..Default::default()
+        };
+
+        assert!(!equity.is_tradable_now(0));
+        assert!(!option.is_tradable_now(1_600_000_000_000_000_000));
+        assert!(option.is_tradable(1_600_000_000_000_000_000)); // Within time bounds
+    }
+
+    #[test]
+    fn test_is_tradable_now_when_reducing() {
+        let equity = Equity {
+            trading_state: TradingState::Reducing,
+            ..Default::default()
+        };
+
+        assert!(equity.is_tradable_now(0));
+    }
+
+    #[test]
+    fn test_is_tradable_now_respects_activation() {
+        let option = OptionsContract {
+            activation: Some(1_600_000_000_000_000_000),
+            ..Default::default()
+        };
+
+        assert!(!option.is_tradable_now(1_599_999_999_999_999_999));
+        assert!(option.is_tradable_now(1_600_000_000_000_000_000));
+    }
+
+    #[test]
+    fn test_trading_state_defaults_to_active_when_deserialized() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&Equity::default()).unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("trading_state");
+
+        let deserialized: Equity = serde_json::from_str(&value.to_string()).unwrap();
+
+        assert_eq!(deserialized.trading_state, TradingState::Active);
+    }
+
+    #[test]
+    fn test_options_contract_deserialize_without_activation() {
+        let instrument = OptionsContract {
+            activation: Some(1_600_000_000_000_000_000),
+            ..Default::default()
+        };
+        let mut value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&instrument).unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("activation");
+
+        let deserialized: OptionsContract = serde_json::from_str(&value.to_string()).unwrap();
+
+        assert_eq!(deserialized, instrument);
+        assert_eq!(deserialized.activation, None);
+    }
+
+    #[test]
+    fn test_tick_value_for_linear_instrument() {
+        let instrument = CryptoPerpetual::default();
+
+        // 2 BTC with a tick of 0.1 USDT
+        let tick_value = instrument
+            .tick_value(Quantity::from("2.000"), None)
+            .unwrap();
+
+        assert_eq!(tick_value, Money::new(0.2, *USDT));
+    }
+
+    #[test]
+    fn test_tick_value_for_inverse_instrument() {
+        let instrument = inverse_perpetual();
+
+        // 10,000 USD contracts ticking from 20,000.0 to 20,000.5 is worth
+        // 10_000 * (1 / 20_000.0 - 1 / 20_000.5) BTC
+        let tick_value = instrument
+            .tick_value(Quantity::from(10_000), Some(Price::from("20000.0")))
+            .unwrap();
+
+        assert_eq!(tick_value, Money::new(0.000_012_50, *BTC));
+        assert_eq!(tick_value.currency, *BTC);
+    }
+
+    #[test]
+    fn test_tick_value_for_inverse_instrument_without_price() {
+        let instrument = inverse_perpetual();
+
+        let result = instrument.tick_value(Quantity::from(10_000), None);
+
+        assert!(matches!(
+            result,
+            Err(InstrumentError::MissingInversePrice(instrument_id)) if instrument_id == instrument.id
+        ));
+    }
+
+    #[test]
+    fn test_downcast_boxed_options_contract() {
+        let instrument: Box<dyn Instrument> = Box::new(OptionsContract {
+            strike_price: Price::from("150.00"),
+            ..Default::default()
+        });
+
+        let options_contract = instrument
+            .as_any()
+            .downcast_ref::<OptionsContract>()
+            .unwrap();
+
+        assert_eq!(options_contract.strike_price, Price::from("150.00"));
+        assert!(instrument.as_any().downcast_ref::<Equity>().is_none());
+    }
+
+    #[test]
+    fn test_linear_crypto_perpetual() {
+        let instrument = CryptoPerpetual::default();
+
+        assert!(!instrument.is_inverse());
+        assert_eq!(instrument.base_currency(), Some(&*BTC));
+        assert_eq!(instrument.quote_currency(), &*USDT);
+        assert_eq!(instrument.settlement_currency(), &*USDT);
+        assert_eq!(instrument.multiplier(), Quantity::from(1));
+        assert_eq!(
+            instrument.calculate_notional_value(
+                Quantity::from("0.500"),
+                Price::from("40000.0"),
+                None
+            ),
+            Money::new(20_000.0, *USDT)
+        );
+    }
+
+    #[test]
+    fn test_inverse_crypto_perpetual() {
+        let instrument = CryptoPerpetual {
+            multiplier: Quantity::from(100),
+            ..inverse_perpetual()
+        };
+
+        assert!(instrument.is_inverse());
+        assert_eq!(instrument.base_currency(), Some(&*BTC));
+        assert_eq!(instrument.quote_currency(), &*USD);
+        assert_eq!(instrument.settlement_currency(), &*BTC);
+        assert_eq!(instrument.multiplier(), Quantity::from(100));
+        // 10 contracts of 100 USD at 50,000.0 USD/BTC is 0.02 BTC
+        assert_eq!(
+            instrument.calculate_notional_value(Quantity::from(10), Price::from("50000.0"), None),
+            Money::new(0.02, *BTC)
+        );
+    }
+
+    #[test]
+    fn test_crypto_perpetual_serde_round_trip() {
+        let instrument = inverse_perpetual();
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: CryptoPerpetual = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, instrument);
+        assert!(deserialized.is_inverse);
+        assert_eq!(deserialized.multiplier, instrument.multiplier);
+        assert_eq!(deserialized.settlement_currency, *BTC);
+    }
+
+    #[test]
+    fn test_crypto_perpetual_deserialize_without_is_inverse_and_multiplier() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&CryptoPerpetual::default()).unwrap())
+                .unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("is_inverse");
+        fields.remove("multiplier");
+
+        let deserialized: CryptoPerpetual = serde_json::from_str(&value.to_string()).unwrap();
+
+        assert!(!deserialized.is_inverse);
+        assert_eq!(deserialized.multiplier, Quantity::from(1));
+    }
+
+    #[test]
+    fn test_futures_contract() {
+        let instrument = FuturesContract::default();
+
+        assert_eq!(instrument.asset_type(), AssetType::Future);
+        assert_eq!(instrument.base_currency(), None);
+        assert_eq!(instrument.quote_currency(), &*USD);
+        assert_eq!(instrument.settlement_currency(), &*USD);
+        assert_eq!(instrument.multiplier(), Quantity::from(50));
+        assert_eq!(
+            instrument.calculate_notional_value(Quantity::from(2), Price::from("4500.00"), None),
+            Money::new(450_000.0, *USD)
+        );
+    }
+
+    #[test]
+    fn test_notional_value_for_linear_future() {
+        let instrument = FuturesContract::default();
+
+        // 2 contracts of 50 at 4500.00 USD
+        assert_eq!(
+            instrument.notional_value(Quantity::from(2), Price::from("4500.00")),
+            Money::new(450_000.0, *USD)
+        );
+    }
+
+    #[test]
+    fn test_notional_value_for_inverse_perpetual() {
+        let instrument = CryptoPerpetual {
+            multiplier: Quantity::from(100),
+            ..inverse_perpetual()
+        };
+        let linear = CryptoPerpetual {
+            is_inverse: false,
+            settlement_currency: *USD,
+            ..instrument.clone()
+        };
+
+        // 10 contracts of 100 USD at 50,000.0 USD/BTC
+        assert_eq!(
+            instrument.notional_value(Quantity::from(10), Price::from("50000.0")),
+            Money::new(0.02, *BTC)
+        );
+        assert_eq!(
+            linear.notional_value(Quantity::from(10), Price::from("50000.0")),
+            Money::new(50_000_000.0, *USD)
+        );
+        assert_eq!(
+            instrument.notional_value(Quantity::from(10), Price::from("50000.0")),
+            instrument.calculate_notional_value(Quantity::from(10), Price::from("50000.0"), None)
+        );
+    }
+
+    #[test]
+    fn test_calculate_base_quantity() {
+        let instrument = CryptoPerpetual::default();
+
+        // 1000 USDT at 40,000.0 is exactly 0.025 BTC
+        let quantity =
+            instrument.calculate_base_quantity(Quantity::from(1000), Price::from("40000.0"));
+
+        assert_eq!(quantity, Quantity::from("0.025"));
+        assert_eq!(quantity.precision, 3);
+    }
+
+    #[test]
+    fn test_calculate_base_quantity_rounds_to_size_precision() {
+        let instrument = CryptoPerpetual::default();
+
+        // 1000 USDT at 30,000.0 is 0.0333... BTC, and 2000 USDT 0.0666... BTC
+        assert_eq!(
+            instrument.calculate_base_quantity(Quantity::from(1000), Price::from("30000.0")),
+            Quantity::from("0.033")
+        );
+        assert_eq!(
+            instrument.calculate_base_quantity(Quantity::from(2000), Price::from("30000.0")),
+            Quantity::from("0.067")
+        );
+    }
+
+    #[test]
+    fn test_calculate_base_quantity_rounds_to_size_increment() {
+        let instrument = CryptoPerpetual {
+            size_increment: Quantity::from("0.005"),
+            ..Default::default()
+        };
+
+        // 0.0333... BTC is nearest to 7 increments, and 0.0311... BTC to 6
+        let quantity =
+            instrument.calculate_base_quantity(Quantity::from(1000), Price::from("30000.0"));
+
+        assert_eq!(quantity, Quantity::from("0.035"));
+        assert_eq!(quantity.precision, 3);
+        assert_eq!(
+            instrument.calculate_base_quantity(Quantity::from(1000), Price::from("32100.0")),
+            Quantity::from("0.030")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `last_px`")]
+    fn test_calculate_base_quantity_with_zero_price() {
+        let _ = CryptoPerpetual::default()
+            .calculate_base_quantity(Quantity::from(1000), Price::zero(1));
+    }
+
+    #[test]
+    fn test_validate_order() {
+        let instrument = CryptoPerpetual::default();
+
+        assert!(instrument
+            .validate_order(Quantity::from("0.500"), Some(Price::from("40000.1")))
+            .is_ok());
+        assert!(instrument
+            .validate_order(Quantity::from("1000.000"), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_order_with_quantity_below_min() {
+        let instrument = CryptoPerpetual {
+            min_quantity: Some(Quantity::from("0.010")),
+            ..Default::default()
+        };
+
+        let result = instrument.validate_order(Quantity::from("0.005"), None);
+
+        assert_eq!(
+            result,
+            Err(InstrumentError::QuantityBelowMin(
+                Quantity::from("0.005"),
+                Quantity::from("0.010")
+            ))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quantity 0.005 is below the minimum 0.010"
+        );
+    }
+
+    #[test]
+    fn test_validate_order_with_quantity_above_max() {
+        let instrument = CryptoPerpetual::default();
+
+        assert_eq!(
+            instrument.validate_order(Quantity::from("1000.001"), None),
+            Err(InstrumentError::QuantityAboveMax(
+                Quantity::from("1000.001"),
+                Quantity::from("1000.000")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validate_order_with_quantity_off_increment() {
+        let instrument = CryptoPerpetual::default();
+
+        assert!(matches!(
+            instrument.validate_order(Quantity::from("0.0015"), None),
+            Err(InstrumentError::QuantityOffIncrement(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_validate_order_with_price_off_increment() {
+        let instrument = CryptoPerpetual::default();
+
+        let result =
+            instrument.validate_order(Quantity::from("0.500"), Some(Price::from("40000.05")));
+
+        assert_eq!(
+            result,
+            Err(InstrumentError::PriceOffIncrement(
+                Price::from("40000.05"),
+                Price::from("0.1")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validate_order_with_price_out_of_bounds() {
+        let instrument = CryptoPerpetual::default();
+
+        assert!(matches!(
+            instrument.validate_order(Quantity::from("0.500"), Some(Price::from("0.0"))),
+            Err(InstrumentError::PriceBelowMin(_, _))
+        ));
+        assert!(matches!(
+            instrument.validate_order(Quantity::from("0.500"), Some(Price::from("1000000.1"))),
+            Err(InstrumentError::PriceAboveMax(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_futures_contract_equality_by_id() {
+        let instrument = FuturesContract::default();
+        let other = FuturesContract {
+            underlying: String::from("MES"),
+            multiplier: Quantity::from(5),
+            ..Default::default()
+        };
+
+        assert_eq!(instrument, other);
+    }
+
+    #[test]
+    fn test_futures_contract_serde_json_round_trip() {
+        let instrument = FuturesContract::default();
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: FuturesContract = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, instrument);
+        assert_eq!(deserialized.expiration, instrument.expiration);
+        assert_eq!(deserialized.multiplier, instrument.multiplier);
+    }
+
+    #[test]
+    fn test_futures_contract_deserialize_without_multiplier() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&FuturesContract::default()).unwrap())
+                .unwrap();
+        value.as_object_mut().unwrap().remove("multiplier");
+
+        let deserialized: FuturesContract = serde_json::from_str(&value.to_string()).unwrap();
+
+        assert_eq!(deserialized.multiplier, Quantity::from(1));
     }
 }
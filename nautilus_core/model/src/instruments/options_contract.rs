@@ -15,18 +15,29 @@
 
 #![allow(dead_code)] // Allow for development
 
-use std::hash::{Hash, Hasher};
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
-use nautilus_core::time::UnixNanos;
+use nautilus_core::{datetime::nanos_to_secs, time::UnixNanos};
 use pyo3::prelude::*;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 use super::Instrument;
 use crate::{
-    enums::{AssetClass, AssetType, OptionKind},
+    currencies::USD,
+    enums::{AssetClass, AssetType, OptionKind, TradingState},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
-    types::{currency::Currency, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency,
+        fixed::{FIXED_PRECISION, FIXED_SCALAR},
+        money::Money,
+        price::Price,
+        quantity::Quantity,
+    },
 };
 
 #[repr(C)]
@@ -39,6 +50,8 @@ pub struct OptionsContract {
     pub underlying: String,
     pub option_kind: OptionKind,
     pub expiration: UnixNanos,
+    #[serde(default)]
+    pub activation: Option<UnixNanos>,
     pub strike_price: Price,
     pub currency: Currency,
     pub price_precision: u8,
@@ -52,6 +65,8 @@ pub struct OptionsContract {
     pub margin_maint: Decimal,
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
+    #[serde(default)]
+    pub trading_state: TradingState,
 }
 
 impl OptionsContract {
@@ -64,6 +79,7 @@ impl OptionsContract {
         underlying: String,
         option_kind: OptionKind,
         expiration: UnixNanos,
+        activation: Option<UnixNanos>,
         strike_price: Price,
         currency: Currency,
         price_precision: u8,
@@ -85,6 +101,7 @@ impl OptionsContract {
             underlying,
             option_kind,
             expiration,
+            activation,
             strike_price,
             currency,
             price_precision,
@@ -98,8 +115,110 @@ impl OptionsContract {
             margin_maint,
             maker_fee,
             taker_fee,
+            trading_state: TradingState::Active,
+        }
+    }
+
+    /// Calculates the initial margin for the given `quantity` at `price`, as the
+    /// notional value multiplied by the `margin_init` rate.
+    #[must_use]
+    pub fn calculate_margin_init(&self, quantity: Quantity, price: Price) -> Money {
+        self.calculate_margin(quantity, price, self.margin_init)
+    }
+
+    /// Calculates the maintenance margin for the given `quantity` at `price`, as
+    /// the notional value multiplied by the `margin_maint` rate.
+    #[must_use]
+    pub fn calculate_margin_maint(&self, quantity: Quantity, price: Price) -> Money {
+        self.calculate_margin(quantity, price, self.margin_maint)
+    }
+
+    /// Returns the intrinsic value of the contract for the given `underlying_price`,
+    /// being `max(0, spot - strike)` for a call and `max(0, strike - spot)` for a put,
+    /// rounded to the contract `price_precision`.
+    #[must_use]
+    pub fn intrinsic_value(&self, underlying_price: Price) -> f64 {
+        let raw = match self.option_kind {
+            OptionKind::Call => underlying_price.raw - self.strike_price.raw,
+            OptionKind::Put => self.strike_price.raw - underlying_price.raw,
+        };
+        // Round half up to the price precision while still in fixed-point
+        let step = 10_i64.pow(u32::from(FIXED_PRECISION - self.price_precision));
+        let raw = (raw.max(0) + step / 2) / step * step;
+        raw as f64 / FIXED_SCALAR
+    }
+
+    /// Returns true if the contract has a positive intrinsic value for the given
+    /// `underlying_price` (at the contract `price_precision`).
+    #[must_use]
+    pub fn is_in_the_money(&self, underlying_price: Price) -> bool {
+        self.intrinsic_value(underlying_price) > 0.0
+    }
+
+    /// Returns the time from `now` until the contract expiration in seconds, which is
+    /// negative if the contract has already expired.
+    #[must_use]
+    pub fn time_to_expiry_secs(&self, now: UnixNanos) -> f64 {
+        if self.expiration >= now {
+            nanos_to_secs(self.expiration - now)
+        } else {
+            -nanos_to_secs(now - self.expiration)
         }
     }
+
+    /// Returns true if the contract has expired as of `now` (including exactly at the
+    /// expiration).
+    #[must_use]
+    pub fn is_expired(&self, now: UnixNanos) -> bool {
+        now >= self.expiration
+    }
+
+    /// Returns true if the contract is tradable as of `now`, being from the
+    /// `activation` (if any, inclusive) until the expiration (exclusive).
+    #[must_use]
+    pub fn is_tradable(&self, now: UnixNanos) -> bool {
+        let is_activated = match self.activation {
+            Some(activation) => now >= activation,
+            None => true,
+        };
+        is_activated && !self.is_expired(now)
+    }
+
+    fn calculate_margin(&self, quantity: Quantity, price: Price, rate: Decimal) -> Money {
+        let notional = self.calculate_notional_value(quantity, price, None);
+        let rate = rate
+            .to_f64()
+            .expect("Error: margin rate not representable as f64");
+        Money::new(notional.as_f64() * rate, self.currency)
+    }
+}
+
+/// Provides a default [`OptionsContract`] used for testing.
+impl Default for OptionsContract {
+    fn default() -> Self {
+        OptionsContract::new(
+            InstrumentId::from_str("AAPL211217C00150000.OPRA").unwrap(),
+            Symbol::new("AAPL211217C00150000"),
+            AssetClass::Equity,
+            String::from("AAPL"),
+            OptionKind::Call,
+            1_639_699_200_000_000_000,
+            None,
+            Price::from("149.00"),
+            *USD,
+            2,
+            Price::from("0.01"),
+            Some(Quantity::from(1)),
+            None,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )
+    }
 }
 
 impl PartialEq<Self> for OptionsContract {
@@ -204,4 +323,20 @@ impl Instrument for OptionsContract {
     fn taker_fee(&self) -> Decimal {
         self.taker_fee
     }
+
+    fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration)
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        self.activation
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
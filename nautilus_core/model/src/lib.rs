@@ -29,6 +29,7 @@ pub mod macros;
 pub mod orderbook;
 pub mod orders;
 pub mod position;
+pub mod prelude;
 pub mod types;
 
 /// Loaded as nautilus_pyo3.model
@@ -44,7 +45,10 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<enums::AccountType>()?;
     m.add_class::<enums::AggregationSource>()?;
     m.add_class::<enums::BarAggregation>()?;
+    m.add_class::<enums::ContingencyType>()?;
+    m.add_class::<enums::OrderType>()?;
     m.add_class::<enums::PriceType>()?;
+    m.add_class::<enums::TriggerType>()?;
     m.add_class::<identifiers::account_id::AccountId>()?;
     m.add_class::<identifiers::client_id::ClientId>()?;
     m.add_class::<identifiers::client_order_id::ClientOrderId>()?;
@@ -59,6 +63,7 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<identifiers::trader_id::TraderId>()?;
     m.add_class::<identifiers::venue::Venue>()?;
     m.add_class::<identifiers::venue_order_id::VenueOrderId>()?;
+    m.add_class::<orders::list::OrderList>()?;
     m.add_class::<types::currency::Currency>()?;
     m.add_class::<types::money::Money>()?;
     m.add_class::<types::price::Price>()?;
@@ -31,7 +31,40 @@ macro_rules! strum_serde {
                 D: Deserializer<'de>,
             {
                 let s = String::deserialize(deserializer)?;
-                <$type>::from_str(&s).map_err(serde::de::Error::custom)
+                <$type>::from_str(&s).map_err(|e| {
+                    serde::de::Error::custom(format!("{e} for `{}`: '{s}'", stringify!($type)))
+                })
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! enum_for_python {
+    ($type:ty) => {
+        #[pymethods]
+        impl $type {
+            #[staticmethod]
+            #[pyo3(name = "from_str")]
+            fn py_from_str(data: &str) -> PyResult<Self> {
+                <$type>::from_str(data).map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "{e} for `{}`: '{data}'",
+                        stringify!($type)
+                    ))
+                })
+            }
+
+            #[getter]
+            #[pyo3(name = "name")]
+            fn py_name(&self) -> &str {
+                self.as_ref()
+            }
+
+            #[getter]
+            #[pyo3(name = "value")]
+            fn py_value(&self) -> u8 {
+                *self as u8
             }
         }
     };
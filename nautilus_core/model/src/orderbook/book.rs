@@ -57,6 +57,16 @@ pub enum BookIntegrityError {
     TooManyLevels(OrderSide, usize),
 }
 
+/// Represents a point-in-time snapshot of the top levels of an [`OrderBook`].
+///
+/// Each side holds `(price, size)` pairs ordered best-first, with the size being the
+/// aggregate volume at that price level.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookDepthSnapshot {
+    pub bids: Vec<(Price, Quantity)>,
+    pub asks: Vec<(Price, Quantity)>,
+}
+
 #[derive(Tabled)]
 struct OrderLevelDisplay {
     bids: String,
@@ -170,6 +180,33 @@ impl OrderBook {
         self.asks.levels.values().collect()
     }
 
+    /// Returns a snapshot of the top `depth` levels on each side of the book.
+    ///
+    /// If a side has fewer than `depth` levels then all of its levels are returned.
+    #[must_use]
+    pub fn depth_snapshot(&self, depth: usize) -> BookDepthSnapshot {
+        BookDepthSnapshot {
+            bids: Self::snapshot_levels(self.bids.levels.values(), depth),
+            asks: Self::snapshot_levels(self.asks.levels.values(), depth),
+        }
+    }
+
+    fn snapshot_levels<'a>(
+        levels: impl Iterator<Item = &'a Level>,
+        depth: usize,
+    ) -> Vec<(Price, Quantity)> {
+        levels
+            .filter_map(|level| {
+                let precision = level.orders.first()?.size.precision;
+                Some((
+                    level.price.value,
+                    Quantity::from_raw(level.volume_raw(), precision),
+                ))
+            })
+            .take(depth)
+            .collect()
+    }
+
     pub fn has_bid(&self) -> bool {
         match self.bids.top() {
             Some(top) => !top.orders.is_empty(),
@@ -606,6 +643,63 @@ mod tests {
         assert_eq!(book.midpoint(), Some(1.5));
     }
 
+    #[test]
+    fn test_depth_snapshot_when_nothing_in_book() {
+        let book = create_stub_book(BookType::L2_MBP);
+        let snapshot = book.depth_snapshot(5);
+
+        assert!(snapshot.bids.is_empty());
+        assert!(snapshot.asks.is_empty());
+    }
+
+    #[test]
+    fn test_depth_snapshot_top_5() {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        let mut order_id = 0;
+        let mut add = |book: &mut OrderBook, side: OrderSide, price: &str, size: &str| {
+            order_id += 1;
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            book.add(order, 100, order_id);
+        };
+
+        // Only three bid levels, with two orders at the best bid
+        add(&mut book, OrderSide::Buy, "99.0", "1.0");
+        add(&mut book, OrderSide::Buy, "99.0", "2.0");
+        add(&mut book, OrderSide::Buy, "98.0", "3.0");
+        add(&mut book, OrderSide::Buy, "97.0", "4.0");
+        for (price, size) in [
+            ("100.0", "1.0"),
+            ("101.0", "2.0"),
+            ("102.0", "3.0"),
+            ("103.0", "4.0"),
+            ("104.0", "5.0"),
+            ("105.0", "6.0"),
+        ] {
+            add(&mut book, OrderSide::Sell, price, size);
+        }
+
+        let snapshot = book.depth_snapshot(5);
+
+        assert_eq!(
+            snapshot.bids,
+            vec![
+                (Price::from("99.0"), Quantity::from("3.0")),
+                (Price::from("98.0"), Quantity::from("3.0")),
+                (Price::from("97.0"), Quantity::from("4.0")),
+            ]
+        );
+        assert_eq!(
+            snapshot.asks,
+            vec![
+                (Price::from("100.0"), Quantity::from("1.0")),
+                (Price::from("101.0"), Quantity::from("2.0")),
+                (Price::from("102.0"), Quantity::from("3.0")),
+                (Price::from("103.0"), Quantity::from("4.0")),
+                (Price::from("104.0"), Quantity::from("5.0")),
+            ]
+        );
+    }
+
     #[test]
     fn test_get_price_for_quantity_no_market() {
         let book = create_stub_book(BookType::L2_MBP);
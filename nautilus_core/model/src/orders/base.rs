@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ops::DerefMut,
+};
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 use thiserror;
@@ -21,12 +24,12 @@ use thiserror;
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
-        TimeInForce, TriggerType,
+        TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::order::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent, OrderExpired,
-        OrderFilled, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate, OrderRejected,
-        OrderSubmitted, OrderTriggered, OrderUpdated,
+        OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
+        OrderRejected, OrderReleased, OrderSubmitted, OrderTriggered, OrderUpdated,
     },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
@@ -34,18 +37,236 @@ use crate::{
         strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
         venue_order_id::VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    instruments::Instrument,
+    orders::{
+        limit::LimitOrder, limit_if_touched::LimitIfTouchedOrder, market::MarketOrder,
+        market_if_touched::MarketIfTouchedOrder, market_to_limit::MarketToLimitOrder,
+        report::OrderStatusReport, stop_limit::StopLimitOrder, stop_market::StopMarketOrder,
+        trailing_stop_limit::TrailingStopLimitOrder, trailing_stop_market::TrailingStopMarketOrder,
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// The delimiter separating multiple tags packed into an order's `tags` string.
+pub const TAGS_DELIMITER: char = ',';
+
 #[derive(thiserror::Error, Debug)]
 pub enum OrderError {
     #[error("Invalid state transition")]
     InvalidStateTransition,
     #[error("Unrecognized event")]
     UnrecognizedEvent,
+    #[error("Event client order ID {1} does not match order {0}")]
+    EventMismatch(ClientOrderId, ClientOrderId),
+    #[error("Missing required field `{1}` for {0} order")]
+    MissingRequiredField(OrderType, &'static str),
+    #[error("Expected {0} order, was {1}")]
+    UnexpectedOrderType(OrderType, OrderType),
+    #[error("Duplicate client order ID {0}")]
+    DuplicateOrderId(ClientOrderId),
+    #[error("Too many legs: {0} exceeds the maximum of {1}")]
+    TooManyLegs(usize, usize),
+    #[error("No session auction time set for {0} order")]
+    MissingAuctionTime(TimeInForce),
+    #[error("Event at {0} is before the session auction time {1}")]
+    BeforeAuction(UnixNanos, UnixNanos),
+    #[error("Too many modifications: the maximum of {0} was reached")]
+    TooManyModifications(u32),
+    #[error("Order is already closed with status {0}")]
+    AlreadyClosed(OrderStatus),
+    #[error("Order is not open with status {0}")]
+    NotOpen(OrderStatus),
+    #[error("Filled quantity {0} does not match the sum of fill quantities {1}")]
+    InconsistentFilledQty(Quantity, Quantity),
+    #[error("Average price {0:?} does not match the filled notional per unit {1:?}")]
+    InconsistentAvgPx(Option<f64>, Option<f64>),
+    #[error("Order {0} has parent order {1} which was not found")]
+    OrphanedOrder(ClientOrderId, ClientOrderId),
+    #[error("Order {0} is not linked from its parent order {1}")]
+    UnlinkedOrder(ClientOrderId, ClientOrderId),
+    #[error("Order {0} is part of a cycle of parent orders")]
+    CyclicOrderTree(ClientOrderId),
+    #[error("Invalid order side {0}")]
+    InvalidSide(OrderSide),
+    #[error("Trailing offset type {0} is not supported")]
+    UnsupportedTrailingOffsetType(TrailingOffsetType),
+    #[error("Time in force {1} is not valid for {0} order")]
+    InvalidTimeInForce(OrderType, TimeInForce),
+    #[error("First event must be `OrderInitialized`")]
+    MissingInitialized,
+    #[error("Event initialized at {0} is before the order was initialized at {1}")]
+    NonMonotonicEvents(UnixNanos, UnixNanos),
+    #[error("Reported filled quantity {1} is less than the order filled quantity {0}")]
+    ReportedFilledQtyDecreased(Quantity, Quantity),
+    #[error("Reported filled quantity {0} has no average price")]
+    MissingReportedAvgPx(Quantity),
+    #[error(
+        "Event `ts_event` {0} is ahead of its `ts_init` {1} by more than the maximum skew {2}"
+    )]
+    ClockSkewExceeded(UnixNanos, UnixNanos, UnixNanos),
+}
+
+/// Returns whether the `time_in_force` is valid for the `order_type`.
+///
+/// - `Market` orders cannot be `Gtd`, as they are never resting.
+/// - `MarketToLimit` and triggered order types cannot be for a session auction
+///   (`AtTheOpen` or `AtTheClose`), as they depend on the market reaching them.
+#[must_use]
+pub fn is_valid_combination(order_type: OrderType, time_in_force: TimeInForce) -> bool {
+    match order_type {
+        OrderType::Market => time_in_force != TimeInForce::Gtd,
+        OrderType::Limit => true,
+        OrderType::MarketToLimit
+        | OrderType::StopMarket
+        | OrderType::StopLimit
+        | OrderType::MarketIfTouched
+        | OrderType::LimitIfTouched
+        | OrderType::TrailingStopMarket
+        | OrderType::TrailingStopLimit => !time_in_force.is_auction(),
+    }
+}
+
+/// Validates that all fields required by the `event` order type are set (see
+/// [`OrderType::required_fields`]), and that its time in force is valid for the order
+/// type (see [`is_valid_combination`]).
+///
+/// # Errors
+///
+/// Returns an [`OrderError::MissingRequiredField`] naming the first required field which
+/// is `None`, or an [`OrderError::InvalidTimeInForce`] for an invalid combination.
+pub fn validate_initialized(event: &OrderInitialized) -> Result<(), OrderError> {
+    for &field in event.order_type.required_fields() {
+        let is_set = match field {
+            "price" => event.price.is_some(),
+            "trigger_price" => event.trigger_price.is_some(),
+            "trigger_type" => event.trigger_type.is_some(),
+            "limit_offset" => event.limit_offset.is_some(),
+            "trailing_offset" => event.trailing_offset.is_some(),
+            "trailing_offset_type" => event.trailing_offset_type.is_some(),
+            _ => unreachable!("unknown `OrderInitialized` field `{field}`"),
+        };
+        if !is_set {
+            return Err(OrderError::MissingRequiredField(event.order_type, field));
+        }
+    }
+    if !is_valid_combination(event.order_type, event.time_in_force) {
+        return Err(OrderError::InvalidTimeInForce(
+            event.order_type,
+            event.time_in_force,
+        ));
+    }
+    Ok(())
+}
+
+/// Initializes an order of the concrete type for the `init` order type.
+///
+/// # Errors
+///
+/// Returns an error if the `init` is not valid for its order type (see [`validate_initialized`]).
+pub fn from_initialized(init: OrderInitialized) -> Result<Box<dyn Order>, OrderError> {
+    let order: Box<dyn Order> = match init.order_type {
+        OrderType::Market => Box::new(MarketOrder::try_from(init)?),
+        OrderType::Limit => Box::new(LimitOrder::try_from(init)?),
+        OrderType::StopMarket => Box::new(StopMarketOrder::try_from(init)?),
+        OrderType::StopLimit => Box::new(StopLimitOrder::try_from(init)?),
+        OrderType::MarketToLimit => Box::new(MarketToLimitOrder::try_from(init)?),
+        OrderType::MarketIfTouched => Box::new(MarketIfTouchedOrder::try_from(init)?),
+        OrderType::LimitIfTouched => Box::new(LimitIfTouchedOrder::try_from(init)?),
+        OrderType::TrailingStopMarket => Box::new(TrailingStopMarketOrder::try_from(init)?),
+        OrderType::TrailingStopLimit => Box::new(TrailingStopLimitOrder::try_from(init)?),
+    };
+    Ok(order)
+}
+
+/// Reconstructs an order from its `events`, by initializing it from the first event
+/// (which must be the `OrderInitialized`) and then applying the rest in sequence.
+///
+/// Venue clocks can run ahead of the local clock, producing a `ts_event` slightly after
+/// the `ts_init`. When a `max_skew` (nanoseconds) is given, any such `ts_event` is clamped
+/// to the `ts_init` of its event, provided it is ahead by no more than `max_skew`.
+///
+/// # Errors
+///
+/// Returns an error if the first event is not an `OrderInitialized`, if any later
+/// event has a `ts_init` before that of the initialization (an
+/// [`OrderError::NonMonotonicEvents`]), if a `ts_event` is ahead of its `ts_init` by
+/// more than the `max_skew` (an [`OrderError::ClockSkewExceeded`]), if the
+/// `OrderInitialized` is not valid for the order type (see [`validate_initialized`]),
+/// or if applying an event fails.
+pub fn from_events<T>(events: Vec<OrderEvent>, max_skew: Option<UnixNanos>) -> Result<T, OrderError>
+where
+    T: Order + TryFrom<OrderInitialized, Error = OrderError>,
+{
+    let mut events = events.into_iter();
+    let mut init = match events.next() {
+        Some(OrderEvent::OrderInitialized(init)) => init,
+        _ => return Err(OrderError::MissingInitialized),
+    };
+
+    // Validate all timestamps before applying anything, so no partial order is built
+    let mut events: Vec<OrderEvent> = events.collect();
+    if let Some(event) = events.iter().find(|event| event.ts_init() < init.ts_init) {
+        return Err(OrderError::NonMonotonicEvents(
+            event.ts_init(),
+            init.ts_init,
+        ));
+    }
+    if let Some(max_skew) = max_skew {
+        init.ts_event = clamp_ts_event(init.ts_event, init.ts_init, max_skew)?;
+        for event in &mut events {
+            let ts_event = clamp_ts_event(event.ts_event(), event.ts_init(), max_skew)?;
+            event.set_ts_event(ts_event);
+        }
+    }
+
+    let mut order = T::try_from(init.clone())?;
+    order.events[0] = OrderEvent::OrderInitialized(init);
+    for event in events {
+        order.apply(event)?;
+    }
+    Ok(order)
+}
+
+/// Reconstructs an order from the `json` of its `OrderInitialized` (see [`Order::to_json`]),
+/// as the concrete order type for its `order_type`.
+///
+/// # Errors
+///
+/// Returns an error if the `json` is not a valid `OrderInitialized`, or if it is not
+/// valid for the order type (see [`from_initialized`]).
+pub fn from_json(json: &str) -> anyhow::Result<Box<dyn Order>> {
+    let init: OrderInitialized = serde_json::from_str(json)?;
+    Ok(from_initialized(init)?)
+}
+
+fn clamp_ts_event(
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    max_skew: UnixNanos,
+) -> Result<UnixNanos, OrderError> {
+    if ts_event <= ts_init {
+        Ok(ts_event)
+    } else if ts_event - ts_init <= max_skew {
+        Ok(ts_init)
+    } else {
+        Err(OrderError::ClockSkewExceeded(ts_event, ts_init, max_skew))
+    }
 }
 
 impl OrderStatus {
+    /// Returns whether the status is terminal, after which the order cannot change.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Denied
+                | OrderStatus::Rejected
+                | OrderStatus::Canceled
+                | OrderStatus::Expired
+                | OrderStatus::Filled
+        )
+    }
+
     #[rustfmt::skip]
     pub fn transition(&mut self, event: &OrderEvent) -> Result<OrderStatus, OrderError> {
         let new_state = match (self, event) {
@@ -56,6 +277,7 @@ impl OrderStatus {
             (OrderStatus::Initialized, OrderEvent::OrderCanceled(_)) => OrderStatus::Canceled,  // Covers emulated and external orders
             (OrderStatus::Initialized, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,  // Covers emulated and external orders
             (OrderStatus::Initialized, OrderEvent::OrderTriggered(_)) => OrderStatus::Triggered, // Covers emulated and external orders
+            (OrderStatus::Initialized, OrderEvent::OrderReleased(_)) => OrderStatus::Initialized,  // Covers emulated orders
             (OrderStatus::Submitted, OrderEvent::OrderPendingUpdate(_)) => OrderStatus::PendingUpdate,
             (OrderStatus::Submitted, OrderEvent::OrderPendingCancel(_)) => OrderStatus::PendingCancel,
             (OrderStatus::Submitted, OrderEvent::OrderRejected(_)) => OrderStatus::Rejected,
@@ -72,8 +294,6 @@ impl OrderStatus {
             (OrderStatus::Accepted, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::Accepted, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::Accepted, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
-            (OrderStatus::Canceled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,  // Real world possibility
-            (OrderStatus::Canceled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,  // Real world possibility
             (OrderStatus::PendingUpdate, OrderEvent::OrderRejected(_)) => OrderStatus::Rejected,
             (OrderStatus::PendingUpdate, OrderEvent::OrderAccepted(_)) => OrderStatus::Accepted,
             (OrderStatus::PendingUpdate, OrderEvent::OrderCanceled(_)) => OrderStatus::Canceled,
@@ -103,13 +323,20 @@ impl OrderStatus {
             (OrderStatus::PartiallyFilled, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::Initialized, OrderEvent::OrderUpdated(_)) => OrderStatus::Initialized,  // Covers emulated orders
+            (OrderStatus::Submitted, OrderEvent::OrderUpdated(_)) => OrderStatus::Submitted,
+            (OrderStatus::Accepted, OrderEvent::OrderUpdated(_)) => OrderStatus::Accepted,
+            (OrderStatus::PendingUpdate, OrderEvent::OrderUpdated(_)) => OrderStatus::Accepted,  // Restored to the status before the request in `OrderCore::apply`
+            (OrderStatus::Triggered, OrderEvent::OrderUpdated(_)) => OrderStatus::Triggered,
+            (OrderStatus::PartiallyFilled, OrderEvent::OrderUpdated(_)) => OrderStatus::PartiallyFilled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
     }
 }
 
-pub trait Order {
+/// An order, which dereferences to its shared [`OrderCore`] state.
+pub trait Order: DerefMut<Target = OrderCore> {
     fn status(&self) -> OrderStatus;
     fn trader_id(&self) -> TraderId;
     fn strategy_id(&self) -> StrategyId;
@@ -131,6 +358,26 @@ pub trait Order {
     fn is_reduce_only(&self) -> bool;
     fn is_quote_quantity(&self) -> bool;
     fn emulation_trigger(&self) -> Option<TriggerType>;
+
+    fn expire_time(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        None
+    }
+
+    fn limit_offset(&self) -> Option<Price> {
+        None
+    }
+
+    fn trailing_offset(&self) -> Option<Price> {
+        None
+    }
+
+    fn trailing_offset_type(&self) -> Option<TrailingOffsetType> {
+        None
+    }
     fn contingency_type(&self) -> Option<ContingencyType>;
     fn order_list_id(&self) -> Option<OrderListId>;
     fn linked_order_ids(&self) -> Option<Vec<ClientOrderId>>;
@@ -162,6 +409,86 @@ pub trait Order {
 
     fn trade_ids(&self) -> Vec<&TradeId>;
 
+    fn modify_count(&self) -> u32 {
+        self.deref().modify_count
+    }
+
+    fn commissions(&self) -> HashMap<Currency, Money> {
+        self.deref().commissions.clone()
+    }
+
+    /// Applies the given `event` to the order, advancing its status and updating its
+    /// fill state, and appends it to the order's events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event is for another order, or is not a valid
+    /// transition from the current status (including any event once the order is closed).
+    fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        self.deref_mut().apply(event)
+    }
+
+    /// Reconciles the order with the venue `report` of its current state, without
+    /// requiring the full chain of events leading to that state.
+    ///
+    /// The status is set to the reported status. Any quantity filled beyond the order's
+    /// `filled_qty` is applied as a single fill (with `reconciliation: true`) priced so
+    /// the average price matches the reported average price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the report is for another order, the order is closed with a
+    /// different state, or the reported filled quantity has decreased or has no
+    /// average price.
+    fn reconcile(&mut self, report: OrderStatusReport) -> Result<(), OrderError> {
+        self.deref_mut().reconcile(report)
+    }
+
+    /// Returns the `OrderInitialized` for the current specification of the order (such
+    /// as its quantity and prices), without its events or fill state.
+    fn init_event(&self) -> OrderInitialized {
+        OrderInitialized {
+            trader_id: self.trader_id(),
+            strategy_id: self.strategy_id(),
+            instrument_id: self.instrument_id(),
+            client_order_id: self.client_order_id(),
+            order_side: self.side(),
+            order_type: self.order_type(),
+            quantity: self.quantity(),
+            price: self.price(),
+            trigger_price: self.trigger_price(),
+            trigger_type: self.trigger_type(),
+            time_in_force: self.time_in_force(),
+            expire_time: self.expire_time(),
+            post_only: self.is_post_only(),
+            reduce_only: self.is_reduce_only(),
+            quote_quantity: self.is_quote_quantity(),
+            display_qty: self.display_qty(),
+            limit_offset: self.limit_offset(),
+            trailing_offset: self.trailing_offset(),
+            trailing_offset_type: self.trailing_offset_type(),
+            emulation_trigger: self.emulation_trigger(),
+            contingency_type: self.contingency_type(),
+            order_list_id: self.order_list_id(),
+            linked_order_ids: self.linked_order_ids(),
+            parent_order_id: self.parent_order_id(),
+            exec_algorithm_id: self.exec_algorithm_id(),
+            exec_algorithm_params: self.exec_algorithm_params(),
+            exec_spawn_id: self.exec_spawn_id(),
+            tags: self.tags(),
+            event_id: self.init_id(),
+            ts_event: self.ts_init(),
+            ts_init: self.ts_init(),
+            reconciliation: false,
+        }
+    }
+
+    /// Returns the order serialized to JSON as its [`Order::init_event`] (see [`from_json`]).
+    fn to_json(&self) -> String {
+        // Unwrapping is safe when serializing a valid object
+        serde_json::to_string(&self.init_event()).unwrap()
+    }
+
     fn is_buy(&self) -> bool {
         self.side() == OrderSide::Buy
     }
@@ -178,10 +505,119 @@ pub trait Order {
         self.order_type() == OrderType::Market
     }
 
+    /// Returns the notional value of the filled quantity at the average fill price,
+    /// or `None` if the order has not been filled.
+    ///
+    /// The notional is `filled_qty * multiplier * avg_px` in the quote currency, or
+    /// `filled_qty * multiplier / avg_px` in the base currency for inverse instruments.
+    ///
+    /// # Panics
+    ///
+    /// If the instrument is inverse with no base currency.
+    fn filled_notional(&self, instrument: &dyn Instrument) -> Option<Money> {
+        let avg_px = self.avg_px()?;
+        if self.filled_qty().is_zero() {
+            return None;
+        }
+
+        let qty = self.filled_qty().as_f64() * instrument.multiplier().as_f64();
+        let notional = if instrument.is_inverse() {
+            let currency = instrument
+                .base_currency()
+                .expect("Error: no base currency for notional calculation");
+            Money::new(qty / avg_px, *currency)
+        } else {
+            Money::new(qty * avg_px, *instrument.quote_currency())
+        };
+        Some(notional)
+    }
+
+    /// Returns the realized PnL of the filled quantity against the `entry_px` of the
+    /// position being closed, net of accumulated commissions, in the given `currency`
+    /// (typically the quote currency of the instrument).
+    ///
+    /// The gross PnL of a SELL order is `(avg_px - entry_px) * filled_qty * multiplier`,
+    /// and the reverse for a BUY order.
+    ///
+    /// Returns `None` if the order has not been filled, or if any commission was
+    /// charged in a currency other than `currency`, as it cannot be netted without
+    /// an exchange rate.
+    fn realized_pnl(
+        &self,
+        entry_px: Price,
+        multiplier: Quantity,
+        currency: Currency,
+    ) -> Option<Money> {
+        let avg_px = self.avg_px()?;
+        if self.filled_qty().is_zero() {
+            return None;
+        }
+
+        let px_diff = if self.is_buy() {
+            entry_px.as_f64() - avg_px
+        } else {
+            avg_px - entry_px.as_f64()
+        };
+        let gross_pnl = px_diff * self.filled_qty().as_f64() * multiplier.as_f64();
+
+        let mut commissions = 0.0;
+        for commission in self.commissions().values() {
+            if commission.currency != currency {
+                return None;
+            }
+            commissions += commission.as_f64();
+        }
+
+        Some(Money::new(gross_pnl - commissions, currency))
+    }
+
+    /// Returns the average fill price adjusted by the accumulated commissions per unit
+    /// of the filled quantity (`filled_qty * multiplier`), at the precision of the
+    /// fill prices.
+    ///
+    /// Commissions worsen the effective price, so are added for a BUY order and
+    /// subtracted for a SELL order.
+    ///
+    /// Returns `None` if the order has not been filled, or if commissions were
+    /// charged in more than one currency.
+    fn net_avg_px(&self, multiplier: Quantity) -> Option<Price> {
+        let avg_px = self.avg_px()?;
+        if self.filled_qty().is_zero() {
+            return None;
+        }
+        let precision = self.events().iter().rev().find_map(|event| match event {
+            OrderEvent::OrderFilled(fill) => Some(fill.last_px.precision),
+            _ => None,
+        })?;
+
+        let commissions = self.commissions();
+        if commissions.len() > 1 {
+            return None;
+        }
+        let commission: f64 = commissions.values().map(Money::as_f64).sum();
+        let per_unit = commission / (self.filled_qty().as_f64() * multiplier.as_f64());
+
+        let net_avg_px = if self.is_buy() {
+            avg_px + per_unit
+        } else {
+            avg_px - per_unit
+        };
+        Some(Price::new(net_avg_px, precision))
+    }
+
+    /// Returns whether the order is held locally by the emulator, which is the case
+    /// when it has an emulation trigger and has not yet been released.
     fn is_emulated(&self) -> bool {
         self.emulation_trigger().is_some()
     }
 
+    /// Returns whether the order is active locally, which is the case when it has
+    /// been initialized (including whilst emulated or after release) but not yet
+    /// submitted to the trading venue.
+    fn is_active_local(&self) -> bool {
+        self.status() == OrderStatus::Initialized
+    }
+
     fn is_contingency(&self) -> bool {
         self.contingency_type().is_some()
     }
@@ -210,14 +646,7 @@ pub trait Order {
     }
 
     fn is_closed(&self) -> bool {
-        matches!(
-            self.status(),
-            OrderStatus::Denied
-                | OrderStatus::Rejected
-                | OrderStatus::Canceled
-                | OrderStatus::Expired
-                | OrderStatus::Filled
-        )
+        self.status().is_closed()
     }
 
     fn is_inflight(&self) -> bool {
@@ -239,8 +668,16 @@ pub trait Order {
 
 pub struct OrderCore {
     pub events: Vec<OrderEvent>,
+    /// The IDs of the recent events applied to the order, used to recognize redeliveries.
+    ///
+    /// With `max_events` set this is bounded to twice `max_events` (plus the init event),
+    /// so a redelivery of an event evicted before that window is no longer recognized.
+    pub event_ids: HashSet<UUID4>,
+    /// The IDs in `event_ids` in the order applied, for bounding the window.
+    event_id_window: VecDeque<UUID4>,
     pub venue_order_ids: Vec<VenueOrderId>,
     pub trade_ids: Vec<TradeId>,
+    pub commissions: HashMap<Currency, Money>,
     pub previous_status: Option<OrderStatus>,
     pub has_price: bool,
     pub has_trigger_price: bool,
@@ -277,6 +714,24 @@ pub struct OrderCore {
     pub init_id: UUID4,
     pub ts_init: UnixNanos,
     pub ts_last: UnixNanos,
+    /// The session auction time for `AtTheOpen`/`AtTheClose` orders, which must be
+    /// set before the order can be filled or expired.
+    pub auction_time: Option<UnixNanos>,
+    /// The number of `OrderUpdated` events applied to the order.
+    pub modify_count: u32,
+    /// The optional maximum number of modifications permitted for the order.
+    pub max_modifications: Option<u32>,
+    /// The optional maximum number of events retained for the order (unbounded if `None`),
+    /// in addition to the init event.
+    ///
+    /// Once exceeded the oldest event after the init event is evicted, whatever its kind,
+    /// so a long-lived order receiving many amendments does not grow without bound. The
+    /// tradeoff is audit completeness: evicted events are gone for audit and replay
+    /// purposes, with `evicted_event_count` the only trace left, so a full history must be
+    /// kept elsewhere (such as the persisted event stream) where one is required.
+    pub max_events: Option<usize>,
+    /// The number of events evicted from `events` due to `max_events`.
+    pub evicted_event_count: usize,
 }
 
 impl OrderCore {
@@ -308,8 +763,11 @@ impl OrderCore {
     ) -> Self {
         Self {
             events: Vec::new(),
+            event_ids: HashSet::new(),
+            event_id_window: VecDeque::new(),
             venue_order_ids: Vec::new(),
             trade_ids: Vec::new(),
+            commissions: HashMap::new(),
             previous_status: None,
             has_price: true,          // TODO
             has_trigger_price: false, // TODO
@@ -346,12 +804,59 @@ impl OrderCore {
             init_id,
             ts_init,
             ts_last: ts_init,
+            auction_time: None,
+            modify_count: 0,
+            max_modifications: None,
+            max_events: None,
+            evicted_event_count: 0,
         }
     }
 
-    fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
-        let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
+    pub(crate) fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        if event.client_order_id() != self.client_order_id {
+            return Err(OrderError::EventMismatch(
+                self.client_order_id,
+                event.client_order_id(),
+            ));
+        }
+
+        // A closed order is immutable, though a redelivery of an event already applied
+        // (matched by its event ID, such as a duplicate cancel) is accepted without effect
+        if self.status.is_closed() {
+            if self.event_ids.contains(&event.event_id()) {
+                return Ok(());
+            }
+            return Err(OrderError::AlreadyClosed(self.status));
+        }
+
+        if self.time_in_force.is_auction() {
+            self.check_auction(&event)?;
+        }
+
+        if let (OrderEvent::OrderUpdated(_), Some(max_modifications)) =
+            (&event, self.max_modifications)
+        {
+            if self.modify_count >= max_modifications {
+                return Err(OrderError::TooManyModifications(max_modifications));
+            }
+        }
+
+        let mut new_status = self.status.transition(&event)?;
+        if self.status == OrderStatus::PendingUpdate {
+            if let OrderEvent::OrderUpdated(_) = event {
+                // A modified order returns to its status before the update was requested
+                new_status = self.previous_status.unwrap_or(new_status);
+            }
+        }
+        // Repeated pending requests keep the status before the first request
+        let is_repeated_pending = new_status == self.status
+            && matches!(
+                self.status,
+                OrderStatus::PendingUpdate | OrderStatus::PendingCancel
+            );
+        if !is_repeated_pending {
+            self.previous_status = Some(self.status);
+        }
         self.status = new_status;
 
         match &event {
@@ -365,15 +870,209 @@ impl OrderCore {
             OrderEvent::OrderCancelRejected(event) => self.cancel_rejected(event),
             OrderEvent::OrderUpdated(event) => self.updated(event),
             OrderEvent::OrderTriggered(event) => self.triggered(event),
+            OrderEvent::OrderReleased(event) => self.released(event),
             OrderEvent::OrderCanceled(event) => self.canceled(event),
             OrderEvent::OrderExpired(event) => self.expired(event),
+            OrderEvent::OrderPartiallyFilled(event) => self.filled(event),
+            OrderEvent::OrderFilled(event) => self.filled(event),
             _ => return Err(OrderError::UnrecognizedEvent),
         }
 
-        self.events.push(event);
+        // Events may be applied out of order, so only advance `ts_last`
+        self.ts_last = self.ts_last.max(event.ts_event());
+        let is_fill = matches!(
+            event,
+            OrderEvent::OrderPartiallyFilled(_) | OrderEvent::OrderFilled(_)
+        );
+        self.push_event(event);
+
+        if is_fill {
+            debug_assert!(
+                self.verify_consistency().is_ok(),
+                "{}",
+                self.verify_consistency().unwrap_err()
+            );
+        }
+        Ok(())
+    }
+
+    pub(crate) fn reconcile(&mut self, report: OrderStatusReport) -> Result<(), OrderError> {
+        if report.client_order_id != self.client_order_id {
+            return Err(OrderError::EventMismatch(
+                self.client_order_id,
+                report.client_order_id,
+            ));
+        }
+        if self.status.is_closed() {
+            if report.order_status == self.status && report.filled_qty == self.filled_qty {
+                return Ok(());
+            }
+            return Err(OrderError::AlreadyClosed(self.status));
+        }
+        if report.filled_qty < self.filled_qty {
+            return Err(OrderError::ReportedFilledQtyDecreased(
+                self.filled_qty,
+                report.filled_qty,
+            ));
+        }
+
+        self.account_id = Some(report.account_id);
+        if self.venue_order_id != Some(report.venue_order_id) {
+            self.venue_order_id = Some(report.venue_order_id);
+            self.venue_order_ids.push(report.venue_order_id);
+        }
+
+        if report.filled_qty > self.filled_qty {
+            let avg_px = report
+                .avg_px
+                .ok_or(OrderError::MissingReportedAvgPx(report.filled_qty))?;
+            let last_qty = report.filled_qty - self.filled_qty;
+            // Price the missing fill so the notional matches the reported average price
+            let filled_notional = self.avg_px.unwrap_or(0.0) * self.filled_qty.as_f64();
+            let notional = avg_px.as_f64() * report.filled_qty.as_f64();
+            let last_px = Price::new(
+                (notional - filled_notional) / last_qty.as_f64(),
+                avg_px.precision,
+            );
+            let fill = OrderFilled {
+                trader_id: self.trader_id,
+                strategy_id: self.strategy_id,
+                instrument_id: self.instrument_id,
+                client_order_id: self.client_order_id,
+                venue_order_id: report.venue_order_id,
+                account_id: report.account_id,
+                trade_id: TradeId::new(&report.report_id.to_string()),
+                position_id: self.position_id,
+                order_side: self.side,
+                order_type: self.order_type,
+                last_qty,
+                last_px,
+                currency: report.currency,
+                commission: Money::new(0.0, report.currency),
+                liquidity_side: LiquiditySide::NoLiquiditySide,
+                event_id: UUID4::new(),
+                ts_event: report.ts_last,
+                ts_init: report.ts_init,
+                reconciliation: true,
+            };
+            // The commission of the missing fill is unknown, so none is accrued
+            self.apply_fill(&fill);
+            self.avg_px = Some(avg_px.as_f64());
+            self.push_event(if report.order_status == OrderStatus::Filled {
+                OrderEvent::OrderFilled(fill)
+            } else {
+                OrderEvent::OrderPartiallyFilled(fill)
+            });
+        }
+
+        if report.order_status != self.status {
+            self.previous_status = Some(self.status);
+            self.status = report.order_status;
+        }
+        self.ts_last = self.ts_last.max(report.ts_last);
+        Ok(())
+    }
+
+    /// Verifies the fill state of the order is consistent with its applied fills, that is
+    /// `filled_qty` equals the sum of the fill quantities, and `avg_px` equals the filled
+    /// notional divided by `filled_qty` (within half a tick of the fill price precision).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first inconsistency found.
+    ///
+    /// Once events have been evicted (see `max_events`) the applied fills may no longer
+    /// be retained, so the fill state cannot be verified and the check passes.
+    pub fn verify_consistency(&self) -> Result<(), OrderError> {
+        if self.evicted_event_count > 0 {
+            return Ok(());
+        }
+        let mut filled_qty = Quantity::zero(self.quantity.precision);
+        let mut notional = 0.0;
+        let mut price_precision = 0;
+        for event in &self.events {
+            if let OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill) = event {
+                filled_qty += fill.last_qty;
+                notional += fill.last_qty.as_f64() * fill.last_px.as_f64();
+                price_precision = price_precision.max(fill.last_px.precision);
+            }
+        }
+
+        if filled_qty != self.filled_qty {
+            return Err(OrderError::InconsistentFilledQty(
+                self.filled_qty,
+                filled_qty,
+            ));
+        }
+
+        let expected_avg_px = if filled_qty.is_zero() {
+            None
+        } else {
+            Some(notional / filled_qty.as_f64())
+        };
+        let tolerance = 0.5 * 10_f64.powi(-i32::from(price_precision));
+        let is_consistent = match (self.avg_px, expected_avg_px) {
+            (None, None) => true,
+            (Some(avg_px), Some(expected)) => (avg_px - expected).abs() <= tolerance,
+            _ => false,
+        };
+        if !is_consistent {
+            return Err(OrderError::InconsistentAvgPx(self.avg_px, expected_avg_px));
+        }
         Ok(())
     }
 
+    pub(crate) fn push_event(&mut self, event: OrderEvent) {
+        let event_id = event.event_id();
+        if self.event_ids.insert(event_id) {
+            self.event_id_window.push_back(event_id);
+        }
+        self.events.push(event);
+        self.evict_events();
+    }
+
+    /// Evicts the oldest events after the init event (`events[0]`, always retained) while
+    /// they exceed `max_events`, and bounds the recent event IDs to match.
+    fn evict_events(&mut self) {
+        let Some(max_events) = self.max_events else {
+            return;
+        };
+        if self.events.len() > max_events + 1 {
+            let excess = self.events.len() - (max_events + 1);
+            self.events.drain(1..=excess);
+            self.evicted_event_count += excess;
+        }
+        while self.event_id_window.len() > 2 * max_events + 1 {
+            if let Some(event_id) = self.event_id_window.pop_front() {
+                self.event_ids.remove(&event_id);
+            }
+        }
+    }
+
+    /// Sets the session auction time for an `AtTheOpen`/`AtTheClose` order.
+    pub fn set_auction_time(&mut self, auction_time: UnixNanos) {
+        self.auction_time = Some(auction_time);
+    }
+
+    /// Checks an auction order remains pending until the session auction time, by
+    /// rejecting any fill or expiry which occurs before it.
+    pub(crate) fn check_auction(&self, event: &OrderEvent) -> Result<(), OrderError> {
+        match event {
+            OrderEvent::OrderPartiallyFilled(_)
+            | OrderEvent::OrderFilled(_)
+            | OrderEvent::OrderExpired(_) => {
+                let auction_time = self
+                    .auction_time
+                    .ok_or(OrderError::MissingAuctionTime(self.time_in_force))?;
+                if event.ts_event() < auction_time {
+                    return Err(OrderError::BeforeAuction(event.ts_event(), auction_time));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn denied(&self, _event: &OrderDenied) {
         // Do nothing else
     }
@@ -408,6 +1107,10 @@ impl OrderCore {
 
     fn triggered(&mut self, _event: &OrderTriggered) {}
 
+    fn released(&mut self, _event: &OrderReleased) {
+        self.emulation_trigger = None;
+    }
+
     fn canceled(&mut self, _event: &OrderCanceled) {}
 
     fn expired(&mut self, _event: &OrderExpired) {}
@@ -443,19 +1146,29 @@ impl OrderCore {
         // }
 
         self.quantity = event.quantity;
-        self.leaves_qty = self.quantity - self.filled_qty;
+        self.leaves_qty = self.quantity.saturating_sub(self.filled_qty);
+        self.modify_count += 1;
     }
 
     fn filled(&mut self, event: &OrderFilled) {
+        *self
+            .commissions
+            .entry(event.commission.currency)
+            .or_insert_with(|| Money::new(0.0, event.commission.currency)) += event.commission;
+        self.apply_fill(event);
+    }
+
+    /// Applies the `event` fill to the order state, excluding its commission.
+    fn apply_fill(&mut self, event: &OrderFilled) {
         self.venue_order_id = Some(event.venue_order_id);
         self.position_id = event.position_id;
         self.trade_ids.push(event.trade_id);
         self.last_trade_id = Some(event.trade_id);
         self.liquidity_side = Some(event.liquidity_side);
-        self.filled_qty += &event.last_qty;
-        self.leaves_qty -= &event.last_qty;
-        self.ts_last = event.ts_event;
+        // The average price is weighted by the filled quantity prior to this fill
         self.set_avg_px(&event.last_qty, &event.last_px);
+        self.filled_qty += &event.last_qty;
+        self.leaves_qty = self.leaves_qty.saturating_sub(event.last_qty);
         // self.set_slippage(); // TODO
     }
 
@@ -521,6 +1234,27 @@ impl OrderCore {
             _ => true,
         }
     }
+
+    /// Returns the individual tags of the order, split on [`TAGS_DELIMITER`].
+    ///
+    /// Surrounding whitespace is trimmed and empty tags are skipped.
+    pub fn tags_vec(&self) -> Vec<String> {
+        self.tags
+            .as_deref()
+            .map(|tags| {
+                tags.split(TAGS_DELIMITER)
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the order has the given `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags_vec().iter().any(|t| t == tag)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -532,9 +1266,15 @@ mod tests {
 
     use super::*;
     use crate::{
+        currencies::{USD, USDT},
         enums::{OrderSide, OrderStatus, PositionSide},
-        events::order::{OrderDeniedBuilder, OrderEvent, OrderInitializedBuilder},
-        orders::market::MarketOrder,
+        events::order::{
+            OrderAcceptedBuilder, OrderCanceledBuilder, OrderDeniedBuilder, OrderEvent,
+            OrderExpiredBuilder, OrderInitializedBuilder, OrderPendingUpdateBuilder,
+            OrderRejectedBuilder, OrderReleasedBuilder, OrderSubmittedBuilder,
+            OrderTriggeredBuilder, OrderUpdatedBuilder,
+        },
+        instruments::{crypto_perpetual::CryptoPerpetual, equity::Equity},
     };
 
     #[rstest(
@@ -587,7 +1327,7 @@ mod tests {
             .quantity(order_qty)
             .build()
             .unwrap()
-            .into();
+            .try_into().unwrap();
 
         assert_eq!(
             order.would_reduce_only(position_side, position_qty),
@@ -595,53 +1335,1337 @@ mod tests {
         );
     }
 
+    #[rustfmt::skip]
+    #[rstest(
+        order_type, expected_field,
+        case(OrderType::Limit, "price"),
+        case(OrderType::StopMarket, "trigger_price"),
+        case(OrderType::StopLimit, "price"),
+        case(OrderType::MarketIfTouched, "trigger_price"),
+        case(OrderType::LimitIfTouched, "price"),
+        case(OrderType::TrailingStopMarket, "trigger_type"),
+        case(OrderType::TrailingStopLimit, "price"),
+    )]
+    fn test_validate_initialized_missing_field(order_type: OrderType, expected_field: &str) {
+        let init = OrderInitializedBuilder::default()
+            .order_type(order_type)
+            .build()
+            .unwrap();
+
+        let result = validate_initialized(&init).err();
+
+        match result {
+            Some(OrderError::MissingRequiredField(result_type, field)) => {
+                assert_eq!(result_type, order_type);
+                assert_eq!(field, expected_field);
+            }
+            _ => panic!("Expected missing field error, was {result:?}"),
+        }
+    }
+
+    #[rstest(order_type, case(OrderType::Market), case(OrderType::MarketToLimit))]
+    fn test_validate_initialized_without_required_fields(order_type: OrderType) {
+        let init = OrderInitializedBuilder::default()
+            .order_type(order_type)
+            .build()
+            .unwrap();
+
+        assert!(validate_initialized(&init).is_ok());
+    }
+
     #[test]
-    fn test_order_state_transition_denied() {
-        let init = OrderInitializedBuilder::default().build().unwrap();
-        let denied = OrderDeniedBuilder::default().build().unwrap();
-        let mut order: MarketOrder = init.into();
-        let event = OrderEvent::OrderDenied(denied);
+    fn test_is_valid_combination_matrix() {
+        use strum::IntoEnumIterator;
 
-        let _ = order.apply(event.clone());
+        let invalid = [
+            (OrderType::Market, TimeInForce::Gtd),
+            (OrderType::MarketToLimit, TimeInForce::AtTheOpen),
+            (OrderType::MarketToLimit, TimeInForce::AtTheClose),
+            (OrderType::StopMarket, TimeInForce::AtTheOpen),
+            (OrderType::StopMarket, TimeInForce::AtTheClose),
+            (OrderType::StopLimit, TimeInForce::AtTheOpen),
+            (OrderType::StopLimit, TimeInForce::AtTheClose),
+            (OrderType::MarketIfTouched, TimeInForce::AtTheOpen),
+            (OrderType::MarketIfTouched, TimeInForce::AtTheClose),
+            (OrderType::LimitIfTouched, TimeInForce::AtTheOpen),
+            (OrderType::LimitIfTouched, TimeInForce::AtTheClose),
+            (OrderType::TrailingStopMarket, TimeInForce::AtTheOpen),
+            (OrderType::TrailingStopMarket, TimeInForce::AtTheClose),
+            (OrderType::TrailingStopLimit, TimeInForce::AtTheOpen),
+            (OrderType::TrailingStopLimit, TimeInForce::AtTheClose),
+        ];
 
-        assert_eq!(order.status, OrderStatus::Denied);
-        assert!(order.is_closed());
-        assert!(!order.is_open());
-        assert_eq!(order.event_count(), 1);
-        assert_eq!(order.last_event(), &event);
+        for order_type in OrderType::iter() {
+            for time_in_force in TimeInForce::iter() {
+                assert_eq!(
+                    is_valid_combination(order_type, time_in_force),
+                    !invalid.contains(&(order_type, time_in_force)),
+                    "{order_type} with {time_in_force}"
+                );
+            }
+        }
     }
 
-    // #[test]
-    // fn test_buy_order_life_cycle_to_filled() {
-    //     let init = OrderInitializedBuilder::default().build().unwrap();
-    //     let submitted = OrderSubmittedBuilder::default().build().unwrap();
-    //     let accepted = OrderAcceptedBuilder::default().build().unwrap();
-    //
-    //     // TODO: We should derive defaults for the below
-    //     let filled = OrderFilledBuilder::default()
-    //         .trader_id(TraderId::default())
-    //         .strategy_id(StrategyId::default())
-    //         .instrument_id(InstrumentId::default())
-    //         .account_id(AccountId::default())
-    //         .client_order_id(ClientOrderId::default())
-    //         .venue_order_id(VenueOrderId::default())
-    //         .position_id(None)
-    //         .order_side(OrderSide::Buy)
-    //         .order_type(OrderType::Market)
-    //         .trade_id(TradeId::new("001"))
-    //         .event_id(UUID4::default())
-    //         .ts_event(UnixNanos::default())
-    //         .ts_init(UnixNanos::default())
-    //         .reconciliation(false)
-    //         .build()
-    //         .unwrap();
-    //
-    //     let client_order_id = init.client_order_id;
-    //     let mut order: MarketOrder = init.into();
-    //     let _ = order.apply(OrderEvent::OrderSubmitted(submitted));
-    //     let _ = order.apply(OrderEvent::OrderAccepted(accepted));
-    //     let _ = order.apply(OrderEvent::OrderFilled(filled));
-    //
-    //     assert_eq!(order.client_order_id, client_order_id);
-    // }
+    #[test]
+    fn test_validate_initialized_with_invalid_time_in_force() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopMarket)
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::Default))
+            .time_in_force(TimeInForce::AtTheOpen)
+            .build()
+            .unwrap();
+
+        let result = validate_initialized(&init).err();
+
+        assert_eq!(
+            result.unwrap().to_string(),
+            "Time in force AT_THE_OPEN is not valid for STOP_MARKET order"
+        );
+    }
+
+    #[test]
+    fn test_validate_initialized_stop_limit_missing_trigger_type() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopLimit)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .build()
+            .unwrap();
+
+        let result = validate_initialized(&init).err();
+
+        assert_eq!(
+            result.unwrap().to_string(),
+            "Missing required field `trigger_type` for STOP_LIMIT order"
+        );
+    }
+
+    #[test]
+    fn test_validate_initialized_trailing_stop_limit_missing_offsets() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::TrailingStopLimit)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::Default))
+            .build()
+            .unwrap();
+
+        let result = validate_initialized(&init).err();
+
+        assert_eq!(
+            result.unwrap().to_string(),
+            "Missing required field `limit_offset` for TRAILING_STOP_LIMIT order"
+        );
+    }
+
+    #[test]
+    fn test_limit_order_try_from_initialized_without_price() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .build()
+            .unwrap();
+
+        let result = LimitOrder::try_from(init);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::MissingRequiredField(OrderType::Limit, "price"))
+        ));
+    }
+
+    #[test]
+    fn test_limit_order_try_from_initialized_with_unexpected_order_type() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Market)
+            .build()
+            .unwrap();
+
+        let result = LimitOrder::try_from(init);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnexpectedOrderType(
+                OrderType::Limit,
+                OrderType::Market
+            ))
+        ));
+    }
+
+    #[rstest(
+        tags,
+        expected,
+        case(None, vec![]),
+        case(Some(""), vec![]),
+        case(Some("ENTRY"), vec!["ENTRY"]),
+        case(Some("ENTRY,SCALP"), vec!["ENTRY", "SCALP"]),
+        case(Some(" ENTRY , ,SCALP "), vec!["ENTRY", "SCALP"])
+    )]
+    fn test_tags_vec(tags: Option<&str>, expected: Vec<&str>) {
+        let order: MarketOrder = OrderInitializedBuilder::default()
+            .tags(tags.map(String::from))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(order.tags_vec(), expected);
+    }
+
+    #[test]
+    fn test_has_tag() {
+        let order: MarketOrder = OrderInitializedBuilder::default()
+            .tags(Some("ENTRY,SCALP".to_string()))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(order.has_tag("ENTRY"));
+        assert!(order.has_tag("SCALP"));
+        assert!(!order.has_tag("EXIT"));
+        assert!(!order.has_tag(""));
+    }
+
+    #[test]
+    fn test_has_tag_when_no_tags() {
+        let order = MarketOrder::default();
+
+        assert!(order.tags_vec().is_empty());
+        assert!(!order.has_tag("ENTRY"));
+    }
+
+    #[test]
+    fn test_filled_notional_when_unfilled() {
+        let order = MarketOrder::default();
+        let instrument = CryptoPerpetual::default();
+
+        assert_eq!(order.filled_notional(&instrument), None);
+    }
+
+    fn accepted_market_order(order_side: OrderSide, quantity: Quantity) -> MarketOrder {
+        let mut order: MarketOrder = OrderInitializedBuilder::default()
+            .order_side(order_side)
+            .quantity(quantity)
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_filled_notional_when_partially_filled() {
+        let mut order = accepted_market_order(OrderSide::Buy, Quantity::from("2.000"));
+        let instrument = CryptoPerpetual::default();
+        let fill = order_filled(&order, Quantity::from("0.500"), Price::from("30000.5"), 1);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+
+        let notional = order.filled_notional(&instrument);
+
+        assert_eq!(notional, Some(Money::new(15_000.25, *USDT)));
+    }
+
+    #[test]
+    fn test_filled_notional_with_multiplier() {
+        let mut order = accepted_market_order(OrderSide::Buy, Quantity::from(200));
+        let instrument = Equity {
+            multiplier: Quantity::from(10),
+            ..Equity::default()
+        };
+        let fill = order_filled(&order, Quantity::from(100), Price::from("150.25"), 1);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+
+        let notional = order.filled_notional(&instrument);
+
+        assert_eq!(notional, Some(Money::new(150_250.0, *USD)));
+    }
+
+    #[test]
+    fn test_emulated_stop_before_release() {
+        let order: StopLimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopLimit)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::Default))
+            .emulation_trigger(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(order.is_emulated());
+        assert!(order.is_active_local());
+        assert!(!order.is_open());
+        assert!(!order.is_inflight());
+    }
+
+    #[test]
+    fn test_emulated_stop_after_release() {
+        let mut order: StopLimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopLimit)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::Default))
+            .emulation_trigger(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let released = OrderReleasedBuilder::default()
+            .released_price(Price::from("1.00010"))
+            .build()
+            .unwrap();
+
+        order.apply(OrderEvent::OrderReleased(released)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.emulation_trigger(), None);
+        assert!(!order.is_emulated());
+        assert!(order.is_active_local());
+    }
+
+    #[test]
+    fn test_is_active_local_after_submitted() {
+        let mut order = MarketOrder::default();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+
+        assert!(!order.is_active_local());
+        assert!(order.is_inflight());
+    }
+
+    #[test]
+    fn test_apply_event_with_mismatched_client_order_id() {
+        let mut order = MarketOrder::default();
+        let denied = OrderDeniedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-999"))
+            .build()
+            .unwrap();
+
+        let result = order.apply(OrderEvent::OrderDenied(denied));
+
+        assert!(matches!(result, Err(OrderError::EventMismatch(_, _))));
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.event_count(), 1);
+    }
+
+    #[test]
+    fn test_realized_pnl_when_unfilled() {
+        let order = MarketOrder::default();
+
+        assert_eq!(
+            order.realized_pnl(Price::from("100.00"), Quantity::from(1), *USD),
+            None
+        );
+    }
+
+    #[test]
+    fn test_realized_pnl_without_commissions() {
+        let mut order = accepted_market_order(OrderSide::Sell, Quantity::from(10));
+        let fill = order_filled(&order, Quantity::from(10), Price::from("105.00"), 1);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        let pnl = order.realized_pnl(Price::from("100.00"), Quantity::from(1), *USD);
+
+        assert_eq!(pnl, Some(Money::new(50.0, *USD)));
+    }
+
+    #[test]
+    fn test_realized_pnl_with_commissions() {
+        let mut order = accepted_market_order(OrderSide::Buy, Quantity::from(10));
+        let mut fill = order_filled(&order, Quantity::from(10), Price::from("98.00"), 1);
+        fill.commission = Money::new(1.5, *USD);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        // Buying back a short position entered at 100.00
+        let pnl = order.realized_pnl(Price::from("100.00"), Quantity::from(2), *USD);
+
+        assert_eq!(pnl, Some(Money::new(38.5, *USD)));
+    }
+
+    #[test]
+    fn test_realized_pnl_with_commissions_in_other_currency() {
+        let mut order = accepted_market_order(OrderSide::Sell, Quantity::from(10));
+        let mut fill = order_filled(&order, Quantity::from(10), Price::from("105.00"), 1);
+        fill.commission = Money::new(1.0, *USDT);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        let pnl = order.realized_pnl(Price::from("100.00"), Quantity::from(1), *USD);
+
+        assert_eq!(pnl, None);
+    }
+
+    #[test]
+    fn test_net_avg_px_when_unfilled() {
+        let order = accepted_limit_order(None);
+
+        assert_eq!(order.net_avg_px(Quantity::from(1)), None);
+    }
+
+    #[test]
+    fn test_net_avg_px_for_buy_with_taker_fee() {
+        let mut order = accepted_limit_order(None);
+        let mut fill = order_filled(&order, order.quantity, Price::from("1.00000"), 1);
+        fill.commission = Money::new(2.0, *USD);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        // 2.00 USD over 100,000 units is 0.00002 per unit
+        let net_avg_px = order.net_avg_px(Quantity::from(1)).unwrap();
+
+        assert_eq!(net_avg_px, Price::from("1.00002"));
+        assert_eq!(net_avg_px.precision, 5);
+        assert_eq!(
+            order.net_avg_px(Quantity::from(2)),
+            Some(Price::from("1.00001"))
+        );
+    }
+
+    #[test]
+    fn test_net_avg_px_for_sell_with_taker_fee() {
+        let mut order: LimitOrder = OrderInitializedBuilder::default()
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderSubmitted(
+                OrderSubmittedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderAccepted(
+                OrderAcceptedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+        let mut fill = order_filled(&order, order.quantity, Price::from("1.00000"), 1);
+        fill.commission = Money::new(2.0, *USD);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        assert_eq!(
+            order.net_avg_px(Quantity::from(1)),
+            Some(Price::from("0.99998"))
+        );
+    }
+
+    #[test]
+    fn test_net_avg_px_with_commissions_in_multiple_currencies() {
+        let mut order = accepted_limit_order(None);
+        let mut fill1 = order_filled(&order, Quantity::from(40_000), Price::from("1.00000"), 1);
+        fill1.commission = Money::new(1.0, *USD);
+        let mut fill2 = order_filled(&order, Quantity::from(60_000), Price::from("1.00000"), 2);
+        fill2.commission = Money::new(1.0, *USDT);
+        order
+            .apply(OrderEvent::OrderPartiallyFilled(fill1))
+            .unwrap();
+        order.apply(OrderEvent::OrderFilled(fill2)).unwrap();
+
+        assert_eq!(order.net_avg_px(Quantity::from(1)), None);
+    }
+
+    fn order_status_report(
+        order: &impl Order,
+        order_status: OrderStatus,
+        filled_qty: Quantity,
+        avg_px: Option<Price>,
+    ) -> OrderStatusReport {
+        OrderStatusReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::new("V-001"),
+            order_status,
+            filled_qty,
+            avg_px,
+            currency: *USD,
+            report_id: UUID4::new(),
+            ts_last: 5,
+            ts_init: 6,
+        }
+    }
+
+    #[test]
+    fn test_reconcile_from_initialized_to_partially_filled() {
+        let mut order: LimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let report = order_status_report(
+            &order,
+            OrderStatus::PartiallyFilled,
+            Quantity::from(40_000),
+            Some(Price::from("1.00010")),
+        );
+
+        order.reconcile(report).unwrap();
+
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.previous_status, Some(OrderStatus::Initialized));
+        assert_eq!(order.filled_qty, Quantity::from(40_000));
+        assert_eq!(order.leaves_qty, Quantity::from(60_000));
+        assert_eq!(order.avg_px, Some(1.0001));
+        assert_eq!(order.account_id, Some(AccountId::new("SIM-001")));
+        assert_eq!(order.venue_order_id, Some(VenueOrderId::new("V-001")));
+        assert_eq!(order.ts_last, 5);
+        assert!(order.is_open());
+        assert_eq!(order.event_count(), 2);
+        match order.last_event() {
+            OrderEvent::OrderPartiallyFilled(fill) => {
+                assert!(fill.reconciliation);
+                assert_eq!(fill.last_qty, Quantity::from(40_000));
+                assert_eq!(fill.last_px, Price::from("1.00010"));
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        assert!(order.verify_consistency().is_ok());
+    }
+
+    #[test]
+    fn test_reconcile_prices_missing_fill_to_match_reported_avg_px() {
+        let mut order = accepted_limit_order(None);
+        let fill = order_filled(&order, Quantity::from(50_000), Price::from("1.00000"), 1);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+        let report = order_status_report(
+            &order,
+            OrderStatus::Filled,
+            order.quantity,
+            Some(Price::from("1.00010")),
+        );
+
+        order.reconcile(report).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.filled_qty, order.quantity);
+        assert!(order.leaves_qty.is_zero());
+        assert_eq!(order.avg_px, Some(1.0001));
+        match order.last_event() {
+            OrderEvent::OrderFilled(fill) => {
+                assert!(fill.reconciliation);
+                assert_eq!(fill.last_qty, Quantity::from(50_000));
+                assert_eq!(fill.last_px, Price::from("1.00020"));
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        assert!(order.verify_consistency().is_ok());
+    }
+
+    #[test]
+    fn test_reconcile_fill_does_not_accrue_commission() {
+        let mut order = accepted_limit_order(None);
+        let mut fill = order_filled(&order, Quantity::from(50_000), Price::from("1.00000"), 1);
+        fill.currency = *USDT;
+        fill.commission = Money::new(1.0, *USDT);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+        let report = order_status_report(
+            &order,
+            OrderStatus::Filled,
+            order.quantity,
+            Some(Price::from("1.00000")),
+        );
+
+        order.reconcile(report).unwrap();
+
+        assert_eq!(
+            order.commissions(),
+            HashMap::from([(*USDT, Money::new(1.0, *USDT))])
+        );
+    }
+
+    #[test]
+    fn test_reconcile_status_only() {
+        let mut order = accepted_limit_order(None);
+        let report = order_status_report(&order, OrderStatus::Canceled, Quantity::zero(0), None);
+
+        order.reconcile(report.clone()).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Canceled);
+        assert_eq!(order.event_count(), 3);
+        // Reconciling a closed order to the same state is idempotent
+        assert!(order.reconcile(report).is_ok());
+    }
+
+    #[test]
+    fn test_reconcile_with_decreased_filled_qty() {
+        let mut order = accepted_limit_order(None);
+        let fill = order_filled(&order, Quantity::from(50_000), Price::from("1.00000"), 1);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+        let report = order_status_report(
+            &order,
+            OrderStatus::PartiallyFilled,
+            Quantity::from(10_000),
+            Some(Price::from("1.00000")),
+        );
+
+        let result = order.reconcile(report);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::ReportedFilledQtyDecreased(_, _))
+        ));
+        assert_eq!(order.filled_qty, Quantity::from(50_000));
+    }
+
+    #[test]
+    fn test_reconcile_fill_without_avg_px() {
+        let mut order = accepted_limit_order(None);
+        let report = order_status_report(&order, OrderStatus::Filled, order.quantity, None);
+
+        let result = order.reconcile(report);
+
+        assert!(matches!(result, Err(OrderError::MissingReportedAvgPx(_))));
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    fn order_filled(
+        order: &impl Order,
+        last_qty: Quantity,
+        last_px: Price,
+        ts_event: UnixNanos,
+    ) -> OrderFilled {
+        OrderFilled {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new("T-001"),
+            position_id: None,
+            order_side: order.side(),
+            order_type: order.order_type(),
+            last_qty,
+            last_px,
+            currency: *USD,
+            commission: Money::new(0.0, *USD),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::default(),
+            ts_event,
+            ts_init: ts_event,
+            reconciliation: false,
+        }
+    }
+
+    fn accepted_auction_order(time_in_force: TimeInForce) -> MarketOrder {
+        let mut order: MarketOrder = OrderInitializedBuilder::default()
+            .time_in_force(time_in_force)
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_at_the_close_order_filled_at_session_close() {
+        let session_close = 1_000;
+        let mut order = accepted_auction_order(TimeInForce::AtTheClose);
+        order.set_auction_time(session_close);
+
+        let early_fill = order_filled(
+            &order,
+            order.quantity,
+            Price::from("100.00"),
+            session_close - 1,
+        );
+        let result = order.apply(OrderEvent::OrderFilled(early_fill));
+
+        assert!(matches!(result, Err(OrderError::BeforeAuction(999, 1_000))));
+        assert_eq!(order.status, OrderStatus::Accepted);
+
+        let fill = order_filled(&order, order.quantity, Price::from("100.00"), session_close);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.filled_qty, order.quantity);
+        assert_eq!(order.ts_last(), session_close);
+    }
+
+    #[test]
+    fn test_at_the_close_order_expired_at_session_close() {
+        let session_close = 1_000;
+        let mut order = accepted_auction_order(TimeInForce::AtTheClose);
+        order.set_auction_time(session_close);
+
+        let expired = OrderExpiredBuilder::default()
+            .ts_event(session_close)
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderExpired(expired)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Expired);
+        assert!(order.is_closed());
+    }
+
+    #[test]
+    fn test_at_the_open_order_without_auction_time() {
+        let mut order = accepted_auction_order(TimeInForce::AtTheOpen);
+
+        let expired = OrderExpiredBuilder::default().build().unwrap();
+        let result = order.apply(OrderEvent::OrderExpired(expired));
+
+        assert!(matches!(
+            result,
+            Err(OrderError::MissingAuctionTime(TimeInForce::AtTheOpen))
+        ));
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    fn accepted_limit_order(max_modifications: Option<u32>) -> LimitOrder {
+        let mut order: LimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        order.max_modifications = max_modifications;
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    fn order_updated(quantity: Quantity) -> OrderEvent {
+        OrderEvent::OrderUpdated(
+            OrderUpdatedBuilder::default()
+                .quantity(quantity)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_modify_count_increments_on_update() {
+        let mut order = accepted_limit_order(None);
+        assert_eq!(order.modify_count(), 0);
+
+        order.apply(order_updated(Quantity::from(200_000))).unwrap();
+        order.apply(order_updated(Quantity::from(300_000))).unwrap();
+
+        assert_eq!(order.modify_count(), 2);
+        assert_eq!(order.quantity, Quantity::from(300_000));
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    #[test]
+    fn test_update_partially_filled_order_keeps_partially_filled_status() {
+        let mut order = accepted_limit_order(None);
+        let fill = order_filled(&order, Quantity::from(40_000), Price::from("1.00000"), 0);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update.clone()))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+
+        order.apply(order_updated(Quantity::from(200_000))).unwrap();
+
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.quantity, Quantity::from(200_000));
+        assert_eq!(order.leaves_qty, Quantity::from(160_000));
+    }
+
+    #[test]
+    fn test_update_triggered_order_keeps_triggered_status() {
+        let mut order = accepted_limit_order(None);
+        let triggered = OrderTriggeredBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderTriggered(triggered)).unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+
+        order.apply(order_updated(Quantity::from(200_000))).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Triggered);
+    }
+
+    #[test]
+    fn test_modify_count_up_to_and_past_max_modifications() {
+        let mut order = accepted_limit_order(Some(2));
+
+        order.apply(order_updated(Quantity::from(200_000))).unwrap();
+        order.apply(order_updated(Quantity::from(300_000))).unwrap();
+        let result = order.apply(order_updated(Quantity::from(400_000)));
+
+        assert!(matches!(result, Err(OrderError::TooManyModifications(2))));
+        assert_eq!(order.modify_count(), 2);
+        assert_eq!(order.quantity, Quantity::from(300_000));
+    }
+
+    #[test]
+    fn test_max_events_caps_retained_events() {
+        let mut order = accepted_limit_order(None);
+        order.max_events = Some(10);
+
+        for i in 1..=1_000 {
+            order
+                .apply(order_updated(Quantity::from(100_000 + i)))
+                .unwrap();
+            assert!(order.event_count() <= 11);
+        }
+
+        assert_eq!(order.event_count(), 11);
+        assert_eq!(order.evicted_event_count, 992);
+        assert_eq!(order.event_ids.len(), 21);
+        assert!(matches!(order.events()[0], OrderEvent::OrderInitialized(_)));
+        assert_eq!(order.modify_count(), 1_000);
+        assert_eq!(order.quantity, Quantity::from(101_000));
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    #[test]
+    fn test_max_events_evicts_fills_and_pins_init_event() {
+        let mut order = accepted_limit_order(None);
+        order.max_events = Some(3);
+
+        for i in 0..50 {
+            let fill = order_filled(&order, Quantity::from(1_000), Price::from("1.00000"), i);
+            order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+            assert!(order.event_count() <= 4);
+        }
+
+        assert_eq!(order.event_count(), 4);
+        assert_eq!(order.evicted_event_count, 49);
+        assert!(matches!(order.events()[0], OrderEvent::OrderInitialized(_)));
+        assert_eq!(order.filled_qty, Quantity::from(50_000));
+        assert!(order.verify_consistency().is_ok());
+    }
+
+    #[test]
+    fn test_events_unbounded_by_default() {
+        let mut order = accepted_limit_order(None);
+
+        for i in 1..=100 {
+            order
+                .apply(order_updated(Quantity::from(100_000 + i)))
+                .unwrap();
+        }
+
+        assert_eq!(order.event_count(), 103);
+        assert_eq!(order.evicted_event_count, 0);
+    }
+
+    #[test]
+    fn test_fill_after_cancel_is_rejected() {
+        let mut order = accepted_limit_order(None);
+        let canceled = OrderCanceledBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderCanceled(canceled)).unwrap();
+
+        let fill = order_filled(&order, order.quantity, Price::from("1.00000"), 1);
+        let result = order.apply(OrderEvent::OrderFilled(fill));
+
+        assert!(matches!(
+            result,
+            Err(OrderError::AlreadyClosed(OrderStatus::Canceled))
+        ));
+        assert_eq!(order.status, OrderStatus::Canceled);
+        assert!(order.filled_qty.is_zero());
+        assert_eq!(order.event_count(), 4);
+    }
+
+    #[test]
+    fn test_overfill_saturates_leaves_qty_at_zero() {
+        let mut order = accepted_limit_order(None);
+        let last_qty = order.quantity + Quantity::from(1);
+        let fill = order_filled(&order, last_qty, Price::from("1.00000"), 1);
+
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        assert_eq!(order.filled_qty, last_qty);
+        assert!(order.leaves_qty.is_zero());
+        assert_eq!(order.leaves_qty.precision, order.quantity.precision);
+    }
+
+    #[test]
+    fn test_duplicate_cancel_is_idempotent() {
+        let mut order = accepted_limit_order(None);
+        let canceled = OrderCanceledBuilder::default().build().unwrap();
+        order
+            .apply(OrderEvent::OrderCanceled(canceled.clone()))
+            .unwrap();
+
+        let result = order.apply(OrderEvent::OrderCanceled(canceled));
+
+        assert!(result.is_ok());
+        assert_eq!(order.status, OrderStatus::Canceled);
+        assert_eq!(order.event_count(), 4);
+    }
+
+    #[test]
+    fn test_redelivered_event_is_idempotent_after_eviction() {
+        let mut order = accepted_limit_order(None);
+        order.max_events = Some(2);
+        let updated = order_updated(Quantity::from(150_000));
+        order.apply(updated.clone()).unwrap();
+        order.apply(order_updated(Quantity::from(200_000))).unwrap();
+        let canceled = OrderCanceledBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderCanceled(canceled)).unwrap();
+        assert!(!order.events.contains(&updated));
+
+        let result = order.apply(updated);
+
+        assert!(result.is_ok());
+        assert_eq!(order.status, OrderStatus::Canceled);
+        assert_eq!(order.quantity, Quantity::from(200_000));
+    }
+
+    #[test]
+    fn test_different_cancel_after_cancel_is_rejected() {
+        let mut order = accepted_limit_order(None);
+        let canceled1 = OrderCanceledBuilder::default().build().unwrap();
+        let canceled2 = OrderCanceledBuilder::default().ts_event(1).build().unwrap();
+        order.apply(OrderEvent::OrderCanceled(canceled1)).unwrap();
+
+        let result = order.apply(OrderEvent::OrderCanceled(canceled2));
+
+        assert!(matches!(result, Err(OrderError::AlreadyClosed(_))));
+    }
+
+    #[test]
+    fn test_verify_consistency_when_unfilled() {
+        let order = MarketOrder::default();
+
+        assert!(order.verify_consistency().is_ok());
+    }
+
+    #[test]
+    fn test_verify_consistency_after_fills() {
+        let mut order = accepted_limit_order(None);
+        let fill1 = order_filled(&order, Quantity::from(40_000), Price::from("1.00000"), 1);
+        let fill2 = order_filled(&order, Quantity::from(60_000), Price::from("1.00010"), 2);
+
+        order
+            .apply(OrderEvent::OrderPartiallyFilled(fill1))
+            .unwrap();
+        order.apply(OrderEvent::OrderFilled(fill2)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert!(order.verify_consistency().is_ok());
+    }
+
+    #[test]
+    fn test_verify_consistency_with_corrupted_avg_px() {
+        let mut order = accepted_limit_order(None);
+        let fill = order_filled(&order, order.quantity, Price::from("1.00000"), 1);
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        order.avg_px = Some(1.00100);
+
+        assert!(matches!(
+            order.verify_consistency(),
+            Err(OrderError::InconsistentAvgPx(Some(_), Some(_)))
+        ));
+    }
+
+    #[test]
+    fn test_verify_consistency_with_corrupted_filled_qty() {
+        let mut order = accepted_limit_order(None);
+        let fill = order_filled(&order, Quantity::from(40_000), Price::from("1.00000"), 1);
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+
+        order.filled_qty = Quantity::from(50_000);
+
+        assert!(matches!(
+            order.verify_consistency(),
+            Err(OrderError::InconsistentFilledQty(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_ts_last_does_not_regress_when_applying_older_event() {
+        let mut order = MarketOrder::default();
+        let submitted = OrderSubmittedBuilder::default()
+            .ts_event(2_000)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .ts_event(1_000)
+            .build()
+            .unwrap();
+
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        assert_eq!(order.ts_last(), 2_000);
+
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Accepted);
+        assert_eq!(order.ts_last(), 2_000);
+    }
+
+    #[test]
+    fn test_order_state_transition_denied() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let denied = OrderDeniedBuilder::default().build().unwrap();
+        let mut order: MarketOrder = init.try_into().unwrap();
+        let event = OrderEvent::OrderDenied(denied);
+
+        let _ = order.apply(event.clone());
+
+        assert_eq!(order.status, OrderStatus::Denied);
+        assert!(order.is_closed());
+        assert!(!order.is_open());
+        assert_eq!(order.event_count(), 2);
+        assert_eq!(order.last_event(), &event);
+    }
+
+    #[test]
+    fn test_buy_order_life_cycle_to_filled() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let market_order: MarketOrder = init.try_into().unwrap();
+        let partial_fill = order_filled(
+            &market_order,
+            Quantity::from(40_000),
+            Price::from("1.00000"),
+            1_000,
+        );
+        let mut fill = order_filled(
+            &market_order,
+            Quantity::from(60_000),
+            Price::from("1.10000"),
+            2_000,
+        );
+        fill.trade_id = TradeId::new("T-002");
+        let mut order: Box<dyn Order> = Box::new(market_order);
+
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        assert_eq!(order.status(), OrderStatus::Submitted);
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        order
+            .apply(OrderEvent::OrderPartiallyFilled(partial_fill))
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_qty(), Quantity::from(40_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(60_000));
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(0));
+        assert!((order.avg_px().unwrap() - 1.06).abs() < 1e-9);
+        assert_eq!(order.ts_last(), 2_000);
+        assert_eq!(order.event_count(), 5);
+        assert!(order.is_closed());
+    }
+
+    #[test]
+    fn test_from_events_with_monotonic_events() {
+        let init = OrderInitializedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .ts_init(2_000)
+            .build()
+            .unwrap();
+
+        let order: MarketOrder = from_events(
+            vec![
+                OrderEvent::OrderInitialized(init),
+                OrderEvent::OrderSubmitted(submitted),
+                OrderEvent::OrderAccepted(accepted),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.event_count(), 3);
+    }
+
+    #[test]
+    fn test_from_events_with_event_before_init_is_non_monotonic() {
+        let init = OrderInitializedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .ts_init(999)
+            .build()
+            .unwrap();
+
+        let result: Result<MarketOrder, OrderError> = from_events(
+            vec![
+                OrderEvent::OrderInitialized(init),
+                OrderEvent::OrderSubmitted(submitted),
+                OrderEvent::OrderAccepted(accepted),
+            ],
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OrderError::NonMonotonicEvents(999, 1_000))
+        ));
+    }
+
+    #[test]
+    fn test_from_events_without_init_event() {
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+
+        let result: Result<MarketOrder, OrderError> =
+            from_events(vec![OrderEvent::OrderSubmitted(submitted)], None);
+
+        assert!(matches!(result, Err(OrderError::MissingInitialized)));
+    }
+
+    #[test]
+    fn test_market_order_json_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .order_side(OrderSide::Sell)
+            .quantity(Quantity::from(250))
+            .ts_event(1_000)
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let order = MarketOrder::try_from(init.clone()).unwrap();
+
+        let json = order.to_json();
+        let restored = from_json(&json).unwrap();
+
+        assert_eq!(restored.order_type(), OrderType::Market);
+        assert_eq!(restored.status(), OrderStatus::Initialized);
+        assert_eq!(restored.client_order_id(), order.client_order_id());
+        assert_eq!(restored.side(), OrderSide::Sell);
+        assert_eq!(restored.quantity(), Quantity::from(250));
+        assert_eq!(restored.init_id(), init.event_id);
+        assert_eq!(restored.ts_init(), 1_000);
+    }
+
+    #[test]
+    fn test_stop_market_order_json_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopMarket)
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::BidAsk))
+            .expire_time(Some(5_000))
+            .time_in_force(TimeInForce::Gtd)
+            .build()
+            .unwrap();
+        let order = StopMarketOrder::try_from(init.clone()).unwrap();
+
+        let json = order.to_json();
+        let restored = from_json(&json).unwrap();
+
+        assert_eq!(restored.order_type(), OrderType::StopMarket);
+        assert_eq!(restored.trigger_price(), Some(Price::from("1.00010")));
+        assert_eq!(restored.trigger_type(), Some(TriggerType::BidAsk));
+        assert_eq!(restored.time_in_force(), TimeInForce::Gtd);
+        assert_eq!(restored.price(), None);
+        assert_eq!(
+            serde_json::from_str::<OrderInitialized>(&json).unwrap(),
+            init
+        );
+    }
+
+    #[test]
+    fn test_boxed_order_json_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::TrailingStopLimit)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .limit_offset(Some(Price::from("0.00005")))
+            .trailing_offset(Some(Price::from("0.00010")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .expire_time(Some(5_000))
+            .time_in_force(TimeInForce::Gtd)
+            .display_qty(Some(Quantity::from(10)))
+            .build()
+            .unwrap();
+        let order: Box<dyn Order> = from_json(&serde_json::to_string(&init).unwrap()).unwrap();
+
+        let json = order.to_json();
+        let restored = from_json(&json).unwrap();
+
+        assert_eq!(order.init_event(), init);
+        assert_eq!(restored.init_event(), init);
+        assert_eq!(restored.order_type(), OrderType::TrailingStopLimit);
+        assert_eq!(restored.trailing_offset(), Some(Price::from("0.00010")));
+    }
+
+    #[test]
+    fn test_from_json_with_invalid_json() {
+        assert!(from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_from_json_with_missing_required_field() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopMarket)
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&init).unwrap();
+
+        let err = from_json(&json).err().unwrap();
+
+        assert!(matches!(
+            err.downcast_ref::<OrderError>(),
+            Some(OrderError::MissingRequiredField(
+                OrderType::StopMarket,
+                "trigger_price"
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_from_events_clamps_ts_event_within_max_skew() {
+        let init = OrderInitializedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .ts_event(1_050)
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .ts_event(2_100)
+            .ts_init(2_000)
+            .build()
+            .unwrap();
+
+        let order: MarketOrder = from_events(
+            vec![
+                OrderEvent::OrderInitialized(init),
+                OrderEvent::OrderSubmitted(submitted),
+                OrderEvent::OrderAccepted(accepted),
+            ],
+            Some(100),
+        )
+        .unwrap();
+
+        let events = order.events();
+        assert_eq!(events[1].ts_event(), 1_000);
+        assert_eq!(events[2].ts_event(), 2_000);
+        assert_eq!(order.ts_last(), 2_000);
+    }
+
+    #[test]
+    fn test_from_events_with_ts_event_beyond_max_skew() {
+        let init = OrderInitializedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .ts_event(1_101)
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+
+        let result: Result<MarketOrder, OrderError> = from_events(
+            vec![
+                OrderEvent::OrderInitialized(init),
+                OrderEvent::OrderSubmitted(submitted),
+            ],
+            Some(100),
+        );
+
+        assert!(matches!(
+            result,
+            Err(OrderError::ClockSkewExceeded(1_101, 1_000, 100))
+        ));
+    }
+
+    #[test]
+    fn test_from_events_without_max_skew_keeps_ts_event() {
+        let init = OrderInitializedBuilder::default()
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .ts_event(5_000)
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+
+        let order: MarketOrder = from_events(
+            vec![
+                OrderEvent::OrderInitialized(init),
+                OrderEvent::OrderSubmitted(submitted),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(order.events()[1].ts_event(), 5_000);
+    }
+
+    #[test]
+    fn test_fill_after_rejected_is_rejected() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let rejected = OrderRejectedBuilder::default().build().unwrap();
+        let mut order: MarketOrder = init.try_into().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderRejected(rejected)).unwrap();
+        let fill = order_filled(&order, Quantity::from(100_000), Price::from("1.00000"), 0);
+
+        let result = order.apply(OrderEvent::OrderFilled(fill));
+
+        assert!(matches!(
+            result,
+            Err(OrderError::AlreadyClosed(OrderStatus::Rejected))
+        ));
+        assert_eq!(order.status(), OrderStatus::Rejected);
+        assert_eq!(order.event_count(), 3);
+    }
+
+    #[test]
+    fn test_fill_before_submitted_is_invalid_transition() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let mut order: MarketOrder = init.try_into().unwrap();
+        let fill = order_filled(&order, Quantity::from(100_000), Price::from("1.00000"), 0);
+
+        let result = order.apply(OrderEvent::OrderFilled(fill));
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.filled_qty(), Quantity::from(0));
+        assert_eq!(order.event_count(), 1);
+    }
+
+    #[test]
+    fn test_submitted_twice_is_invalid_transition() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let mut order: MarketOrder = init.try_into().unwrap();
+        order
+            .apply(OrderEvent::OrderSubmitted(submitted.clone()))
+            .unwrap();
+
+        let result = order.apply(OrderEvent::OrderSubmitted(submitted));
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+        assert_eq!(order.status(), OrderStatus::Submitted);
+    }
 }
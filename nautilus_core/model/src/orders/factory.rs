@@ -0,0 +1,389 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::{
+    base::{validate_initialized, Order, OrderError},
+    limit::LimitOrder,
+    market::MarketOrder,
+    stop_market::StopMarketOrder,
+};
+use crate::{
+    enums::{OrderSide, TimeInForce, TriggerType},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    instruments::Instrument,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents the optional parameters for an order created by an [`OrderFactory`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderParams {
+    pub time_in_force: TimeInForce,
+    pub reduce_only: bool,
+    pub tags: Option<String>,
+}
+
+/// Provides [`OrderParams`] for a `GTC` order which is not reduce-only and has no tags.
+impl Default for OrderParams {
+    fn default() -> Self {
+        Self {
+            time_in_force: TimeInForce::Gtc,
+            reduce_only: false,
+            tags: None,
+        }
+    }
+}
+
+/// Provides a factory for creating orders for a single trader and strategy.
+///
+/// Each order is given the next client order ID in sequence (see
+/// [`ClientOrderId::generate`]) and a new `init_id`, initialized at the given `ts_init`.
+#[derive(Clone, Debug)]
+pub struct OrderFactory {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    seq: u64,
+}
+
+impl OrderFactory {
+    #[must_use]
+    pub fn new(trader_id: TraderId, strategy_id: StrategyId) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            seq: 0,
+        }
+    }
+
+    /// Returns the sequence number of the last client order ID generated.
+    #[must_use]
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Creates a new [`MarketOrder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is not valid for its type (see [`validate_initialized`]).
+    pub fn market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        params: OrderParams,
+        ts_init: UnixNanos,
+    ) -> Result<MarketOrder, OrderError> {
+        let order = MarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            self.next_client_order_id(),
+            order_side,
+            quantity,
+            params.time_in_force,
+            params.reduce_only,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            params.tags,
+            UUID4::new(),
+            ts_init,
+        );
+        validate_initialized(&order.init_event())?;
+        Ok(order)
+    }
+
+    /// Creates a new [`LimitOrder`] at the given `price`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is not valid for its type (see [`validate_initialized`]).
+    pub fn limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        params: OrderParams,
+        ts_init: UnixNanos,
+    ) -> Result<LimitOrder, OrderError> {
+        let order = LimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            self.next_client_order_id(),
+            order_side,
+            quantity,
+            price,
+            params.time_in_force,
+            None,
+            false,
+            params.reduce_only,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            params.tags,
+            UUID4::new(),
+            ts_init,
+        );
+        validate_initialized(&order.init_event())?;
+        Ok(order)
+    }
+
+    /// Creates a new [`StopMarketOrder`] for the `instrument` triggered at the given
+    /// `trigger_price`.
+    ///
+    /// A `trigger_type` of `Default` or `NoTrigger` is resolved to the default for the
+    /// `instrument` (see [`TriggerType::resolve`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is not valid for its type (see [`validate_initialized`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_market(
+        &mut self,
+        instrument: &dyn Instrument,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        params: OrderParams,
+        ts_init: UnixNanos,
+    ) -> Result<StopMarketOrder, OrderError> {
+        let order = StopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            *instrument.id(),
+            self.next_client_order_id(),
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.resolve(instrument),
+            params.time_in_force,
+            None,
+            false,
+            params.reduce_only,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            params.tags,
+            UUID4::new(),
+            ts_init,
+        );
+        validate_initialized(&order.init_event())?;
+        Ok(order)
+    }
+
+    fn next_client_order_id(&mut self) -> ClientOrderId {
+        self.seq += 1;
+        ClientOrderId::generate(self.trader_id, self.strategy_id, self.seq)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderStatus, OrderType},
+        instruments::{crypto_perpetual::CryptoPerpetual, equity::Equity},
+        orders::base::Order,
+    };
+
+    fn order_factory() -> OrderFactory {
+        OrderFactory::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"))
+    }
+
+    #[test]
+    fn test_sequential_orders_have_incrementing_client_order_ids() {
+        let mut factory = order_factory();
+        let instrument = Equity::default();
+        let instrument_id = *instrument.id();
+
+        let market = factory
+            .market(
+                instrument_id,
+                OrderSide::Buy,
+                Quantity::from(100_000),
+                OrderParams::default(),
+                0,
+            )
+            .unwrap();
+        let limit = factory
+            .limit(
+                instrument_id,
+                OrderSide::Sell,
+                Quantity::from(100_000),
+                Price::from("1.00100"),
+                OrderParams::default(),
+                0,
+            )
+            .unwrap();
+        let stop = factory
+            .stop_market(
+                &instrument,
+                OrderSide::Sell,
+                Quantity::from(100_000),
+                Price::from("0.99900"),
+                TriggerType::Default,
+                OrderParams::default(),
+                0,
+            )
+            .unwrap();
+
+        assert_eq!(
+            market.client_order_id(),
+            ClientOrderId::new("O-10-TRADER-001-5-S-001-1")
+        );
+        assert_eq!(
+            limit.client_order_id(),
+            ClientOrderId::new("O-10-TRADER-001-5-S-001-2")
+        );
+        assert_eq!(
+            stop.client_order_id(),
+            ClientOrderId::new("O-10-TRADER-001-5-S-001-3")
+        );
+        assert_eq!(factory.seq(), 3);
+        assert_ne!(market.init_id(), limit.init_id());
+    }
+
+    #[test]
+    fn test_orders_are_initialized_with_params() {
+        let mut factory = order_factory();
+        let params = OrderParams {
+            time_in_force: TimeInForce::Ioc,
+            reduce_only: true,
+            tags: Some("EXIT".to_string()),
+        };
+
+        let order = factory
+            .limit(
+                InstrumentId::default(),
+                OrderSide::Sell,
+                Quantity::from(50_000),
+                Price::from("1.00100"),
+                params,
+                1_000,
+            )
+            .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.trader_id(), TraderId::new("TRADER-001"));
+        assert_eq!(order.strategy_id(), StrategyId::new("S-001"));
+        assert_eq!(order.side(), OrderSide::Sell);
+        assert_eq!(order.price(), Some(Price::from("1.00100")));
+        assert_eq!(order.time_in_force(), TimeInForce::Ioc);
+        assert!(order.is_reduce_only());
+        assert_eq!(order.tags(), Some("EXIT".to_string()));
+        assert_eq!(order.ts_init(), 1_000);
+    }
+
+    #[test]
+    fn test_order_with_invalid_time_in_force() {
+        let mut factory = order_factory();
+        let params = OrderParams {
+            time_in_force: TimeInForce::Gtd,
+            ..OrderParams::default()
+        };
+
+        let result = factory.market(
+            InstrumentId::default(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            params,
+            0,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidTimeInForce(
+                OrderType::Market,
+                TimeInForce::Gtd
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_stop_market_order() {
+        let mut factory = order_factory();
+
+        let order = factory
+            .stop_market(
+                &CryptoPerpetual::default(),
+                OrderSide::Buy,
+                Quantity::from(100_000),
+                Price::from("1.00200"),
+                TriggerType::BidAsk,
+                OrderParams::default(),
+                0,
+            )
+            .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::StopMarket);
+        assert_eq!(order.trigger_price(), Some(Price::from("1.00200")));
+        assert_eq!(order.trigger_type(), Some(TriggerType::BidAsk));
+        assert_eq!(order.price(), None);
+    }
+
+    #[rstest(trigger_type, case(TriggerType::Default), case(TriggerType::NoTrigger))]
+    fn test_stop_market_order_resolves_default_trigger_type(trigger_type: TriggerType) {
+        let mut factory = order_factory();
+        let instrument = CryptoPerpetual::default();
+
+        let order = factory
+            .stop_market(
+                &instrument,
+                OrderSide::Buy,
+                Quantity::from(100_000),
+                Price::from("1.00200"),
+                trigger_type,
+                OrderParams::default(),
+                0,
+            )
+            .unwrap();
+
+        assert_eq!(order.instrument_id(), *instrument.id());
+        assert_eq!(order.trigger_type(), Some(TriggerType::MarkPrice));
+    }
+}
@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::identifiers::{client_order_id::ClientOrderId, venue_order_id::VenueOrderId};
+
+/// A bidirectional index between client order IDs and venue order IDs.
+///
+/// Each client order ID maps to at most one venue order ID and vice versa, so when an
+/// order is re-mapped (such as when a venue assigns a new ID on amend) the stale
+/// mapping is removed.
+#[derive(Clone, Debug, Default)]
+pub struct OrderIdIndex {
+    venue_order_ids: HashMap<ClientOrderId, VenueOrderId>,
+    client_order_ids: HashMap<VenueOrderId, ClientOrderId>,
+}
+
+impl OrderIdIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the `client_order_id` to the `venue_order_id`, returning the venue order ID
+    /// it was previously mapped to (if any).
+    ///
+    /// Any previous mapping of either ID is replaced, so both lookups stay consistent.
+    pub fn insert(
+        &mut self,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+    ) -> Option<VenueOrderId> {
+        if let Some(previous_client_order_id) = self.client_order_ids.get(&venue_order_id) {
+            if *previous_client_order_id != client_order_id {
+                self.venue_order_ids.remove(previous_client_order_id);
+            }
+        }
+        let previous = self.venue_order_ids.insert(client_order_id, venue_order_id);
+        if let Some(previous_venue_order_id) = previous {
+            self.client_order_ids.remove(&previous_venue_order_id);
+        }
+        self.client_order_ids
+            .insert(venue_order_id, client_order_id);
+        previous
+    }
+
+    /// Returns the venue order ID mapped to the `client_order_id`, if any.
+    #[must_use]
+    pub fn venue_for(&self, client_order_id: &ClientOrderId) -> Option<VenueOrderId> {
+        self.venue_order_ids.get(client_order_id).copied()
+    }
+
+    /// Returns the client order ID mapped to the `venue_order_id`, if any.
+    #[must_use]
+    pub fn client_for(&self, venue_order_id: &VenueOrderId) -> Option<ClientOrderId> {
+        self.client_order_ids.get(venue_order_id).copied()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_both_ways() {
+        let mut index = OrderIdIndex::new();
+        let client_order_id = ClientOrderId::new("O-1");
+        let venue_order_id = VenueOrderId::new("V-1");
+
+        let previous = index.insert(client_order_id, venue_order_id);
+
+        assert_eq!(previous, None);
+        assert_eq!(index.venue_for(&client_order_id), Some(venue_order_id));
+        assert_eq!(index.client_for(&venue_order_id), Some(client_order_id));
+    }
+
+    #[test]
+    fn test_lookup_when_not_mapped() {
+        let index = OrderIdIndex::new();
+
+        assert_eq!(index.venue_for(&ClientOrderId::new("O-1")), None);
+        assert_eq!(index.client_for(&VenueOrderId::new("V-1")), None);
+    }
+
+    #[test]
+    fn test_remap_on_amend_removes_stale_venue_order_id() {
+        let mut index = OrderIdIndex::new();
+        let client_order_id = ClientOrderId::new("O-1");
+        index.insert(client_order_id, VenueOrderId::new("V-1"));
+
+        let previous = index.insert(client_order_id, VenueOrderId::new("V-2"));
+
+        assert_eq!(previous, Some(VenueOrderId::new("V-1")));
+        assert_eq!(
+            index.venue_for(&client_order_id),
+            Some(VenueOrderId::new("V-2"))
+        );
+        assert_eq!(
+            index.client_for(&VenueOrderId::new("V-2")),
+            Some(client_order_id)
+        );
+        assert_eq!(index.client_for(&VenueOrderId::new("V-1")), None);
+    }
+
+    #[test]
+    fn test_remap_venue_order_id_to_another_client_order_id() {
+        let mut index = OrderIdIndex::new();
+        let venue_order_id = VenueOrderId::new("V-1");
+        index.insert(ClientOrderId::new("O-1"), venue_order_id);
+
+        index.insert(ClientOrderId::new("O-2"), venue_order_id);
+
+        assert_eq!(
+            index.client_for(&venue_order_id),
+            Some(ClientOrderId::new("O-2"))
+        );
+        assert_eq!(index.venue_for(&ClientOrderId::new("O-1")), None);
+    }
+}
@@ -20,7 +20,7 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::base::{validate_initialized, Order, OrderCore, OrderError};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType,
@@ -71,7 +71,7 @@ impl LimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -99,7 +99,10 @@ impl LimitOrder {
             price,
             expire_time,
             display_qty,
-        }
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
     }
 }
 
@@ -234,6 +237,14 @@ impl Order for LimitOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -307,18 +318,26 @@ impl Order for LimitOrder {
     }
 }
 
-impl From<OrderInitialized> for LimitOrder {
-    fn from(event: OrderInitialized) -> Self {
-        LimitOrder::new(
+impl TryFrom<OrderInitialized> for LimitOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::Limit {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::Limit,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(LimitOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
             event.client_order_id,
             event.order_side,
             event.quantity,
-            event
-                .price // TODO: Improve this error, model order domain errors
-                .expect("Error initializing order: `price` was `None` for `LimitOrder"),
+            event.price.unwrap(),
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -336,45 +355,57 @@ impl From<OrderInitialized> for LimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&LimitOrder> for OrderInitialized {
     fn from(order: &LimitOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: Some(order.price),
-            trigger_price: None,
-            trigger_type: None,
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
-        }
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::order::OrderInitializedBuilder;
+
+    #[test]
+    fn test_default() {
+        let order = LimitOrder::default();
+
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.price(), Some(Price::new(1.0, 5)));
+        assert_eq!(order.trigger_price(), None);
+        assert_eq!(order.trigger_type(), None);
+        assert_eq!(order.quantity(), Quantity::new(100_000.0, 0));
+        assert_eq!(order.leaves_qty(), order.quantity());
+        assert_eq!(order.expire_time, None);
+        assert_eq!(order.display_qty, None);
+    }
+
+    #[test]
+    fn test_initialized_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(Some(1_000))
+            .display_qty(Some(Quantity::from(10_000)))
+            .build()
+            .unwrap();
+
+        let order = LimitOrder::try_from(init.clone()).unwrap();
+        let round_trip = OrderInitialized::from(&order);
+
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.price(), Some(Price::from("1.00000")));
+        assert_eq!(order.expire_time, Some(1_000));
+        assert_eq!(order.display_qty, Some(Quantity::from(10_000)));
+        assert_eq!(round_trip, init);
     }
 }
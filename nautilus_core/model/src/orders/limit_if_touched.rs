@@ -20,7 +20,7 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::base::{validate_initialized, Order, OrderCore, OrderError};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType,
@@ -77,7 +77,7 @@ impl LimitIfTouchedOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -109,7 +109,10 @@ impl LimitIfTouchedOrder {
             display_qty,
             is_triggered: false,
             ts_triggered: None,
-        }
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
     }
 }
 
@@ -246,6 +249,14 @@ impl Order for LimitIfTouchedOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -317,28 +328,43 @@ impl Order for LimitIfTouchedOrder {
     fn trade_ids(&self) -> Vec<&TradeId> {
         self.trade_ids.iter().collect()
     }
+
+    fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        let ts_triggered = match &event {
+            OrderEvent::OrderTriggered(triggered) => Some(triggered.ts_event),
+            _ => None,
+        };
+        self.core.apply(event)?;
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        }
+        Ok(())
+    }
 }
 
-impl From<OrderInitialized> for LimitIfTouchedOrder {
-    fn from(event: OrderInitialized) -> Self {
-        LimitIfTouchedOrder::new(
+impl TryFrom<OrderInitialized> for LimitIfTouchedOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::LimitIfTouched {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::LimitIfTouched,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(LimitIfTouchedOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
             event.client_order_id,
             event.order_side,
             event.quantity,
-            event
-                .price // TODO: Improve this error, model order domain errors
-                .expect("Error initializing order: `price` was `None` for `LimitIfTouchedOrder"),
-            event
-                .trigger_price // TODO: Improve this error, model order domain errors
-                .expect(
-                    "Error initializing order: `trigger_price` was `None` for `LimitIfTouchedOrder",
-                ),
-            event
-                .trigger_type
-                .expect("Error initializing order: `trigger_type` was `None`"),
+            event.price.unwrap(),
+            event.trigger_price.unwrap(),
+            event.trigger_type.unwrap(),
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -356,45 +382,81 @@ impl From<OrderInitialized> for LimitIfTouchedOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&LimitIfTouchedOrder> for OrderInitialized {
     fn from(order: &LimitIfTouchedOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: Some(order.price),
-            trigger_price: Some(order.trigger_price),
-            trigger_type: Some(order.trigger_type),
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
-        }
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::order::{
+        OrderAcceptedBuilder, OrderInitializedBuilder, OrderSubmittedBuilder, OrderTriggeredBuilder,
+    };
+
+    fn initialized() -> OrderInitialized {
+        OrderInitializedBuilder::default()
+            .order_type(OrderType::LimitIfTouched)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .build()
+            .unwrap()
+    }
+
+    fn accepted_order() -> LimitIfTouchedOrder {
+        let mut order = LimitIfTouchedOrder::try_from(initialized()).unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_initialized_round_trip() {
+        let init = initialized();
+
+        let order = LimitIfTouchedOrder::try_from(init.clone()).unwrap();
+        let round_trip = OrderInitialized::from(&order);
+
+        assert_eq!(order.order_type(), OrderType::LimitIfTouched);
+        assert_eq!(order.trigger_price(), Some(Price::from("1.00010")));
+        assert_eq!(order.trigger_type(), Some(TriggerType::LastTrade));
+        assert_eq!(round_trip.price, Some(Price::from("1.00000")));
+        assert_eq!(round_trip, init);
+    }
+
+    #[test]
+    fn test_accepted_order_is_not_triggered() {
+        let order = accepted_order();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert!(!order.is_triggered);
+        assert_eq!(order.ts_triggered, None);
+    }
+
+    #[test]
+    fn test_triggered_event_sets_is_triggered_and_ts_triggered() {
+        let mut order = accepted_order();
+        let triggered = OrderTriggeredBuilder::default()
+            .ts_event(5_000)
+            .ts_init(5_001)
+            .build()
+            .unwrap();
+
+        order.apply(OrderEvent::OrderTriggered(triggered)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Triggered);
+        assert!(order.is_triggered);
+        assert_eq!(order.ts_triggered, Some(5_000));
     }
 }
@@ -0,0 +1,620 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashSet;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use serde::{Deserialize, Serialize};
+
+use super::base::OrderError;
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TriggerType},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+/// The default maximum number of orders (legs) permitted in an [`OrderList`].
+pub const DEFAULT_MAX_LEGS: usize = 10;
+
+/// Represents a list of bulk or related contingent orders.
+///
+/// The member orders are held as their [`OrderInitialized`] events, so a list may
+/// contain orders of differing types. Each order is (re)constructed from its event
+/// with the corresponding `TryFrom<OrderInitialized>` implementation.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[pyclass]
+pub struct OrderList {
+    pub id: OrderListId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub orders: Vec<OrderInitialized>,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderList {
+    /// Creates a new [`OrderList`] from the given `orders`.
+    ///
+    /// # Panics
+    ///
+    /// If `orders` is empty.
+    #[must_use]
+    pub fn new(id: OrderListId, orders: Vec<OrderInitialized>, ts_init: UnixNanos) -> Self {
+        assert!(!orders.is_empty(), "`orders` was empty");
+
+        Self {
+            id,
+            instrument_id: orders[0].instrument_id,
+            strategy_id: orders[0].strategy_id,
+            orders,
+            ts_init,
+        }
+    }
+
+    /// Creates a new bracket [`OrderList`] of the `entry` order, followed by a
+    /// stop-market stop-loss triggered at `stop_loss_trigger` and a limit take-profit at
+    /// `take_profit_price`.
+    ///
+    /// The entry is an `OTO` parent of the stop-loss and take-profit, which are `OCO`
+    /// linked to each other. Both are reduce-only `GTC` orders for the entry quantity on
+    /// the opposite side, and reference the entry as their parent. The `entry` may be of
+    /// any order type, and its contingency and linkage fields are overwritten.
+    ///
+    /// # Panics
+    ///
+    /// If the `entry` order side is `NoOrderSide`.
+    #[must_use]
+    pub fn bracket(
+        id: OrderListId,
+        entry: OrderInitialized,
+        stop_loss_id: ClientOrderId,
+        stop_loss_trigger: Price,
+        take_profit_id: ClientOrderId,
+        take_profit_price: Price,
+    ) -> Self {
+        let exit_side = match entry.order_side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+            OrderSide::NoOrderSide => {
+                panic!("invalid `OrderSide` for bracket entry, was NoOrderSide")
+            }
+        };
+        let exit =
+            |client_order_id: ClientOrderId, linked_order_id: ClientOrderId| OrderInitialized {
+                client_order_id,
+                order_side: exit_side,
+                time_in_force: TimeInForce::Gtc,
+                expire_time: None,
+                post_only: false,
+                reduce_only: true,
+                quote_quantity: false,
+                display_qty: None,
+                limit_offset: None,
+                trailing_offset: None,
+                trailing_offset_type: None,
+                emulation_trigger: None,
+                contingency_type: Some(ContingencyType::Oco),
+                order_list_id: Some(id),
+                linked_order_ids: Some(vec![linked_order_id]),
+                parent_order_id: Some(entry.client_order_id),
+                exec_algorithm_id: None,
+                exec_algorithm_params: None,
+                exec_spawn_id: None,
+                tags: None,
+                event_id: UUID4::new(),
+                ..entry.clone()
+            };
+        let stop_loss = OrderInitialized {
+            order_type: OrderType::StopMarket,
+            price: None,
+            trigger_price: Some(stop_loss_trigger),
+            trigger_type: Some(TriggerType::Default),
+            ..exit(stop_loss_id, take_profit_id)
+        };
+        let take_profit = OrderInitialized {
+            order_type: OrderType::Limit,
+            price: Some(take_profit_price),
+            trigger_price: None,
+            trigger_type: None,
+            ..exit(take_profit_id, stop_loss_id)
+        };
+        let ts_init = entry.ts_init;
+        let entry = OrderInitialized {
+            contingency_type: Some(ContingencyType::Oto),
+            order_list_id: Some(id),
+            linked_order_ids: Some(vec![stop_loss_id, take_profit_id]),
+            parent_order_id: None,
+            ..entry
+        };
+
+        Self::new(id, vec![entry, stop_loss, take_profit], ts_init)
+    }
+
+    /// Returns the first order in the list.
+    #[must_use]
+    pub fn first(&self) -> &OrderInitialized {
+        &self.orders[0]
+    }
+
+    /// Returns the contingency type of the list, as given by its first order.
+    #[must_use]
+    pub fn contingency_type(&self) -> Option<ContingencyType> {
+        self.first().contingency_type
+    }
+
+    /// Validates the list has at most `max_legs` orders (defaults to
+    /// [`DEFAULT_MAX_LEGS`]) and that no client order ID appears more than once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OrderError::TooManyLegs`] if the list exceeds the maximum size, or
+    /// [`OrderError::DuplicateOrderId`] for the first repeated client order ID.
+    pub fn validate(&self, max_legs: Option<usize>) -> Result<(), OrderError> {
+        let max_legs = max_legs.unwrap_or(DEFAULT_MAX_LEGS);
+        if self.orders.len() > max_legs {
+            return Err(OrderError::TooManyLegs(self.orders.len(), max_legs));
+        }
+
+        let mut seen = HashSet::with_capacity(self.orders.len());
+        for order in &self.orders {
+            if !seen.insert(order.client_order_id) {
+                return Err(OrderError::DuplicateOrderId(order.client_order_id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the net quantity of the orders not in `closed`, signed positive for buy
+    /// orders and negative for sell orders.
+    ///
+    /// The list only holds the orders initialization events, so which orders are
+    /// closed must be given by the caller.
+    #[must_use]
+    pub fn net_qty(&self, closed: &HashSet<ClientOrderId>) -> f64 {
+        self.open_orders(closed)
+            .map(|order| match order.order_side {
+                OrderSide::Buy => order.quantity.as_f64(),
+                OrderSide::Sell => -order.quantity.as_f64(),
+                OrderSide::NoOrderSide => 0.0,
+            })
+            .sum()
+    }
+
+    /// Returns the gross (unsigned) quantity of the orders not in `closed`, at the
+    /// greatest precision of those orders.
+    #[must_use]
+    pub fn gross_qty(&self, closed: &HashSet<ClientOrderId>) -> Quantity {
+        self.open_orders(closed)
+            .fold(Quantity::zero(0), |gross, order| Quantity {
+                raw: gross.raw + order.quantity.raw,
+                precision: gross.precision.max(order.quantity.precision),
+            })
+    }
+
+    /// Returns the client order IDs of the orders to cancel when the order with the
+    /// given `client_order_id` is filled, being its linked orders if its contingency
+    /// type cancels siblings on fill (see [`ContingencyType::cancels_siblings_on_fill`]).
+    #[must_use]
+    pub fn canceled_on_fill(&self, client_order_id: ClientOrderId) -> Vec<ClientOrderId> {
+        self.linked_on_fill(client_order_id, ContingencyType::cancels_siblings_on_fill)
+    }
+
+    /// Returns the client order IDs of the orders to activate when the order with the
+    /// given `client_order_id` is filled, being its linked orders if its contingency
+    /// type activates children on fill (see
+    /// [`ContingencyType::activates_children_on_fill`]).
+    #[must_use]
+    pub fn activated_on_fill(&self, client_order_id: ClientOrderId) -> Vec<ClientOrderId> {
+        self.linked_on_fill(client_order_id, ContingencyType::activates_children_on_fill)
+    }
+
+    fn linked_on_fill(
+        &self,
+        client_order_id: ClientOrderId,
+        behavior: fn(ContingencyType) -> bool,
+    ) -> Vec<ClientOrderId> {
+        self.orders
+            .iter()
+            .find(|order| order.client_order_id == client_order_id)
+            .filter(|order| order.contingency_type.is_some_and(behavior))
+            .and_then(|order| order.linked_order_ids.clone())
+            .unwrap_or_default()
+    }
+
+    fn open_orders<'a>(
+        &'a self,
+        closed: &'a HashSet<ClientOrderId>,
+    ) -> impl Iterator<Item = &'a OrderInitialized> {
+        self.orders
+            .iter()
+            .filter(|order| !closed.contains(&order.client_order_id))
+    }
+}
+
+#[pymethods]
+impl OrderList {
+    #[getter]
+    fn order_list_id(&self) -> OrderListId {
+        self.id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn py_strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    /// Return the dictionary representations of the orders `OrderInitialized` events.
+    #[getter]
+    #[pyo3(name = "orders")]
+    fn py_orders(&self, py: Python<'_>) -> PyResult<Vec<Py<PyDict>>> {
+        let decoder = PyModule::import(py, "msgspec")?.getattr("json")?;
+        self.orders
+            .iter()
+            .map(|order| {
+                let json_str = serde_json::to_string(order)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                decoder.call_method("decode", (json_str,), None)?.extract()
+            })
+            .collect()
+    }
+
+    #[getter]
+    #[pyo3(name = "contingency_type")]
+    fn py_contingency_type(&self) -> Option<ContingencyType> {
+        self.contingency_type()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[staticmethod]
+    fn from_json(data: Vec<u8>) -> PyResult<Self> {
+        serde_json::from_slice(&data).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    fn as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        serde_json::to_vec(self).unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        events::order::OrderInitializedBuilder,
+        orders::{
+            base::Order, limit::LimitOrder, stop_limit::StopLimitOrder,
+            stop_market::StopMarketOrder,
+        },
+    };
+
+    fn oco_order_list() -> OrderList {
+        let order_list_id = OrderListId::new("OL-001");
+        let take_profit_id = ClientOrderId::new("O-001");
+        let stop_loss_id = ClientOrderId::new("O-002");
+
+        let take_profit = OrderInitializedBuilder::default()
+            .client_order_id(take_profit_id)
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00100")))
+            .contingency_type(Some(ContingencyType::Oco))
+            .order_list_id(Some(order_list_id))
+            .linked_order_ids(Some(vec![stop_loss_id]))
+            .build()
+            .unwrap();
+        let stop_loss = OrderInitializedBuilder::default()
+            .client_order_id(stop_loss_id)
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::StopLimit)
+            .price(Some(Price::from("0.99890")))
+            .trigger_price(Some(Price::from("0.99900")))
+            .trigger_type(Some(TriggerType::Default))
+            .contingency_type(Some(ContingencyType::Oco))
+            .order_list_id(Some(order_list_id))
+            .linked_order_ids(Some(vec![take_profit_id]))
+            .build()
+            .unwrap();
+
+        OrderList::new(order_list_id, vec![take_profit, stop_loss], 0)
+    }
+
+    #[test]
+    fn test_new() {
+        let order_list = oco_order_list();
+
+        assert_eq!(order_list.id, OrderListId::new("OL-001"));
+        assert_eq!(order_list.instrument_id, order_list.first().instrument_id);
+        assert_eq!(order_list.strategy_id, order_list.first().strategy_id);
+        assert_eq!(order_list.orders.len(), 2);
+        assert_eq!(order_list.contingency_type(), Some(ContingencyType::Oco));
+    }
+
+    #[test]
+    #[should_panic(expected = "`orders` was empty")]
+    fn test_new_with_no_orders() {
+        let _ = OrderList::new(OrderListId::new("OL-001"), vec![], 0);
+    }
+
+    fn order_list_of_len(len: usize) -> OrderList {
+        let orders = (0..len)
+            .map(|i| {
+                OrderInitializedBuilder::default()
+                    .client_order_id(ClientOrderId::new(&format!("O-{i:03}")))
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        OrderList::new(OrderListId::new("OL-001"), orders, 0)
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(oco_order_list().validate(None).is_ok());
+        assert!(order_list_of_len(DEFAULT_MAX_LEGS).validate(None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_with_duplicate_order_ids() {
+        let mut order_list = oco_order_list();
+        order_list.orders[1].client_order_id = order_list.orders[0].client_order_id;
+
+        let result = order_list.validate(None);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::DuplicateOrderId(id)) if id == ClientOrderId::new("O-001")
+        ));
+    }
+
+    #[test]
+    fn test_validate_with_too_many_legs() {
+        let order_list = order_list_of_len(DEFAULT_MAX_LEGS + 1);
+
+        let result = order_list.validate(None);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::TooManyLegs(len, max))
+                if len == DEFAULT_MAX_LEGS + 1 && max == DEFAULT_MAX_LEGS
+        ));
+    }
+
+    #[test]
+    fn test_validate_with_custom_max_legs() {
+        let order_list = oco_order_list();
+
+        assert!(matches!(
+            order_list.validate(Some(1)),
+            Err(OrderError::TooManyLegs(2, 1))
+        ));
+        assert!(order_list.validate(Some(2)).is_ok());
+    }
+
+    fn bracket_order_list() -> OrderList {
+        let leg = |id: &str, side: OrderSide, reduce_only: bool| {
+            OrderInitializedBuilder::default()
+                .client_order_id(ClientOrderId::new(id))
+                .order_side(side)
+                .quantity(Quantity::from(100_000))
+                .reduce_only(reduce_only)
+                .build()
+                .unwrap()
+        };
+        let orders = vec![
+            leg("O-001", OrderSide::Buy, false),
+            leg("O-002", OrderSide::Sell, true),
+            leg("O-003", OrderSide::Sell, true),
+        ];
+        OrderList::new(OrderListId::new("OL-001"), orders, 0)
+    }
+
+    #[test]
+    fn test_net_and_gross_qty() {
+        let order_list = bracket_order_list();
+        let closed = HashSet::new();
+
+        assert_eq!(order_list.net_qty(&closed), -100_000.0);
+        assert_eq!(order_list.gross_qty(&closed), Quantity::from(300_000));
+    }
+
+    #[test]
+    fn test_net_and_gross_qty_ignores_closed_orders() {
+        let order_list = bracket_order_list();
+        // The stop-loss leg has been canceled, leaving the entry offset by the take-profit
+        let closed = HashSet::from([ClientOrderId::new("O-003")]);
+
+        assert_eq!(order_list.net_qty(&closed), 0.0);
+        assert_eq!(order_list.gross_qty(&closed), Quantity::from(200_000));
+    }
+
+    #[test]
+    fn test_net_and_gross_qty_when_all_closed() {
+        let order_list = bracket_order_list();
+        let closed = order_list
+            .orders
+            .iter()
+            .map(|order| order.client_order_id)
+            .collect();
+
+        assert_eq!(order_list.net_qty(&closed), 0.0);
+        assert_eq!(order_list.gross_qty(&closed), Quantity::zero(0));
+    }
+
+    fn bracket() -> OrderList {
+        let entry = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-001"))
+            .order_side(OrderSide::Buy)
+            .order_type(OrderType::Limit)
+            .quantity(Quantity::from(100_000))
+            .price(Some(Price::from("1.00000")))
+            .time_in_force(TimeInForce::Day)
+            .ts_init(1_000)
+            .build()
+            .unwrap();
+
+        OrderList::bracket(
+            OrderListId::new("OL-001"),
+            entry,
+            ClientOrderId::new("O-002"),
+            Price::from("0.99900"),
+            ClientOrderId::new("O-003"),
+            Price::from("1.00200"),
+        )
+    }
+
+    #[test]
+    fn test_bracket_entry_is_oto_parent() {
+        let order_list = bracket();
+        let entry = order_list.first();
+
+        assert_eq!(order_list.orders.len(), 3);
+        assert_eq!(order_list.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(order_list.ts_init, 1_000);
+        assert!(order_list.validate(None).is_ok());
+        assert_eq!(entry.order_list_id, Some(OrderListId::new("OL-001")));
+        assert_eq!(
+            entry.linked_order_ids,
+            Some(vec![
+                ClientOrderId::new("O-002"),
+                ClientOrderId::new("O-003")
+            ])
+        );
+        assert_eq!(entry.parent_order_id, None);
+        assert!(!entry.reduce_only);
+        assert_eq!(entry.time_in_force, TimeInForce::Day);
+    }
+
+    #[test]
+    fn test_bracket_children_are_reduce_only_oco_linked_to_entry() {
+        let order_list = bracket();
+        let entry_id = order_list.first().client_order_id;
+        let stop_loss = &order_list.orders[1];
+        let take_profit = &order_list.orders[2];
+
+        for (child, other) in [(stop_loss, take_profit), (take_profit, stop_loss)] {
+            assert!(child.reduce_only);
+            assert_eq!(child.order_side, OrderSide::Sell);
+            assert_eq!(child.quantity, Quantity::from(100_000));
+            assert_eq!(child.time_in_force, TimeInForce::Gtc);
+            assert_eq!(child.contingency_type, Some(ContingencyType::Oco));
+            assert_eq!(child.order_list_id, Some(order_list.id));
+            assert_eq!(child.linked_order_ids, Some(vec![other.client_order_id]));
+            assert_eq!(child.parent_order_id, Some(entry_id));
+        }
+        assert_ne!(stop_loss.event_id, take_profit.event_id);
+    }
+
+    #[test]
+    fn test_bracket_entry_fill_activates_children() {
+        let order_list = bracket();
+
+        assert_eq!(
+            order_list.activated_on_fill(ClientOrderId::new("O-001")),
+            vec![ClientOrderId::new("O-002"), ClientOrderId::new("O-003")]
+        );
+        assert!(order_list
+            .canceled_on_fill(ClientOrderId::new("O-001"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_bracket_child_fill_cancels_sibling() {
+        let order_list = bracket();
+
+        assert_eq!(
+            order_list.canceled_on_fill(ClientOrderId::new("O-003")),
+            vec![ClientOrderId::new("O-002")]
+        );
+        assert!(order_list
+            .activated_on_fill(ClientOrderId::new("O-003"))
+            .is_empty());
+        assert!(order_list
+            .canceled_on_fill(ClientOrderId::new("O-999"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_bracket_children_order_types() {
+        let order_list = bracket();
+
+        let stop_loss = StopMarketOrder::try_from(order_list.orders[1].clone()).unwrap();
+        let take_profit = LimitOrder::try_from(order_list.orders[2].clone()).unwrap();
+
+        assert_eq!(stop_loss.order_type(), OrderType::StopMarket);
+        assert_eq!(stop_loss.trigger_price, Price::from("0.99900"));
+        assert_eq!(stop_loss.price(), None);
+        assert_eq!(take_profit.order_type(), OrderType::Limit);
+        assert_eq!(take_profit.price, Price::from("1.00200"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `OrderSide` for bracket entry")]
+    fn test_bracket_with_no_order_side() {
+        let entry = OrderInitializedBuilder::default()
+            .order_side(OrderSide::NoOrderSide)
+            .build()
+            .unwrap();
+
+        let _ = OrderList::bracket(
+            OrderListId::new("OL-001"),
+            entry,
+            ClientOrderId::new("O-002"),
+            Price::from("0.99900"),
+            ClientOrderId::new("O-003"),
+            Price::from("1.00200"),
+        );
+    }
+
+    #[test]
+    fn test_oco_serde_json_round_trip() {
+        let order_list = oco_order_list();
+
+        let json = serde_json::to_string(&order_list).unwrap();
+        let deserialized: OrderList = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, order_list);
+
+        let take_profit = LimitOrder::try_from(deserialized.orders[0].clone()).unwrap();
+        let stop_loss = StopLimitOrder::try_from(deserialized.orders[1].clone()).unwrap();
+        assert_eq!(take_profit.order_type(), OrderType::Limit);
+        assert_eq!(take_profit.price, Price::from("1.00100"));
+        assert_eq!(stop_loss.price, Price::from("0.99890"));
+        assert_eq!(stop_loss.trigger_price, Price::from("0.99900"));
+        assert_eq!(
+            stop_loss.linked_order_ids(),
+            Some(vec![take_profit.client_order_id()])
+        );
+    }
+}
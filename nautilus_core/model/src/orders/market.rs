@@ -20,19 +20,20 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::base::{validate_initialized, Order, OrderCore, OrderError};
 use crate::{
+    data::trade::TradeTick,
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType,
     },
-    events::order::{OrderEvent, OrderInitialized},
+    events::order::{OrderEvent, OrderFilled, OrderInitialized},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
         strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
         venue_order_id::VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
 pub struct MarketOrder {
@@ -63,7 +64,7 @@ impl MarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -88,7 +89,147 @@ impl MarketOrder {
                 init_id,
                 ts_init,
             ),
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
+    }
+
+    /// Creates a new [`MarketOrder`], checking the arguments are valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `order_side` is `NoOrderSide`, as a market order must have
+    /// a direction.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        quote_quantity: bool,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<String, String>>,
+        exec_spawn_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Result<Self, OrderError> {
+        if order_side == OrderSide::NoOrderSide {
+            return Err(OrderError::InvalidSide(order_side));
         }
+
+        Ok(Self::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            time_in_force,
+            reduce_only,
+            quote_quantity,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id,
+            tags,
+            init_id,
+            ts_init,
+        ))
+    }
+
+    /// Reconstructs the fills of the order from the `trades` which filled it, in order.
+    ///
+    /// Each trade is applied to the order as an `OrderPartiallyFilled` event, or an
+    /// `OrderFilled` event for the trade which completes the order, so that the running
+    /// `leaves_qty` and `avg_px` of the order are maintained. A trade larger than the
+    /// remaining quantity only fills the remainder, and any trades after the order is
+    /// filled are ignored. Fills are taken as liquidity taking with zero commission in
+    /// the given `currency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a fill cannot be applied to the order in its current state
+    /// (e.g. it has not yet been submitted or accepted). All fills are validated before
+    /// any is applied, so the order is unchanged on error.
+    pub fn fills_from_trades(
+        &mut self,
+        trades: &[TradeTick],
+        account_id: AccountId,
+        currency: Currency,
+    ) -> Result<Vec<OrderFilled>, OrderError> {
+        let mut leaves_qty = self.leaves_qty;
+        let mut fills = Vec::with_capacity(trades.len());
+        for trade in trades {
+            if leaves_qty.is_zero() {
+                break;
+            }
+            let last_qty = trade.size.min(leaves_qty);
+            leaves_qty -= last_qty;
+            fills.push(OrderFilled {
+                trader_id: self.trader_id,
+                strategy_id: self.strategy_id,
+                instrument_id: self.instrument_id,
+                client_order_id: self.client_order_id,
+                venue_order_id: self.venue_order_id.unwrap_or_default(),
+                account_id,
+                trade_id: trade.trade_id,
+                position_id: self.position_id,
+                order_side: self.side,
+                order_type: self.order_type,
+                last_qty,
+                last_px: trade.price,
+                currency,
+                commission: Money::new(0.0, currency),
+                liquidity_side: LiquiditySide::Taker,
+                event_id: UUID4::new(),
+                ts_event: trade.ts_event,
+                ts_init: trade.ts_init,
+                reconciliation: false,
+            });
+        }
+
+        // Only the last fill can complete the order
+        let is_filled = leaves_qty.is_zero();
+        let events: Vec<OrderEvent> = fills
+            .iter()
+            .enumerate()
+            .map(|(i, fill)| {
+                if is_filled && i == fills.len() - 1 {
+                    OrderEvent::OrderFilled(fill.clone())
+                } else {
+                    OrderEvent::OrderPartiallyFilled(fill.clone())
+                }
+            })
+            .collect();
+
+        if self.status.is_closed() {
+            return Err(OrderError::AlreadyClosed(self.status));
+        }
+        let mut status = self.status;
+        for event in &events {
+            if self.time_in_force.is_auction() {
+                self.check_auction(event)?;
+            }
+            status = status.transition(event)?;
+        }
+
+        for event in events {
+            self.core.apply(event)?;
+        }
+        Ok(fills)
     }
 }
 
@@ -291,9 +432,19 @@ impl Order for MarketOrder {
     }
 }
 
-impl From<OrderInitialized> for MarketOrder {
-    fn from(event: OrderInitialized) -> Self {
-        MarketOrder::new(
+impl TryFrom<OrderInitialized> for MarketOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::Market {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::Market,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(MarketOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -313,45 +464,180 @@ impl From<OrderInitialized> for MarketOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&MarketOrder> for OrderInitialized {
     fn from(order: &MarketOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: None,
-            trigger_price: None,
-            trigger_type: None,
-            time_in_force: order.time_in_force,
-            expire_time: None,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: None,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        currencies::USD,
+        enums::AggressorSide,
+        events::order::{OrderAcceptedBuilder, OrderInitializedBuilder, OrderSubmittedBuilder},
+    };
+
+    fn trade(price: &str, size: i64, trade_id: &str, ts_event: UnixNanos) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::default(),
+            price: Price::from(price),
+            size: Quantity::from(size),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new(trade_id),
+            ts_event,
+            ts_init: ts_event,
         }
     }
+
+    fn accepted_order(quantity: i64) -> MarketOrder {
+        let mut order: MarketOrder = OrderInitializedBuilder::default()
+            .quantity(Quantity::from(quantity))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_fills_from_trades_with_two_partial_fills() {
+        let mut order = accepted_order(100);
+
+        let fills = order
+            .fills_from_trades(&[trade("100.00", 40, "T-1", 1)], AccountId::default(), *USD)
+            .unwrap();
+
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_qty, Quantity::from(40));
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.leaves_qty(), Quantity::from(60));
+        assert_eq!(order.avg_px(), Some(100.0));
+
+        let fills = order
+            .fills_from_trades(&[trade("101.00", 20, "T-2", 2)], AccountId::default(), *USD)
+            .unwrap();
+
+        assert_eq!(fills[0].trade_id, TradeId::new("T-2"));
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_qty(), Quantity::from(60));
+        assert_eq!(order.leaves_qty(), Quantity::from(40));
+        assert!((order.avg_px().unwrap() - 100.333_333_333).abs() < 1e-6);
+        assert_eq!(order.ts_last(), 2);
+    }
+
+    #[test]
+    fn test_fills_from_trades_to_filled() {
+        let mut order = accepted_order(100);
+        let trades = [trade("100.00", 40, "T-1", 1), trade("110.00", 60, "T-2", 2)];
+
+        let fills = order
+            .fills_from_trades(&trades, AccountId::default(), *USD)
+            .unwrap();
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.leaves_qty(), Quantity::from(0));
+        assert_eq!(order.avg_px(), Some(106.0));
+        assert_eq!(order.trade_ids().len(), 2);
+    }
+
+    #[test]
+    fn test_fills_from_trades_clamps_to_leaves_qty() {
+        let mut order = accepted_order(100);
+        let trades = [
+            trade("100.00", 40, "T-1", 1),
+            trade("110.00", 80, "T-2", 2),
+            trade("120.00", 50, "T-3", 3),
+        ];
+
+        let fills = order
+            .fills_from_trades(&trades, AccountId::default(), *USD)
+            .unwrap();
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[1].last_qty, Quantity::from(60));
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100));
+        assert_eq!(order.avg_px(), Some(106.0));
+    }
+
+    #[test]
+    fn test_fills_from_trades_with_error_leaves_order_unchanged() {
+        let mut order = accepted_order(100);
+        order.time_in_force = TimeInForce::AtTheOpen;
+        order.set_auction_time(3);
+        let trades = [trade("100.00", 40, "T-1", 5), trade("110.00", 60, "T-2", 1)];
+
+        let result = order.fills_from_trades(&trades, AccountId::default(), *USD);
+
+        assert!(matches!(result, Err(OrderError::BeforeAuction(1, 3))));
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert!(order.filled_qty().is_zero());
+        assert!(order.trade_ids().is_empty());
+    }
+
+    #[test]
+    fn test_fills_from_trades_when_not_accepted() {
+        let mut order = MarketOrder::default();
+
+        let result =
+            order.fills_from_trades(&[trade("100.00", 40, "T-1", 1)], AccountId::default(), *USD);
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+    }
+
+    fn new_checked_with_side(order_side: OrderSide) -> Result<MarketOrder, OrderError> {
+        MarketOrder::new_checked(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            order_side,
+            Quantity::from(100_000),
+            TimeInForce::Gtc,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_new_checked() {
+        let order = new_checked_with_side(OrderSide::Sell).unwrap();
+
+        assert_eq!(order.side(), OrderSide::Sell);
+        assert_eq!(order.order_type(), OrderType::Market);
+    }
+
+    #[test]
+    fn test_new_checked_with_no_order_side() {
+        let result = new_checked_with_side(OrderSide::NoOrderSide);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidSide(OrderSide::NoOrderSide))
+        ));
+    }
 }
@@ -20,7 +20,10 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::{
+    base::{validate_initialized, Order, OrderCore, OrderError},
+    report::OrderStatusReport,
+};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType,
@@ -37,9 +40,12 @@ use crate::{
 
 pub struct MarketToLimitOrder {
     core: OrderCore,
+    /// The limit price of the remaining quantity, set to the first fill price.
     pub price: Option<Price>,
     pub expire_time: Option<UnixNanos>,
     pub display_qty: Option<Quantity>,
+    /// Whether the order has been converted to a limit order by its first fill.
+    pub is_converted: bool,
 }
 
 impl MarketToLimitOrder {
@@ -70,7 +76,7 @@ impl MarketToLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -98,6 +104,29 @@ impl MarketToLimitOrder {
             price: None, // Price will be determined on fill
             expire_time,
             display_qty,
+            is_converted: false,
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
+    }
+
+    /// Converts the remaining quantity to a limit order at the price of the first
+    /// fill, once the order has been filled.
+    fn convert_on_fill(&mut self) {
+        if self.is_converted {
+            return;
+        }
+        // Until converted the first fill is the latest applied, so it is still retained
+        let first_fill_px = self.events.iter().rev().find_map(|event| match event {
+            OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill) => {
+                Some(fill.last_px)
+            }
+            _ => None,
+        });
+        if let Some(last_px) = first_fill_px {
+            self.price = Some(last_px);
+            self.is_converted = true;
         }
     }
 }
@@ -232,6 +261,14 @@ impl Order for MarketToLimitOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -303,11 +340,33 @@ impl Order for MarketToLimitOrder {
     fn trade_ids(&self) -> Vec<&TradeId> {
         self.trade_ids.iter().collect()
     }
+
+    fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        self.core.apply(event)?;
+        self.convert_on_fill();
+        Ok(())
+    }
+
+    fn reconcile(&mut self, report: OrderStatusReport) -> Result<(), OrderError> {
+        self.core.reconcile(report)?;
+        self.convert_on_fill();
+        Ok(())
+    }
 }
 
-impl From<OrderInitialized> for MarketToLimitOrder {
-    fn from(event: OrderInitialized) -> Self {
-        MarketToLimitOrder::new(
+impl TryFrom<OrderInitialized> for MarketToLimitOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::MarketToLimit {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::MarketToLimit,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(MarketToLimitOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -331,45 +390,162 @@ impl From<OrderInitialized> for MarketToLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&MarketToLimitOrder> for OrderInitialized {
     fn from(order: &MarketToLimitOrder) -> Self {
-        Self {
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        currencies::USD,
+        events::order::{
+            OrderAcceptedBuilder, OrderFilled, OrderInitializedBuilder, OrderSubmittedBuilder,
+        },
+        identifiers::account_id::AccountId,
+        types::money::Money,
+    };
+
+    fn accepted_order() -> MarketToLimitOrder {
+        let mut order: MarketToLimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::MarketToLimit)
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    fn order_filled(
+        order: &MarketToLimitOrder,
+        trade_id: &str,
+        last_qty: Quantity,
+        last_px: Price,
+    ) -> OrderFilled {
+        OrderFilled {
             trader_id: order.trader_id,
             strategy_id: order.strategy_id,
             instrument_id: order.instrument_id,
             client_order_id: order.client_order_id,
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new(trade_id),
+            position_id: None,
             order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: None,
-            trigger_price: None,
-            trigger_type: None,
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
+            order_type: OrderType::MarketToLimit,
+            last_qty,
+            last_px,
+            currency: *USD,
+            commission: Money::new(0.0, *USD),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 1,
             reconciliation: false,
         }
     }
+
+    #[test]
+    fn test_initialized_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::MarketToLimit)
+            .expire_time(Some(1_000))
+            .display_qty(Some(Quantity::from(10_000)))
+            .build()
+            .unwrap();
+
+        let order = MarketToLimitOrder::try_from(init.clone()).unwrap();
+        let round_trip = OrderInitialized::from(&order);
+
+        assert_eq!(order.order_type(), OrderType::MarketToLimit);
+        assert_eq!(order.expire_time, Some(1_000));
+        assert_eq!(order.display_qty, Some(Quantity::from(10_000)));
+        assert_eq!(round_trip, init);
+    }
+
+    #[test]
+    fn test_before_conversion() {
+        let order = accepted_order();
+
+        assert_eq!(order.price(), None);
+        assert!(!order.is_converted);
+        assert!(order.is_open());
+    }
+
+    #[test]
+    fn test_first_fill_converts_to_limit_at_fill_price() {
+        let mut order = accepted_order();
+        let fill = order_filled(
+            &order,
+            "T-1",
+            Quantity::from(40_000),
+            Price::from("1.00010"),
+        );
+
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+
+        assert!(order.is_converted);
+        assert_eq!(order.price(), Some(Price::from("1.00010")));
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.leaves_qty(), Quantity::from(60_000));
+        assert_eq!(order.order_type(), OrderType::MarketToLimit);
+    }
+
+    #[test]
+    fn test_later_fills_keep_the_converted_price() {
+        let mut order = accepted_order();
+        let fill1 = order_filled(
+            &order,
+            "T-1",
+            Quantity::from(40_000),
+            Price::from("1.00010"),
+        );
+        let fill2 = order_filled(
+            &order,
+            "T-2",
+            Quantity::from(60_000),
+            Price::from("1.00020"),
+        );
+
+        order
+            .apply(OrderEvent::OrderPartiallyFilled(fill1))
+            .unwrap();
+        order.apply(OrderEvent::OrderFilled(fill2)).unwrap();
+
+        assert_eq!(order.price(), Some(Price::from("1.00010")));
+        assert_eq!(order.status(), OrderStatus::Filled);
+    }
+
+    #[test]
+    fn test_rejected_fill_does_not_convert() {
+        let mut order: MarketToLimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::MarketToLimit)
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let fill = order_filled(
+            &order,
+            "T-1",
+            Quantity::from(40_000),
+            Price::from("1.00010"),
+        );
+
+        assert!(order.apply(OrderEvent::OrderFilled(fill)).is_err());
+        assert!(!order.is_converted);
+        assert_eq!(order.price(), None);
+    }
 }
@@ -16,11 +16,23 @@
 #![allow(dead_code)]
 
 pub mod base;
+pub mod factory;
+pub mod index;
 pub mod limit;
 pub mod limit_if_touched;
+pub mod list;
 pub mod market;
 pub mod market_if_touched;
 pub mod market_to_limit;
+pub mod query;
+pub mod report;
+pub mod snapshot;
 pub mod stop_limit;
+pub mod stop_market;
 pub mod trailing_stop_limit;
 pub mod trailing_stop_market;
+pub mod tree;
+
+pub use index::OrderIdIndex;
+pub use query::{filter_by_strategy, filter_open};
+pub use tree::{build_tree, OrderTree};
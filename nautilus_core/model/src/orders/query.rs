@@ -0,0 +1,160 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use super::base::Order;
+use crate::identifiers::strategy_id::StrategyId;
+
+/// Returns the open orders (see [`Order::is_open`]), in the order given.
+#[must_use]
+pub fn filter_open(orders: &[Box<dyn Order>]) -> Vec<&dyn Order> {
+    orders
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|order| order.is_open())
+        .collect()
+}
+
+/// Returns the orders for the given `strategy_id`, in the order given.
+#[must_use]
+pub fn filter_by_strategy(orders: &[Box<dyn Order>], strategy_id: StrategyId) -> Vec<&dyn Order> {
+    orders
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|order| order.strategy_id() == strategy_id)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::{OrderType, TriggerType},
+        events::order::{
+            OrderAcceptedBuilder, OrderCanceledBuilder, OrderEvent, OrderInitializedBuilder,
+            OrderSubmittedBuilder,
+        },
+        identifiers::client_order_id::ClientOrderId,
+        orders::{limit::LimitOrder, market::MarketOrder},
+        types::price::Price,
+    };
+
+    fn limit_order(id: &str, strategy_id: &str) -> LimitOrder {
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::new(id))
+            .strategy_id(StrategyId::new(strategy_id))
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    fn accept(order: &mut dyn Order) {
+        let client_order_id = order.client_order_id();
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+    }
+
+    /// Returns a mix of initialized, accepted, canceled and emulated orders across two
+    /// strategies, of which `O-002` and `O-004` are open.
+    fn orders() -> Vec<Box<dyn Order>> {
+        let initialized = limit_order("O-001", "S-001");
+        let mut accepted = limit_order("O-002", "S-001");
+        accept(&mut accepted);
+        let mut canceled = limit_order("O-003", "S-001");
+        accept(&mut canceled);
+        let cancel = OrderCanceledBuilder::default()
+            .client_order_id(canceled.client_order_id())
+            .build()
+            .unwrap();
+        canceled.apply(OrderEvent::OrderCanceled(cancel)).unwrap();
+        let mut other_strategy: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-004"))
+            .strategy_id(StrategyId::new("S-002"))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        accept(&mut other_strategy);
+        let emulated: LimitOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-005"))
+            .strategy_id(StrategyId::new("S-002"))
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .emulation_trigger(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        vec![
+            Box::new(initialized),
+            Box::new(accepted),
+            Box::new(canceled),
+            Box::new(other_strategy),
+            Box::new(emulated),
+        ]
+    }
+
+    fn ids(orders: &[&dyn Order]) -> Vec<ClientOrderId> {
+        orders.iter().map(|order| order.client_order_id()).collect()
+    }
+
+    #[test]
+    fn test_filter_open() {
+        let orders = orders();
+
+        assert_eq!(
+            ids(&filter_open(&orders)),
+            vec![ClientOrderId::new("O-002"), ClientOrderId::new("O-004")]
+        );
+    }
+
+    #[test]
+    fn test_filter_by_strategy() {
+        let orders = orders();
+
+        assert_eq!(
+            ids(&filter_by_strategy(&orders, StrategyId::new("S-002"))),
+            vec![ClientOrderId::new("O-004"), ClientOrderId::new("O-005")]
+        );
+        assert!(filter_by_strategy(&orders, StrategyId::new("S-003")).is_empty());
+    }
+
+    #[test]
+    fn test_open_orders_for_strategy() {
+        let orders = orders();
+
+        let open = filter_open(&orders);
+        let open_for_strategy: Vec<&dyn Order> = open
+            .into_iter()
+            .filter(|order| order.strategy_id() == StrategyId::new("S-001"))
+            .collect();
+
+        assert_eq!(ids(&open_for_strategy), vec![ClientOrderId::new("O-002")]);
+    }
+}
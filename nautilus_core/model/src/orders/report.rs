@@ -0,0 +1,47 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::OrderStatus,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a report of the current state of an order at the trading venue, used
+/// to reconcile the order with the venue (see [`Order::reconcile`]).
+///
+/// [`Order::reconcile`]: super::base::Order::reconcile
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: VenueOrderId,
+    pub order_status: OrderStatus,
+    pub filled_qty: Quantity,
+    /// The average fill price, required if any quantity has been filled.
+    pub avg_px: Option<Price>,
+    /// The currency of the fill prices (the quote currency of the instrument).
+    pub currency: Currency,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
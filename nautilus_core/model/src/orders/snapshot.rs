@@ -0,0 +1,204 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+
+use super::base::OrderCore;
+use crate::{
+    enums::{LiquiditySide, OrderStatus},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, position_id::PositionId,
+        trade_id::TradeId, venue_order_id::VenueOrderId,
+    },
+    types::quantity::Quantity,
+};
+
+/// Represents the mutable state of an order at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderSnapshot {
+    pub client_order_id: ClientOrderId,
+    pub status: OrderStatus,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub position_id: Option<PositionId>,
+    pub account_id: Option<AccountId>,
+    pub last_trade_id: Option<TradeId>,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    pub leaves_qty: Quantity,
+    pub avg_px: Option<f64>,
+    pub slippage: Option<f64>,
+    pub liquidity_side: Option<LiquiditySide>,
+    pub ts_last: UnixNanos,
+}
+
+/// Represents a change to a single field between two [`OrderSnapshot`]s, holding the
+/// previous and new values.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldChange {
+    Status(OrderStatus, OrderStatus),
+    VenueOrderId(Option<VenueOrderId>, Option<VenueOrderId>),
+    PositionId(Option<PositionId>, Option<PositionId>),
+    AccountId(Option<AccountId>, Option<AccountId>),
+    LastTradeId(Option<TradeId>, Option<TradeId>),
+    Quantity(Quantity, Quantity),
+    FilledQty(Quantity, Quantity),
+    LeavesQty(Quantity, Quantity),
+    AvgPx(Option<f64>, Option<f64>),
+    Slippage(Option<f64>, Option<f64>),
+    LiquiditySide(Option<LiquiditySide>, Option<LiquiditySide>),
+    TsLast(UnixNanos, UnixNanos),
+}
+
+impl OrderSnapshot {
+    /// Returns the changes from this snapshot to the `other` (later) snapshot, in field
+    /// declaration order.
+    #[must_use]
+    pub fn diff(&self, other: &OrderSnapshot) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+
+        macro_rules! diff_field {
+            ($field:ident, $variant:ident) => {
+                if self.$field != other.$field {
+                    changes.push(FieldChange::$variant(
+                        self.$field.clone(),
+                        other.$field.clone(),
+                    ));
+                }
+            };
+        }
+
+        diff_field!(status, Status);
+        diff_field!(venue_order_id, VenueOrderId);
+        diff_field!(position_id, PositionId);
+        diff_field!(account_id, AccountId);
+        diff_field!(last_trade_id, LastTradeId);
+        diff_field!(quantity, Quantity);
+        diff_field!(filled_qty, FilledQty);
+        diff_field!(leaves_qty, LeavesQty);
+        diff_field!(avg_px, AvgPx);
+        diff_field!(slippage, Slippage);
+        diff_field!(liquidity_side, LiquiditySide);
+        diff_field!(ts_last, TsLast);
+
+        changes
+    }
+}
+
+impl OrderCore {
+    /// Returns a snapshot of the current mutable state of the order.
+    #[must_use]
+    pub fn snapshot(&self) -> OrderSnapshot {
+        OrderSnapshot {
+            client_order_id: self.client_order_id,
+            status: self.status,
+            venue_order_id: self.venue_order_id,
+            position_id: self.position_id,
+            account_id: self.account_id,
+            last_trade_id: self.last_trade_id,
+            quantity: self.quantity,
+            filled_qty: self.filled_qty,
+            leaves_qty: self.leaves_qty,
+            avg_px: self.avg_px,
+            slippage: self.slippage,
+            liquidity_side: self.liquidity_side,
+            ts_last: self.ts_last,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+
+    use super::*;
+    use crate::{
+        currencies::USD,
+        enums::OrderType,
+        events::order::{
+            OrderAcceptedBuilder, OrderEvent, OrderFilled, OrderInitializedBuilder,
+            OrderSubmittedBuilder,
+        },
+        orders::{base::Order, limit::LimitOrder},
+        types::{money::Money, price::Price},
+    };
+
+    fn accepted_limit_order() -> LimitOrder {
+        let mut order: LimitOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_diff_when_unchanged() {
+        let order = accepted_limit_order();
+
+        assert!(order.snapshot().diff(&order.snapshot()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_after_partial_fill() {
+        let mut order = accepted_limit_order();
+        let before = order.snapshot();
+        let fill = OrderFilled {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new("T-001"),
+            position_id: None,
+            order_side: order.side(),
+            order_type: order.order_type(),
+            last_qty: Quantity::from(40_000),
+            last_px: Price::from("1.00000"),
+            currency: *USD,
+            commission: Money::new(0.0, *USD),
+            liquidity_side: LiquiditySide::Maker,
+            event_id: UUID4::default(),
+            ts_event: 1_000,
+            ts_init: 1_000,
+            reconciliation: false,
+        };
+
+        order.apply(OrderEvent::OrderPartiallyFilled(fill)).unwrap();
+        let changes = before.diff(&order.snapshot());
+
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange::Status(OrderStatus::Accepted, OrderStatus::PartiallyFilled),
+                FieldChange::LastTradeId(None, Some(TradeId::new("T-001"))),
+                FieldChange::FilledQty(Quantity::from(0), Quantity::from(40_000)),
+                FieldChange::LeavesQty(Quantity::from(100_000), Quantity::from(60_000)),
+                FieldChange::AvgPx(None, Some(1.0)),
+                FieldChange::LiquiditySide(None, Some(LiquiditySide::Maker)),
+                FieldChange::TsLast(0, 1_000),
+            ]
+        );
+    }
+}
@@ -20,7 +20,7 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::base::{validate_initialized, Order, OrderCore, OrderError};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType,
@@ -77,14 +77,14 @@ impl StopLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
                 instrument_id,
                 client_order_id,
                 order_side,
-                OrderType::LimitIfTouched,
+                OrderType::StopLimit,
                 quantity,
                 time_in_force,
                 post_only,
@@ -109,7 +109,10 @@ impl StopLimitOrder {
             display_qty,
             is_triggered: false,
             ts_triggered: None,
-        }
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
     }
 }
 
@@ -246,6 +249,14 @@ impl Order for StopLimitOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -319,24 +330,28 @@ impl Order for StopLimitOrder {
     }
 }
 
-impl From<OrderInitialized> for StopLimitOrder {
-    fn from(event: OrderInitialized) -> Self {
-        StopLimitOrder::new(
+impl TryFrom<OrderInitialized> for StopLimitOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::StopLimit {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::StopLimit,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(StopLimitOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
             event.client_order_id,
             event.order_side,
             event.quantity,
-            event
-                .price // TODO: Improve this error, model order domain errors
-                .expect("Error initializing order: `price` was `None` for `StopLimitOrder"),
-            event
-                .trigger_price // TODO: Improve this error, model order domain errors
-                .expect("Error initializing order: `trigger_price` was `None` for `StopLimitOrder"),
-            event
-                .trigger_type
-                .expect("Error initializing order: `trigger_type` was `None`"),
+            event.price.unwrap(),
+            event.trigger_price.unwrap(),
+            event.trigger_type.unwrap(),
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -354,45 +369,45 @@ impl From<OrderInitialized> for StopLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&StopLimitOrder> for OrderInitialized {
     fn from(order: &StopLimitOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: Some(order.price),
-            trigger_price: Some(order.trigger_price),
-            trigger_type: Some(order.trigger_type),
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
-        }
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::order::OrderInitializedBuilder;
+
+    #[test]
+    fn test_initialized_round_trip_keeps_limit_and_trigger_prices() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopLimit)
+            .price(Some(Price::from("1.00000")))
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap();
+
+        let order = StopLimitOrder::try_from(init.clone()).unwrap();
+        let round_trip = OrderInitialized::from(&order);
+
+        assert_eq!(order.order_type(), OrderType::StopLimit);
+        assert_eq!(order.price(), Some(Price::from("1.00000")));
+        assert_eq!(order.trigger_price(), Some(Price::from("1.00010")));
+        assert_ne!(order.price(), order.trigger_price());
+        assert_eq!(round_trip.order_type, OrderType::StopLimit);
+        assert_eq!(round_trip.price, Some(Price::from("1.00000")));
+        assert_eq!(round_trip.trigger_price, Some(Price::from("1.00010")));
+        assert_eq!(round_trip.trigger_type, Some(TriggerType::BidAsk));
+        assert_eq!(round_trip, init);
     }
 }
@@ -18,14 +18,17 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_core::{correctness, time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::{
+    base::{validate_initialized, Order, OrderCore, OrderError},
+    stop_limit::StopLimitOrder,
+};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType,
     },
-    events::order::{OrderEvent, OrderInitialized},
+    events::order::{OrderEvent, OrderInitialized, OrderReleased},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
@@ -75,7 +78,7 @@ impl StopMarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -106,14 +109,100 @@ impl StopMarketOrder {
             display_qty,
             is_triggered: false,
             ts_triggered: None,
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
+    }
+
+    /// Returns whether the given `market` price would trigger the stop, which is when
+    /// it has reached the trigger price in the direction of the order side.
+    #[must_use]
+    pub fn would_trigger(&self, market: Price) -> bool {
+        match self.side {
+            OrderSide::Buy => market >= self.trigger_price,
+            OrderSide::Sell => market <= self.trigger_price,
+            OrderSide::NoOrderSide => false,
         }
     }
+
+    /// Checks whether an emulated order should be released at the given `market` price.
+    ///
+    /// If the order is emulated and the `market` price would trigger the stop, then an
+    /// [`OrderReleased`] event is applied (clearing the emulation state) and returned,
+    /// otherwise returns `None`.
+    pub fn check_release(&mut self, market: Price, ts: UnixNanos) -> Option<OrderReleased> {
+        if !self.is_emulated() || !self.would_trigger(market) {
+            return None;
+        }
+
+        let event = OrderReleased {
+            trader_id: self.trader_id,
+            strategy_id: self.strategy_id,
+            instrument_id: self.instrument_id,
+            client_order_id: self.client_order_id,
+            released_price: market,
+            event_id: UUID4::new(),
+            ts_event: ts,
+            ts_init: ts,
+        };
+        self.apply(OrderEvent::OrderReleased(event.clone())).ok()?;
+        Some(event)
+    }
+
+    /// Returns a [`StopLimitOrder`] promoted from this order, with the same identity,
+    /// quantity, trigger and options, and a limit price `limit_offset` beyond the
+    /// trigger price in the direction of the order side (above for a buy, below for
+    /// a sell).
+    ///
+    /// # Panics
+    ///
+    /// If `limit_offset` is negative, or the order side is `NoOrderSide`.
+    #[must_use]
+    pub fn to_stop_limit(&self, limit_offset: Price) -> StopLimitOrder {
+        correctness::i64_in_range_inclusive(limit_offset.raw, 0, i64::MAX, "`limit_offset` raw");
+
+        let price = match self.side {
+            OrderSide::Buy => self.trigger_price + limit_offset,
+            OrderSide::Sell => self.trigger_price - limit_offset,
+            OrderSide::NoOrderSide => panic!("Cannot promote an order with `NoOrderSide`"),
+        };
+
+        StopLimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.side,
+            self.quantity,
+            price,
+            self.trigger_price,
+            self.trigger_type,
+            self.time_in_force,
+            self.expire_time,
+            self.is_post_only,
+            self.is_reduce_only,
+            self.is_quote_quantity,
+            self.display_qty,
+            self.emulation_trigger,
+            self.contingency_type,
+            self.order_list_id,
+            self.linked_order_ids.clone(),
+            self.parent_order_id,
+            self.exec_algorithm_id,
+            self.exec_algorithm_params.clone(),
+            self.exec_spawn_id,
+            self.tags.clone(),
+            self.init_id,
+            self.ts_init,
+        )
+    }
 }
 
 /// Provides a default [`StopMarketOrder`] used for testing.
 impl Default for StopMarketOrder {
     fn default() -> Self {
-        StopLimitOrder::new(
+        StopMarketOrder::new(
             TraderId::default(),
             StrategyId::default(),
             InstrumentId::default(),
@@ -211,7 +300,7 @@ impl Order for StopMarketOrder {
     }
 
     fn price(&self) -> Option<Price> {
-        Some(self.price)
+        None
     }
 
     fn trigger_price(&self) -> Option<Price> {
@@ -242,6 +331,14 @@ impl Order for StopMarketOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -315,23 +412,27 @@ impl Order for StopMarketOrder {
     }
 }
 
-impl From<OrderInitialized> for StopMarketOrder {
-    fn from(event: OrderInitialized) -> Self {
-        StopLimitOrder::new(
+impl TryFrom<OrderInitialized> for StopMarketOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::StopMarket {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::StopMarket,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(StopMarketOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
             event.client_order_id,
             event.order_side,
             event.quantity,
-            event
-                .trigger_price // TODO: Improve this error, model order domain errors
-                .expect(
-                    "Error initializing order: `trigger_price` was `None` for `StopMarketOrder`",
-                ),
-            event.trigger_type.expect(
-                "Error initializing order: `trigger_type` was `None` for `StopMarketOrder`",
-            ),
+            event.trigger_price.unwrap(),
+            event.trigger_type.unwrap(),
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -349,45 +450,134 @@ impl From<OrderInitialized> for StopMarketOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
-impl From<&StopLimitOrder> for OrderInitialized {
-    fn from(order: &StopLimitOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: None,
-            trigger_price: Some(order.trigger_price),
-            trigger_type: Some(order.trigger_type),
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
-        }
+impl From<&StopMarketOrder> for OrderInitialized {
+    fn from(order: &StopMarketOrder) -> Self {
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::order::OrderInitializedBuilder;
+
+    fn emulated_stop(side: OrderSide) -> StopMarketOrder {
+        OrderInitializedBuilder::default()
+            .order_side(side)
+            .order_type(OrderType::StopMarket)
+            .trigger_price(Some(Price::from("1.00010")))
+            .trigger_type(Some(TriggerType::Default))
+            .emulation_trigger(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_default_initialized_round_trip() {
+        let order = StopMarketOrder::default();
+
+        let init = OrderInitialized::from(&order);
+        let round_trip = StopMarketOrder::try_from(init.clone()).unwrap();
+
+        assert_eq!(order.order_type(), OrderType::StopMarket);
+        assert_eq!(order.price(), None);
+        assert_eq!(init.order_type, OrderType::StopMarket);
+        assert_eq!(init.price, None);
+        assert_eq!(init.trigger_price, Some(Price::new(1.0, 5)));
+        assert_eq!(init.trigger_type, Some(TriggerType::BidAsk));
+        assert_eq!(round_trip.order_type(), OrderType::StopMarket);
+        assert_eq!(round_trip.trigger_price, order.trigger_price);
+        assert_eq!(round_trip.trigger_type, order.trigger_type);
+        assert_eq!(OrderInitialized::from(&round_trip), init);
+    }
+
+    #[test]
+    fn test_would_trigger() {
+        let buy = emulated_stop(OrderSide::Buy);
+        let sell = emulated_stop(OrderSide::Sell);
+
+        assert!(!buy.would_trigger(Price::from("1.00009")));
+        assert!(buy.would_trigger(Price::from("1.00010")));
+        assert!(buy.would_trigger(Price::from("1.00011")));
+        assert!(sell.would_trigger(Price::from("1.00009")));
+        assert!(sell.would_trigger(Price::from("1.00010")));
+        assert!(!sell.would_trigger(Price::from("1.00011")));
+    }
+
+    #[test]
+    fn test_to_stop_limit_for_buy() {
+        let order = emulated_stop(OrderSide::Buy);
+
+        let promoted = order.to_stop_limit(Price::from("0.00005"));
+
+        assert_eq!(promoted.client_order_id, order.client_order_id);
+        assert_eq!(promoted.side, OrderSide::Buy);
+        assert_eq!(promoted.quantity, order.quantity);
+        assert_eq!(promoted.trigger_price, Price::from("1.00010"));
+        assert_eq!(promoted.trigger_type, TriggerType::Default);
+        assert_eq!(promoted.emulation_trigger, Some(TriggerType::BidAsk));
+        assert_eq!(promoted.price, Price::from("1.00015"));
+    }
+
+    #[test]
+    fn test_to_stop_limit_for_sell() {
+        let order = emulated_stop(OrderSide::Sell);
+
+        let promoted = order.to_stop_limit(Price::from("0.00005"));
+
+        assert_eq!(promoted.client_order_id, order.client_order_id);
+        assert_eq!(promoted.side, OrderSide::Sell);
+        assert_eq!(promoted.trigger_price, Price::from("1.00010"));
+        assert_eq!(promoted.price, Price::from("1.00005"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`limit_offset` raw")]
+    fn test_to_stop_limit_with_negative_offset() {
+        let _ = emulated_stop(OrderSide::Buy).to_stop_limit(Price::from("-0.00005"));
+    }
+
+    #[test]
+    fn test_check_release_on_trigger() {
+        let mut order = emulated_stop(OrderSide::Buy);
+
+        let released = order.check_release(Price::from("1.00012"), 1_000).unwrap();
+
+        assert_eq!(released.client_order_id, order.client_order_id);
+        assert_eq!(released.released_price, Price::from("1.00012"));
+        assert_eq!(released.ts_event, 1_000);
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.emulation_trigger(), None);
+        assert!(!order.is_emulated());
+        assert_eq!(order.events().len(), 2);
+        assert_eq!(order.ts_last, 1_000);
+    }
+
+    #[test]
+    fn test_check_release_when_not_triggered() {
+        let mut order = emulated_stop(OrderSide::Sell);
+
+        assert!(order.check_release(Price::from("1.00011"), 1_000).is_none());
+        assert!(order.is_emulated());
+        assert_eq!(order.events().len(), 1);
+    }
+
+    #[test]
+    fn test_check_release_when_not_emulated() {
+        let mut order = emulated_stop(OrderSide::Buy);
+        order.check_release(Price::from("1.00010"), 1_000).unwrap();
+
+        // Already released so there is nothing further to emulate
+        assert!(order.check_release(Price::from("1.00020"), 2_000).is_none());
+        assert_eq!(order.events().len(), 2);
     }
 }
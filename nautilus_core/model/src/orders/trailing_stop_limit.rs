@@ -20,7 +20,7 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::base::{validate_initialized, Order, OrderCore, OrderError};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce,
@@ -84,7 +84,7 @@ impl TrailingStopLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -119,7 +119,10 @@ impl TrailingStopLimitOrder {
             display_qty,
             is_triggered: false,
             ts_triggered: None,
-        }
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
     }
 }
 
@@ -259,6 +262,26 @@ impl Order for TrailingStopLimitOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
+    fn limit_offset(&self) -> Option<Price> {
+        Some(self.limit_offset)
+    }
+
+    fn trailing_offset(&self) -> Option<Price> {
+        Some(self.trailing_offset)
+    }
+
+    fn trailing_offset_type(&self) -> Option<TrailingOffsetType> {
+        Some(self.trailing_offset_type)
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -332,29 +355,31 @@ impl Order for TrailingStopLimitOrder {
     }
 }
 
-impl From<OrderInitialized> for TrailingStopLimitOrder {
-    fn from(event: OrderInitialized) -> Self {
-        TrailingStopLimitOrder::new(
+impl TryFrom<OrderInitialized> for TrailingStopLimitOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::TrailingStopLimit {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::TrailingStopLimit,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(TrailingStopLimitOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
             event.client_order_id,
             event.order_side,
             event.quantity,
-            event
-                .price // TODO: Improve this error, model order domain errors
-                .expect("Error initializing order: `price` was `None` for `TrailingStopLimitOrder`"),
-            event
-                .trigger_price // TODO: Improve this error, model order domain errors
-                .expect(
-                    "Error initializing order: `trigger_price` was `None` for `TrailingStopLimitOrder`",
-                ),
-            event
-                .trigger_type
-                .expect("Error initializing order: `trigger_type` was `None` for `TrailingStopLimitOrder`"),
-            event.limit_offset.unwrap(),  // TODO
-            event.trailing_offset.unwrap(),  // TODO
-            event.trailing_offset_type.unwrap(),  // TODO
+            event.price.unwrap(),
+            event.trigger_price.unwrap(),
+            event.trigger_type.unwrap(),
+            event.limit_offset.unwrap(),
+            event.trailing_offset.unwrap(),
+            event.trailing_offset_type.unwrap(),
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -372,45 +397,12 @@ impl From<OrderInitialized> for TrailingStopLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&TrailingStopLimitOrder> for OrderInitialized {
     fn from(order: &TrailingStopLimitOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: Some(order.price),
-            trigger_price: Some(order.trigger_price),
-            trigger_type: Some(order.trigger_type),
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: Some(order.limit_offset),
-            trailing_offset: Some(order.trailing_offset),
-            trailing_offset_type: Some(order.trailing_offset_type),
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
-        }
+        order.init_event()
     }
 }
@@ -20,13 +20,13 @@ use std::{
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
-use super::base::{Order, OrderCore};
+use super::base::{validate_initialized, Order, OrderCore, OrderError};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce,
         TrailingOffsetType, TriggerType,
     },
-    events::order::{OrderEvent, OrderInitialized},
+    events::order::{OrderEvent, OrderInitialized, OrderUpdated},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
@@ -38,10 +38,17 @@ use crate::{
 
 pub struct TrailingStopMarketOrder {
     core: OrderCore,
-    pub trigger_price: Price,
+    /// The trigger price, `None` until first set by a trailing update.
+    pub trigger_price: Option<Price>,
     pub trigger_type: TriggerType,
     pub trailing_offset: Price,
     pub trailing_offset_type: TrailingOffsetType,
+    /// The market price at which the order starts trailing, immediately if `None`.
+    ///
+    /// This is not carried by `OrderInitialized`, so an order initialized from the event
+    /// (or reconstructed from its events) trails immediately.
+    pub activation_price: Option<Price>,
+    pub is_activated: bool,
     pub expire_time: Option<UnixNanos>,
     pub display_qty: Option<Quantity>,
     pub is_triggered: bool,
@@ -58,10 +65,11 @@ impl TrailingStopMarketOrder {
         client_order_id: ClientOrderId,
         order_side: OrderSide,
         quantity: Quantity,
-        trigger_price: Price,
+        trigger_price: Option<Price>,
         trigger_type: TriggerType,
         trailing_offset: Price,
         trailing_offset_type: TrailingOffsetType,
+        activation_price: Option<Price>,
         time_in_force: TimeInForce,
         expire_time: Option<UnixNanos>,
         reduce_only: bool,
@@ -79,7 +87,7 @@ impl TrailingStopMarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
+        let mut order = Self {
             core: OrderCore::new(
                 trader_id,
                 strategy_id,
@@ -108,11 +116,110 @@ impl TrailingStopMarketOrder {
             trigger_type,
             trailing_offset,
             trailing_offset_type,
+            activation_price,
+            is_activated: activation_price.is_none(),
             expire_time,
             display_qty,
             is_triggered: false,
             ts_triggered: None,
+        };
+        let init = order.init_event();
+        order.push_event(OrderEvent::OrderInitialized(init));
+        order
+    }
+
+    /// Updates the trigger price to trail the given `market_price` by the trailing offset,
+    /// returning the `OrderUpdated` applied if the trigger price moved.
+    ///
+    /// A buy order trails above the market and a sell order below it. The trigger price
+    /// only ever ratchets towards the market (down for a buy, up for a sell) and never
+    /// moves adversely. The `price_increment` is the instrument tick size, used when the
+    /// trailing offset is given in ticks, and a basis point offset is applied with
+    /// [`Price::apply_bps`].
+    ///
+    /// With an `activation_price` the order only starts trailing once the market reaches
+    /// it (at or below for a buy, at or above for a sell), and without a trigger price the
+    /// first update sets one.
+    ///
+    /// The new trigger price is applied as an `OrderUpdated` event at `ts_init`, so it
+    /// counts as a modification of the order like any other update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is not open, if the trailing offset type is not one
+    /// of `Price`, `BasisPoints` or `Ticks`, or if the update cannot be applied.
+    pub fn update_trailing_price(
+        &mut self,
+        market_price: Price,
+        price_increment: Price,
+        ts_init: UnixNanos,
+    ) -> Result<Option<OrderUpdated>, OrderError> {
+        if !self.is_open() {
+            return Err(OrderError::NotOpen(self.status));
+        }
+
+        if self.side == OrderSide::NoOrderSide {
+            return Ok(None);
+        }
+        if !self.is_activated {
+            self.is_activated = match (self.activation_price, self.side) {
+                (Some(activation_price), OrderSide::Buy) => market_price <= activation_price,
+                (Some(activation_price), _) => market_price >= activation_price,
+                (None, _) => true,
+            };
+            if !self.is_activated {
+                return Ok(None);
+            }
+        }
+
+        let offset = |offset: Price| match self.side {
+            OrderSide::Buy => Price {
+                raw: market_price.raw + offset.raw,
+                precision: market_price.precision,
+            },
+            _ => Price {
+                raw: market_price.raw - offset.raw,
+                precision: market_price.precision,
+            },
+        };
+        let trigger_price = match self.trailing_offset_type {
+            TrailingOffsetType::Price => offset(self.trailing_offset),
+            TrailingOffsetType::BasisPoints => {
+                market_price.apply_bps(self.trailing_offset.as_f64(), self.side)
+            }
+            TrailingOffsetType::Ticks => offset(Price::new(
+                self.trailing_offset.as_f64() * price_increment.as_f64(),
+                market_price.precision,
+            )),
+            offset_type => return Err(OrderError::UnsupportedTrailingOffsetType(offset_type)),
+        };
+
+        let is_favorable = match (self.trigger_price, self.side) {
+            (None, _) => true,
+            (Some(current), OrderSide::Buy) => trigger_price < current,
+            (Some(current), _) => trigger_price > current,
+        };
+        if !is_favorable {
+            return Ok(None);
         }
+
+        let updated = OrderUpdated {
+            trader_id: self.trader_id,
+            strategy_id: self.strategy_id,
+            instrument_id: self.instrument_id,
+            client_order_id: self.client_order_id,
+            venue_order_id: self.venue_order_id,
+            account_id: self.account_id,
+            quantity: self.quantity,
+            price: None,
+            trigger_price: Some(trigger_price),
+            event_id: UUID4::new(),
+            ts_event: ts_init,
+            ts_init,
+            reconciliation: false,
+        };
+        self.apply(OrderEvent::OrderUpdated(updated.clone()))?;
+        Ok(Some(updated))
     }
 }
 
@@ -126,10 +233,11 @@ impl Default for TrailingStopMarketOrder {
             ClientOrderId::default(),
             OrderSide::Buy,
             Quantity::new(100_000.0, 0),
-            Price::new(1.0, 5),
+            Some(Price::new(1.0, 5)),
             TriggerType::BidAsk,
             Price::new(0.001, 5),
             TrailingOffsetType::Price,
+            None,
             TimeInForce::Gtc,
             None,
             false,
@@ -222,7 +330,7 @@ impl Order for TrailingStopMarketOrder {
     }
 
     fn trigger_price(&self) -> Option<Price> {
-        Some(self.trigger_price)
+        self.trigger_price
     }
 
     fn trigger_type(&self) -> Option<TriggerType> {
@@ -249,6 +357,22 @@ impl Order for TrailingStopMarketOrder {
         self.emulation_trigger
     }
 
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
+    fn trailing_offset(&self) -> Option<Price> {
+        Some(self.trailing_offset)
+    }
+
+    fn trailing_offset_type(&self) -> Option<TrailingOffsetType> {
+        Some(self.trailing_offset_type)
+    }
+
     fn contingency_type(&self) -> Option<ContingencyType> {
         self.contingency_type
     }
@@ -320,27 +444,44 @@ impl Order for TrailingStopMarketOrder {
     fn trade_ids(&self) -> Vec<&TradeId> {
         self.trade_ids.iter().collect()
     }
+
+    fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        let trigger_price = match &event {
+            OrderEvent::OrderUpdated(updated) => updated.trigger_price,
+            _ => None,
+        };
+        self.core.apply(event)?;
+        if trigger_price.is_some() {
+            self.trigger_price = trigger_price;
+        }
+        Ok(())
+    }
 }
 
-impl From<OrderInitialized> for TrailingStopMarketOrder {
-    fn from(event: OrderInitialized) -> Self {
-        TrailingStopMarketOrder::new(
+impl TryFrom<OrderInitialized> for TrailingStopMarketOrder {
+    type Error = OrderError;
+
+    fn try_from(event: OrderInitialized) -> Result<Self, Self::Error> {
+        if event.order_type != OrderType::TrailingStopMarket {
+            return Err(OrderError::UnexpectedOrderType(
+                OrderType::TrailingStopMarket,
+                event.order_type,
+            ));
+        }
+        validate_initialized(&event)?;
+        // The required fields are checked by `validate_initialized`
+        Ok(TrailingStopMarketOrder::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
             event.client_order_id,
             event.order_side,
             event.quantity,
-            event
-                .trigger_price // TODO: Improve this error, model order domain errors
-                .expect(
-                    "Error initializing order: `trigger_price` was `None` for `TrailingStopMarketOrder`",
-                ),
-            event
-                .trigger_type
-                .expect("Error initializing order: `trigger_type` was `None` for `TrailingStopMarketOrder`"),
-            event.trailing_offset.unwrap(),  // TODO
-            event.trailing_offset_type.unwrap(),  // TODO
+            event.trigger_price,
+            event.trigger_type.unwrap(),
+            event.trailing_offset.unwrap(),
+            event.trailing_offset_type.unwrap(),
+            None,
             event.time_in_force,
             event.expire_time,
             event.reduce_only,
@@ -357,45 +498,275 @@ impl From<OrderInitialized> for TrailingStopMarketOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        ))
     }
 }
 
 impl From<&TrailingStopMarketOrder> for OrderInitialized {
     fn from(order: &TrailingStopMarketOrder) -> Self {
-        Self {
-            trader_id: order.trader_id,
-            strategy_id: order.strategy_id,
-            instrument_id: order.instrument_id,
-            client_order_id: order.client_order_id,
-            order_side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: None,
-            trigger_price: Some(order.trigger_price),
-            trigger_type: Some(order.trigger_type),
-            time_in_force: order.time_in_force,
-            expire_time: order.expire_time,
-            post_only: order.is_post_only,
-            reduce_only: order.is_reduce_only,
-            quote_quantity: order.is_quote_quantity,
-            display_qty: order.display_qty,
-            limit_offset: None,
-            trailing_offset: Some(order.trailing_offset),
-            trailing_offset_type: Some(order.trailing_offset_type),
-            emulation_trigger: order.emulation_trigger,
-            contingency_type: order.contingency_type,
-            order_list_id: order.order_list_id,
-            linked_order_ids: order.linked_order_ids.clone(),
-            parent_order_id: order.parent_order_id,
-            exec_algorithm_id: order.exec_algorithm_id,
-            exec_algorithm_params: order.exec_algorithm_params.clone(),
-            exec_spawn_id: order.exec_spawn_id,
-            tags: order.tags.clone(),
-            event_id: order.init_id,
-            ts_event: order.ts_init,
-            ts_init: order.ts_init,
-            reconciliation: false,
-        }
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::order::{
+        OrderAcceptedBuilder, OrderInitializedBuilder, OrderSubmittedBuilder,
+    };
+
+    fn trailing_stop(
+        side: OrderSide,
+        trigger_price: &str,
+        trailing_offset: &str,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> TrailingStopMarketOrder {
+        let mut order: TrailingStopMarketOrder = OrderInitializedBuilder::default()
+            .order_side(side)
+            .order_type(OrderType::TrailingStopMarket)
+            .trigger_price(Some(Price::from(trigger_price)))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .trailing_offset(Some(Price::from(trailing_offset)))
+            .trailing_offset_type(Some(trailing_offset_type))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_initialized_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::TrailingStopMarket)
+            .trigger_price(Some(Price::from("1.00100")))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .trailing_offset(Some(Price::from("0.00100")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .build()
+            .unwrap();
+
+        let order = TrailingStopMarketOrder::try_from(init.clone()).unwrap();
+
+        assert_eq!(order.order_type(), OrderType::TrailingStopMarket);
+        assert_eq!(order.price(), None);
+        assert_eq!(order.trigger_price(), Some(Price::from("1.00100")));
+        assert_eq!(OrderInitialized::from(&order), init);
+    }
+
+    #[test]
+    fn test_update_trailing_price_for_sell_ratchets_up_only() {
+        let mut order = trailing_stop(
+            OrderSide::Sell,
+            "0.99900",
+            "0.00100",
+            TrailingOffsetType::Price,
+        );
+        let tick = Price::from("0.00001");
+
+        assert!(order
+            .update_trailing_price(Price::from("1.00050"), tick, 0)
+            .unwrap()
+            .is_some());
+        assert_eq!(order.trigger_price, Some(Price::from("0.99950")));
+
+        // Market falls back, so the trigger price holds
+        assert!(order
+            .update_trailing_price(Price::from("0.99980"), tick, 0)
+            .unwrap()
+            .is_none());
+        assert_eq!(order.trigger_price, Some(Price::from("0.99950")));
+
+        assert!(order
+            .update_trailing_price(Price::from("1.00200"), tick, 0)
+            .unwrap()
+            .is_some());
+        assert_eq!(order.trigger_price, Some(Price::from("1.00100")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_for_buy_ratchets_down_only() {
+        let mut order = trailing_stop(
+            OrderSide::Buy,
+            "1.00100",
+            "0.00100",
+            TrailingOffsetType::Price,
+        );
+        let tick = Price::from("0.00001");
+
+        assert!(order
+            .update_trailing_price(Price::from("0.99950"), tick, 0)
+            .unwrap()
+            .is_some());
+        assert_eq!(order.trigger_price, Some(Price::from("1.00050")));
+
+        // Market rises back, so the trigger price holds
+        assert!(order
+            .update_trailing_price(Price::from("1.00020"), tick, 0)
+            .unwrap()
+            .is_none());
+        assert_eq!(order.trigger_price, Some(Price::from("1.00050")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_applies_order_updated() {
+        let mut order = trailing_stop(
+            OrderSide::Sell,
+            "0.99900",
+            "0.00100",
+            TrailingOffsetType::Price,
+        );
+
+        let updated = order
+            .update_trailing_price(Price::from("1.00050"), Price::from("0.00001"), 5)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(updated.trigger_price, Some(Price::from("0.99950")));
+        assert_eq!(updated.quantity, order.quantity);
+        assert_eq!(updated.ts_init, 5);
+        assert_eq!(order.last_event(), &OrderEvent::OrderUpdated(updated));
+        assert_eq!(order.modify_count(), 1);
+        assert_eq!(order.status(), OrderStatus::Accepted);
+    }
+
+    #[test]
+    fn test_update_trailing_price_when_not_open() {
+        let mut order: TrailingStopMarketOrder = OrderInitializedBuilder::default()
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::TrailingStopMarket)
+            .trigger_price(Some(Price::from("0.99900")))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .trailing_offset(Some(Price::from("0.00100")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let result = order.update_trailing_price(Price::from("1.00050"), Price::from("0.00001"), 5);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::NotOpen(OrderStatus::Initialized))
+        ));
+        assert_eq!(order.trigger_price, Some(Price::from("0.99900")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_with_basis_points() {
+        // 50 basis points
+        let mut order = trailing_stop(
+            OrderSide::Sell,
+            "90.00",
+            "50.00",
+            TrailingOffsetType::BasisPoints,
+        );
+
+        order
+            .update_trailing_price(Price::from("100.00"), Price::from("0.01"), 0)
+            .unwrap();
+
+        assert_eq!(order.trigger_price, Some(Price::from("99.50")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_with_ticks() {
+        let mut order = trailing_stop(OrderSide::Buy, "110.0", "4", TrailingOffsetType::Ticks);
+
+        order
+            .update_trailing_price(Price::from("100.0"), Price::from("0.5"), 0)
+            .unwrap();
+
+        assert_eq!(order.trigger_price, Some(Price::from("102.0")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_with_unsupported_offset_type() {
+        let mut order = trailing_stop(
+            OrderSide::Sell,
+            "0.99900",
+            "0.00100",
+            TrailingOffsetType::PriceTier,
+        );
+
+        let result = order.update_trailing_price(Price::from("1.00050"), Price::from("0.00001"), 0);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnsupportedTrailingOffsetType(
+                TrailingOffsetType::PriceTier
+            ))
+        ));
+        assert_eq!(order.trigger_price, Some(Price::from("0.99900")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_sets_missing_trigger_price() {
+        let mut order: TrailingStopMarketOrder = OrderInitializedBuilder::default()
+            .order_type(OrderType::TrailingStopMarket)
+            .trigger_type(Some(TriggerType::LastTrade))
+            .trailing_offset(Some(Price::from("0.00100")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(order.trigger_price, None);
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+
+        assert!(order
+            .update_trailing_price(Price::from("1.00000"), Price::from("0.00001"), 0)
+            .unwrap()
+            .is_some());
+        assert_eq!(order.trigger_price, Some(Price::from("1.00100")));
+    }
+
+    #[test]
+    fn test_update_trailing_price_waits_for_activation_price() {
+        let mut order = trailing_stop(
+            OrderSide::Sell,
+            "0.99900",
+            "0.00100",
+            TrailingOffsetType::Price,
+        );
+        order.activation_price = Some(Price::from("1.00100"));
+        order.is_activated = false;
+        let tick = Price::from("0.00001");
+
+        assert!(order
+            .update_trailing_price(Price::from("1.00050"), tick, 0)
+            .unwrap()
+            .is_none());
+        assert!(!order.is_activated);
+        assert_eq!(order.trigger_price, Some(Price::from("0.99900")));
+
+        assert!(order
+            .update_trailing_price(Price::from("1.00100"), tick, 0)
+            .unwrap()
+            .is_some());
+        assert!(order.is_activated);
+        assert_eq!(order.trigger_price, Some(Price::from("1.00000")));
+
+        // Once activated the order keeps trailing below the activation price
+        assert!(order
+            .update_trailing_price(Price::from("1.00050"), tick, 0)
+            .unwrap()
+            .is_none());
+        assert!(order
+            .update_trailing_price(Price::from("1.00150"), tick, 0)
+            .unwrap()
+            .is_some());
+        assert_eq!(order.trigger_price, Some(Price::from("1.00050")));
     }
 }
@@ -0,0 +1,248 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, HashSet};
+
+use super::base::{Order, OrderError};
+use crate::identifiers::client_order_id::ClientOrderId;
+
+/// Represents the parent/child relationships between a set of contingent orders,
+/// such as a bracket (an entry order with take-profit and stop-loss children).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OrderTree {
+    roots: Vec<ClientOrderId>,
+    parents: HashMap<ClientOrderId, ClientOrderId>,
+    children: HashMap<ClientOrderId, Vec<ClientOrderId>>,
+}
+
+impl OrderTree {
+    /// Returns the IDs of the orders without a parent, in the order given.
+    #[must_use]
+    pub fn roots(&self) -> &[ClientOrderId] {
+        &self.roots
+    }
+
+    /// Returns the parent of the order with the given `client_order_id` (if any).
+    #[must_use]
+    pub fn parent(&self, client_order_id: &ClientOrderId) -> Option<ClientOrderId> {
+        self.parents.get(client_order_id).copied()
+    }
+
+    /// Returns the children of the order with the given `client_order_id`, in the
+    /// order given.
+    #[must_use]
+    pub fn children(&self, client_order_id: &ClientOrderId) -> &[ClientOrderId] {
+        self.children
+            .get(client_order_id)
+            .map_or(&[], |children| children.as_slice())
+    }
+}
+
+/// Builds an [`OrderTree`] by linking each order to its `parent_order_id`.
+///
+/// Where a parent lists `linked_order_ids`, each of its children must be among them.
+///
+/// # Errors
+///
+/// Returns an error if a client order ID appears more than once, if a parent order
+/// is missing from `orders`, if a child is not linked from its parent, or if the
+/// parent relationships contain a cycle.
+pub fn build_tree(orders: &[Box<dyn Order>]) -> Result<OrderTree, OrderError> {
+    let mut index = HashMap::with_capacity(orders.len());
+    for order in orders {
+        let client_order_id = order.client_order_id();
+        if index.insert(client_order_id, order).is_some() {
+            return Err(OrderError::DuplicateOrderId(client_order_id));
+        }
+    }
+
+    let mut tree = OrderTree::default();
+    for order in orders {
+        let client_order_id = order.client_order_id();
+        let Some(parent_order_id) = order.parent_order_id() else {
+            tree.roots.push(client_order_id);
+            continue;
+        };
+
+        let parent = index
+            .get(&parent_order_id)
+            .ok_or(OrderError::OrphanedOrder(client_order_id, parent_order_id))?;
+        if let Some(linked_order_ids) = parent.linked_order_ids() {
+            if !linked_order_ids.contains(&client_order_id) {
+                return Err(OrderError::UnlinkedOrder(client_order_id, parent_order_id));
+            }
+        }
+
+        tree.parents.insert(client_order_id, parent_order_id);
+        tree.children
+            .entry(parent_order_id)
+            .or_default()
+            .push(client_order_id);
+    }
+
+    // Every order must reach a root by following its parents
+    for order in orders {
+        let mut visited = HashSet::new();
+        let mut current = order.client_order_id();
+        while let Some(parent_order_id) = tree.parent(&current) {
+            if !visited.insert(current) {
+                return Err(OrderError::CyclicOrderTree(order.client_order_id()));
+            }
+            current = parent_order_id;
+        }
+    }
+
+    Ok(tree)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::{ContingencyType, OrderSide, OrderType, TriggerType},
+        events::order::OrderInitializedBuilder,
+        orders::{limit::LimitOrder, market::MarketOrder, stop_limit::StopLimitOrder},
+        types::price::Price,
+    };
+
+    fn bracket(sl_parent_order_id: ClientOrderId) -> Vec<Box<dyn Order>> {
+        let entry_id = ClientOrderId::new("O-001");
+        let tp_id = ClientOrderId::new("O-002");
+        let sl_id = ClientOrderId::new("O-003");
+
+        let entry: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(entry_id)
+            .contingency_type(Some(ContingencyType::Oto))
+            .linked_order_ids(Some(vec![tp_id, sl_id]))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let take_profit: LimitOrder = OrderInitializedBuilder::default()
+            .client_order_id(tp_id)
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00100")))
+            .contingency_type(Some(ContingencyType::Ouo))
+            .linked_order_ids(Some(vec![sl_id]))
+            .parent_order_id(Some(entry_id))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let stop_loss: StopLimitOrder = OrderInitializedBuilder::default()
+            .client_order_id(sl_id)
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::StopLimit)
+            .price(Some(Price::from("0.99890")))
+            .trigger_price(Some(Price::from("0.99900")))
+            .trigger_type(Some(TriggerType::Default))
+            .contingency_type(Some(ContingencyType::Ouo))
+            .linked_order_ids(Some(vec![tp_id]))
+            .parent_order_id(Some(sl_parent_order_id))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        vec![Box::new(entry), Box::new(take_profit), Box::new(stop_loss)]
+    }
+
+    #[test]
+    fn test_build_tree_for_bracket() {
+        let orders = bracket(ClientOrderId::new("O-001"));
+
+        let tree = build_tree(&orders).unwrap();
+
+        let entry_id = ClientOrderId::new("O-001");
+        let tp_id = ClientOrderId::new("O-002");
+        let sl_id = ClientOrderId::new("O-003");
+        assert_eq!(tree.roots(), &[entry_id]);
+        assert_eq!(tree.children(&entry_id), &[tp_id, sl_id]);
+        assert_eq!(tree.parent(&tp_id), Some(entry_id));
+        assert_eq!(tree.parent(&sl_id), Some(entry_id));
+        assert_eq!(tree.parent(&entry_id), None);
+        assert!(tree.children(&tp_id).is_empty());
+    }
+
+    #[test]
+    fn test_build_tree_with_orphaned_child() {
+        let orders = bracket(ClientOrderId::new("O-999"));
+
+        let result = build_tree(&orders);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::OrphanedOrder(child, parent))
+                if child == ClientOrderId::new("O-003") && parent == ClientOrderId::new("O-999")
+        ));
+    }
+
+    #[test]
+    fn test_build_tree_with_unlinked_child() {
+        let parent_id = ClientOrderId::new("O-001");
+        let child_id = ClientOrderId::new("O-002");
+        let parent: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(parent_id)
+            .linked_order_ids(Some(vec![ClientOrderId::new("O-003")]))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let child: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(child_id)
+            .parent_order_id(Some(parent_id))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let orders: Vec<Box<dyn Order>> = vec![Box::new(parent), Box::new(child)];
+
+        let result = build_tree(&orders);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnlinkedOrder(child, parent)) if child == child_id && parent == parent_id
+        ));
+    }
+
+    #[test]
+    fn test_build_tree_with_cycle() {
+        let a_id = ClientOrderId::new("O-001");
+        let b_id = ClientOrderId::new("O-002");
+        let a: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(a_id)
+            .parent_order_id(Some(b_id))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let b: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(b_id)
+            .parent_order_id(Some(a_id))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let orders: Vec<Box<dyn Order>> = vec![Box::new(a), Box::new(b)];
+
+        let result = build_tree(&orders);
+
+        assert!(matches!(result, Err(OrderError::CyclicOrderTree(id)) if id == a_id));
+    }
+}
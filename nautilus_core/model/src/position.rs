@@ -29,6 +29,7 @@ use crate::{
         venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
+    orders::base::Order,
     types::{currency::Currency, money::Money, quantity::Quantity},
 };
 
@@ -36,7 +37,7 @@ use crate::{
 ///
 /// The position ID may be assigned at the trading venue, or can be system
 /// generated depending on a strategies OMS (Order Management System) settings.
-struct Position {
+pub struct Position {
     events: Vec<OrderFilled>,
     client_order_ids: Vec<ClientOrderId>,
     venue_order_ids: Vec<VenueOrderId>,
@@ -75,7 +76,7 @@ struct Position {
 }
 
 impl Position {
-    pub fn new<T: Instrument>(instrument: &T, fill: &OrderFilled) -> Self {
+    pub fn new<T: Instrument + ?Sized>(instrument: &T, fill: &OrderFilled) -> Self {
         assert_eq!(instrument.id(), &fill.instrument_id);
         assert!(fill.position_id.is_some());
         assert!(fill.order_side != OrderSide::NoOrderSide);
@@ -119,6 +120,24 @@ impl Position {
         }
     }
 
+    /// Opens a new position from the opening `fill` of the given `order`.
+    ///
+    /// The position is created flat for the `instrument` and the `fill` is then applied,
+    /// so it is LONG for a BUY fill and SHORT for a SELL fill.
+    ///
+    /// # Panics
+    ///
+    /// If the `fill` is not for the `order` or `instrument`, has no position ID, or
+    /// has no order side.
+    pub fn from_fill(order: &dyn Order, fill: &OrderFilled, instrument: &dyn Instrument) -> Self {
+        assert_eq!(order.client_order_id(), fill.client_order_id);
+        assert_eq!(order.side(), fill.order_side);
+
+        let mut position = Self::new(instrument, fill);
+        position.apply(fill.clone());
+        position
+    }
+
     pub fn apply(&mut self, fill: OrderFilled) {
         assert!(
             !self.trade_ids.contains(&fill.trade_id),
@@ -146,6 +165,12 @@ impl Position {
 
         self.events.push(fill.clone()); // Potentially do this last
         self.trade_ids.push(fill.trade_id);
+        if !self.client_order_ids.contains(&fill.client_order_id) {
+            self.client_order_ids.push(fill.client_order_id);
+        }
+        if !self.venue_order_ids.contains(&fill.venue_order_id) {
+            self.venue_order_ids.push(fill.venue_order_id);
+        }
 
         // Calculate cumulative commissions
         let commission_currency = fill.commission.currency;
@@ -160,8 +185,14 @@ impl Position {
 
         // Calculate avg prices, points, return, PnL
         match fill.order_side {
-            OrderSide::Buy => {}
-            OrderSide::Sell => {}
+            OrderSide::Buy => {
+                self.buy_qty += &fill.last_qty;
+                self.signed_qty += fill.last_qty.as_f64();
+            }
+            OrderSide::Sell => {
+                self.sell_qty += &fill.last_qty;
+                self.signed_qty -= fill.last_qty.as_f64();
+            }
             _ => panic!("invalid order side"),
         }
 
@@ -200,3 +231,159 @@ impl Eq for Position {}
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+
+    use super::*;
+    use crate::{
+        currencies::{BTC, USD, USDT},
+        enums::{LiquiditySide, OrderType},
+        events::order::OrderInitializedBuilder,
+        instruments::crypto_perpetual::CryptoPerpetual,
+        orders::market::MarketOrder,
+        types::price::Price,
+    };
+
+    fn market_order(instrument: &dyn Instrument, side: OrderSide, quantity: &str) -> MarketOrder {
+        OrderInitializedBuilder::default()
+            .instrument_id(*instrument.id())
+            .order_side(side)
+            .quantity(Quantity::from(quantity))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    fn order_filled(order: &MarketOrder, last_px: Price, commission: Money) -> OrderFilled {
+        OrderFilled {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new("T-001"),
+            position_id: Some(PositionId::new("P-001")),
+            order_side: order.side(),
+            order_type: OrderType::Market,
+            last_qty: order.quantity(),
+            last_px,
+            currency: commission.currency,
+            commission,
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 2,
+            reconciliation: false,
+        }
+    }
+
+    #[test]
+    fn test_from_fill_opens_long() {
+        let instrument = CryptoPerpetual::default();
+        let order = market_order(&instrument, OrderSide::Buy, "0.500");
+        let fill = order_filled(&order, Price::from("40000.0"), Money::new(8.0, *USDT));
+
+        let position = Position::from_fill(&order, &fill, &instrument);
+
+        assert_eq!(position.id, PositionId::new("P-001"));
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.entry, OrderSide::Buy);
+        assert_eq!(position.signed_qty, 0.5);
+        assert_eq!(position.quantity, Quantity::from("0.500"));
+        assert_eq!(position.peak_qty, Quantity::from("0.500"));
+        assert_eq!(position.buy_qty, Quantity::from("0.500"));
+        assert!(position.sell_qty.is_zero());
+        assert_eq!(position.avg_px_open, 40_000.0);
+        assert_eq!(position.opening_order_id, order.client_order_id());
+        assert_eq!(position.trade_ids, vec![TradeId::new("T-001")]);
+        assert_eq!(position.commissions[&*USDT], Money::new(8.0, *USDT));
+        assert_eq!(position.ts_opened, 1);
+        assert_eq!(position.ts_init, 2);
+        assert!(!position.is_inverse);
+        assert_eq!(position.settlement_currency, *USDT);
+    }
+
+    #[test]
+    fn test_from_fill_opens_short() {
+        let instrument = CryptoPerpetual::default();
+        let order = market_order(&instrument, OrderSide::Sell, "1.250");
+        let fill = order_filled(&order, Price::from("41000.5"), Money::new(0.0, *USDT));
+
+        let position = Position::from_fill(&order, &fill, &instrument);
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.entry, OrderSide::Sell);
+        assert_eq!(position.signed_qty, -1.25);
+        assert_eq!(position.quantity, Quantity::from("1.250"));
+        assert_eq!(position.sell_qty, Quantity::from("1.250"));
+        assert!(position.buy_qty.is_zero());
+        assert_eq!(position.avg_px_open, 41_000.5);
+    }
+
+    #[test]
+    fn test_from_fill_for_inverse_instrument() {
+        let instrument = CryptoPerpetual {
+            quote_currency: *USD,
+            settlement_currency: *BTC,
+            is_inverse: true,
+            size_precision: 0,
+            size_increment: Quantity::from(1),
+            multiplier: Quantity::from(100),
+            ..Default::default()
+        };
+        let order = market_order(&instrument, OrderSide::Sell, "10");
+        let fill = order_filled(&order, Price::from("50000.0"), Money::new(0.0001, *BTC));
+
+        let position = Position::from_fill(&order, &fill, &instrument);
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.quantity, Quantity::from(10));
+        assert!(position.is_inverse);
+        assert_eq!(position.multiplier, Quantity::from(100));
+        assert_eq!(position.base_currency, Some(*BTC));
+        assert_eq!(position.quote_currency, *USD);
+        assert_eq!(position.settlement_currency, *BTC);
+        assert_eq!(position.commissions[&*BTC], Money::new(0.0001, *BTC));
+    }
+
+    #[test]
+    fn test_apply_closing_fill_to_position_from_fill() {
+        let instrument = CryptoPerpetual::default();
+        let order = market_order(&instrument, OrderSide::Buy, "0.500");
+        let fill = order_filled(&order, Price::from("40000.0"), Money::new(8.0, *USDT));
+        let mut position = Position::from_fill(&order, &fill, &instrument);
+        let closing_order = market_order(&instrument, OrderSide::Sell, "0.500");
+        let mut closing_fill = order_filled(
+            &closing_order,
+            Price::from("41000.0"),
+            Money::new(8.2, *USDT),
+        );
+        closing_fill.client_order_id = ClientOrderId::new("O-002");
+        closing_fill.trade_id = TradeId::new("T-002");
+        closing_fill.ts_event = 11;
+
+        position.apply(closing_fill);
+
+        assert_eq!(position.side, PositionSide::Flat);
+        assert_eq!(position.signed_qty, 0.0);
+        assert!(position.quantity.is_zero());
+        assert_eq!(position.peak_qty, Quantity::from("0.500"));
+        assert_eq!(position.sell_qty, Quantity::from("0.500"));
+        assert_eq!(position.closing_order_id, Some(ClientOrderId::new("O-002")));
+        assert_eq!(position.duration_ns, Some(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion")]
+    fn test_from_fill_for_another_order() {
+        let instrument = CryptoPerpetual::default();
+        let order = market_order(&instrument, OrderSide::Buy, "0.500");
+        let mut fill = order_filled(&order, Price::from("40000.0"), Money::new(0.0, *USDT));
+        fill.client_order_id = ClientOrderId::new("O-OTHER");
+
+        let _ = Position::from_fill(&order, &fill, &instrument);
+    }
+}
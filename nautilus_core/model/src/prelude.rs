@@ -0,0 +1,92 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Re-exports the commonly used model types, so that typical strategy code only
+//! needs `use nautilus_model::prelude::*;`.
+
+pub use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+pub use crate::{
+    enums::{
+        AggressorSide, AssetClass, AssetType, ContingencyType, LiquiditySide, OmsType, OrderSide,
+        OrderStatus, OrderType, PositionSide, PriceType, TimeInForce, TrailingOffsetType,
+        TriggerType,
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        exec_algorithm_id::ExecAlgorithmId, instrument_id::InstrumentId,
+        order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
+        symbol::Symbol, trade_id::TradeId, trader_id::TraderId, venue::Venue,
+        venue_order_id::VenueOrderId,
+    },
+    orders::{
+        base::{Order, OrderError},
+        limit::LimitOrder,
+        limit_if_touched::LimitIfTouchedOrder,
+        list::OrderList,
+        market::MarketOrder,
+        market_if_touched::MarketIfTouchedOrder,
+        market_to_limit::MarketToLimitOrder,
+        stop_limit::StopLimitOrder,
+        stop_market::StopMarketOrder,
+        trailing_stop_limit::TrailingStopLimitOrder,
+        trailing_stop_market::TrailingStopMarketOrder,
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_construct_market_order_from_prelude() {
+        let order = MarketOrder::new(
+            TraderId::new("TRADER-001"),
+            StrategyId::new("S-001"),
+            InstrumentId::new(Symbol::new("AUD/USD"), Venue::new("SIM")),
+            ClientOrderId::new("O-001"),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            TimeInForce::Gtc,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        );
+
+        assert_eq!(order.order_type(), OrderType::Market);
+        assert_eq!(order.side(), OrderSide::Buy);
+        assert_eq!(order.status(), OrderStatus::Initialized);
+    }
+
+    #[test]
+    fn test_stop_market_order_from_prelude() {
+        let order = StopMarketOrder::default();
+
+        assert_eq!(order.order_type(), OrderType::StopMarket);
+    }
+}
@@ -29,6 +29,10 @@ use ustr::Ustr;
 
 use crate::{currencies::CURRENCY_MAP, enums::CurrencyType};
 
+/// Represents a medium of exchange in a specified denomination.
+///
+/// A currency is identified by its `code` alone, so equality and hashing ignore the
+/// remaining metadata (which may differ between otherwise identical instances).
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq)]
 #[pyclass]
@@ -197,7 +201,16 @@ pub unsafe extern "C" fn currency_from_cstr(code_ptr: *const c_char) -> Currency
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use crate::{enums::CurrencyType, types::currency::Currency};
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+    use crate::{currencies::USD, types::money::Money};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
 
     #[test]
     fn test_currency_new_for_fiat() {
@@ -220,4 +233,25 @@ mod tests {
         assert_eq!(currency.name.as_str(), "Ether");
         assert_eq!(currency.currency_type, CurrencyType::Crypto);
     }
+
+    #[test]
+    fn test_currency_equality_and_hash_by_code_only() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let other = Currency::new("USD", 4, 0, "US Dollar", CurrencyType::Crypto);
+
+        assert_eq!(usd, *USD);
+        assert_eq!(usd, other);
+        assert_eq!(hash(&usd), hash(&other));
+        assert_eq!(hash(&usd), hash(&Currency::from("USD")));
+        assert_ne!(usd, Currency::from("AUD"));
+    }
+
+    #[test]
+    fn test_money_arithmetic_with_differently_constructed_currencies() {
+        let other = Currency::new("USD", 2, 0, "US Dollar", CurrencyType::Fiat);
+
+        let total = Money::new(1.50, *USD) + Money::new(2.25, other);
+
+        assert_eq!(total, Money::new(3.75, *USD));
+    }
 }
@@ -44,6 +44,31 @@ pub fn fixed_u64_to_f64(value: u64) -> f64 {
     (value as f64) * 0.000_000_001
 }
 
+/// Returns the decimal string `value` with the digits of its integer part grouped in
+/// thousands by the `separator`, e.g. `-1234567.50` as `-1,234,567.50`.
+#[must_use]
+pub fn separate_thousands(value: &str, separator: char) -> String {
+    let (sign, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", value),
+    };
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(index) => unsigned.split_at(index),
+        None => (unsigned, ""),
+    };
+
+    let mut result = String::with_capacity(value.len() + integer.len() / 3);
+    result.push_str(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            result.push(separator);
+        }
+        result.push(digit);
+    }
+    result.push_str(fraction);
+    result
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -181,4 +206,19 @@ mod tests {
         let result = fixed_u64_to_f64(value);
         assert_eq!(result, (value as f64) * 0.000_000_001);
     }
+
+    #[rstest(
+        value,
+        expected,
+        case("0", "0"),
+        case("999", "999"),
+        case("1000", "1,000"),
+        case("100000", "100,000"),
+        case("1234567.50", "1,234,567.50"),
+        case("-1234.5", "-1,234.5"),
+        case("-123.456789", "-123.456789")
+    )]
+    fn test_separate_thousands(value: &str, expected: &str) {
+        assert_eq!(separate_thousands(value, ','), expected);
+    }
 }
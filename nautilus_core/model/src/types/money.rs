@@ -23,16 +23,26 @@ use std::{
 
 use nautilus_core::correctness;
 use pyo3::prelude::*;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Deserializer, Serialize};
+use thiserror;
 
 use crate::types::{
     currency::Currency,
-    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+    fixed::{f64_to_fixed_i64, fixed_i64_to_f64, FIXED_PRECISION},
 };
 
 pub const MONEY_MAX: f64 = 9_223_372_036.0;
 pub const MONEY_MIN: f64 = -9_223_372_036.0;
 
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    #[error("Currency mismatch: {} and {}", .0.code, .1.code)]
+    CurrencyMismatch(Currency, Currency),
+    #[error("`Money` amount overflowed")]
+    Overflow,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq)]
 #[pyclass]
@@ -65,6 +75,80 @@ impl Money {
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    /// Creates a new [`Money`] from a decimal `amount`, without passing through a float.
+    ///
+    /// The amount is rounded (half away from zero) to the precision of the `currency`,
+    /// so fee computations on `Decimal` stay exact until this final rounding.
+    ///
+    /// # Panics
+    ///
+    /// If `amount` is outside the range [`MONEY_MIN`, `MONEY_MAX`].
+    #[must_use]
+    pub fn from_decimal(amount: Decimal, currency: Currency) -> Self {
+        let amount = amount.round_dp_with_strategy(
+            u32::from(currency.precision),
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        correctness::f64_in_range_inclusive(
+            amount.to_f64().unwrap_or(f64::NAN),
+            MONEY_MIN,
+            MONEY_MAX,
+            "`Money` amount",
+        );
+
+        let scale = Decimal::from(10_i64.pow(u32::from(FIXED_PRECISION)));
+        let raw = (amount * scale)
+            .to_i64()
+            .expect("`Money` amount within range has a raw `i64` value");
+        Self { raw, currency }
+    }
+
+    /// Adds `other` to this amount, checking the currencies match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ, or
+    /// [`MoneyError::Overflow`] if the sum overflows.
+    pub fn checked_add(&self, other: Self) -> Result<Self, MoneyError> {
+        self.check_currency(&other)?;
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::from_raw(raw, self.currency))
+    }
+
+    /// Subtracts `other` from this amount, checking the currencies match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ, or
+    /// [`MoneyError::Overflow`] if the difference overflows.
+    pub fn checked_sub(&self, other: Self) -> Result<Self, MoneyError> {
+        self.check_currency(&other)?;
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::from_raw(raw, self.currency))
+    }
+
+    fn check_currency(&self, other: &Self) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch(self.currency, other.currency))
+        }
+    }
+
+    /// Returns the amount as an exact [`Decimal`] at the precision of the currency.
+    #[must_use]
+    pub fn to_decimal(&self) -> Decimal {
+        let mut amount = Decimal::new(self.raw, u32::from(FIXED_PRECISION));
+        amount.rescale(u32::from(self.currency.precision));
+        amount
+    }
 }
 
 impl Hash for Money {
@@ -123,25 +207,19 @@ impl Neg for Money {
     }
 }
 
+/// Adds the amounts, panicking if the currencies differ (see [`Money::checked_add`]).
 impl Add for Money {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.currency, rhs.currency);
-        Self {
-            raw: self.raw + rhs.raw,
-            currency: self.currency,
-        }
+        self.checked_add(rhs).unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
+/// Subtracts the amounts, panicking if the currencies differ (see [`Money::checked_sub`]).
 impl Sub for Money {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.currency, rhs.currency);
-        Self {
-            raw: self.raw - rhs.raw,
-            currency: self.currency,
-        }
+        self.checked_sub(rhs).unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
@@ -278,7 +356,75 @@ pub extern "C" fn money_sub_assign(mut a: Money, b: Money) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::currencies::{BTC, USD};
+    use crate::currencies::{BTC, EUR, USD};
+
+    #[test]
+    fn test_checked_add_same_currency() {
+        let sum = Money::new(100.25, *USD).checked_add(Money::new(50.50, *USD));
+
+        assert_eq!(sum, Ok(Money::new(150.75, *USD)));
+        assert_eq!(
+            Money::new(1.0, *USD) + Money::new(2.0, *USD),
+            Money::new(3.0, *USD)
+        );
+    }
+
+    #[test]
+    fn test_checked_sub_same_currency() {
+        let difference = Money::new(100.25, *USD).checked_sub(Money::new(150.50, *USD));
+
+        assert_eq!(difference, Ok(Money::new(-50.25, *USD)));
+        assert_eq!(
+            Money::new(3.0, *USD) - Money::new(2.0, *USD),
+            Money::new(1.0, *USD)
+        );
+    }
+
+    #[test]
+    fn test_checked_add_and_sub_with_currency_mismatch() {
+        let usd = Money::new(100.0, *USD);
+        let eur = Money::new(100.0, *EUR);
+
+        assert_eq!(
+            usd.checked_add(eur),
+            Err(MoneyError::CurrencyMismatch(*USD, *EUR))
+        );
+        assert_eq!(
+            usd.checked_sub(eur),
+            Err(MoneyError::CurrencyMismatch(*USD, *EUR))
+        );
+        assert_eq!(
+            usd.checked_add(eur).unwrap_err().to_string(),
+            "Currency mismatch: USD and EUR"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Currency mismatch: USD and EUR")]
+    fn test_add_with_currency_mismatch() {
+        let _ = Money::new(100.0, *USD) + Money::new(100.0, *EUR);
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        let max = Money::from_raw(i64::MAX, *USD);
+
+        assert_eq!(
+            max.checked_add(Money::new(0.01, *USD)),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_add_amounts_rounded_to_currency_precision() {
+        // Each amount is rounded to the 2 decimal places of USD on construction
+        let amount = Money::new(1.234, *USD);
+
+        let sum = amount.checked_add(amount).unwrap();
+
+        assert_eq!(sum, Money::new(2.46, *USD));
+        assert_eq!(sum.to_string(), "2.46 USD");
+    }
 
     #[test]
     fn test_money_new_usd() {
@@ -288,6 +434,48 @@ mod tests {
         assert_eq!(money.to_string(), "1000.00 USD");
     }
 
+    #[test]
+    fn test_money_from_decimal() {
+        let fee = Decimal::from_str("12.345").unwrap();
+
+        let money = Money::from_decimal(fee, *USD);
+
+        assert_eq!(money, Money::new(12.35, *USD));
+        assert_eq!(money.to_string(), "12.35 USD");
+    }
+
+    #[test]
+    fn test_money_from_decimal_negative_rounds_away_from_zero() {
+        let money = Money::from_decimal(Decimal::from_str("-0.005").unwrap(), *USD);
+
+        assert_eq!(money, Money::new(-0.01, *USD));
+    }
+
+    #[test]
+    fn test_money_decimal_round_trip() {
+        let fee = Decimal::from_str("0.00012345").unwrap();
+
+        let money = Money::from_decimal(fee, *BTC);
+        let decimal = money.to_decimal();
+
+        assert_eq!(decimal, fee);
+        assert_eq!(decimal.to_string(), "0.00012345");
+        assert_eq!(Money::from_decimal(decimal, *BTC), money);
+    }
+
+    #[test]
+    fn test_money_to_decimal_has_currency_precision() {
+        let money = Money::new(1000.0, *USD);
+
+        assert_eq!(money.to_decimal().to_string(), "1000.00");
+    }
+
+    #[test]
+    #[should_panic(expected = "`Money` amount")]
+    fn test_money_from_decimal_out_of_range() {
+        let _ = Money::from_decimal(Decimal::from(10_000_000_000_i64), *USD);
+    }
+
     #[test]
     fn test_money_new_btc() {
         let money = Money::new(10.3, BTC.clone());
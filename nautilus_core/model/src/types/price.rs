@@ -23,14 +23,29 @@ use std::{
 
 use nautilus_core::{correctness, parsing::precision_from_str};
 use pyo3::prelude::*;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Deserializer, Serialize};
+use thiserror;
 
-use super::fixed::FIXED_SCALAR;
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use super::fixed::{FIXED_PRECISION, FIXED_SCALAR};
+use crate::{
+    enums::OrderSide,
+    types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64, separate_thousands},
+};
 
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
 
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PriceError {
+    #[error("Precision mismatch: {0} and {1}")]
+    PrecisionMismatch(u8, u8),
+    #[error("`Price` value overflowed")]
+    Overflow,
+    #[error("`Price` division by zero")]
+    DivisionByZero,
+}
+
 /// Sentinel Price for errors.
 pub const ERROR_PRICE: Price = Price {
     raw: i64::MAX,
@@ -38,7 +53,7 @@ pub const ERROR_PRICE: Price = Price {
 };
 
 #[repr(C)]
-#[derive(Copy, Clone, Eq, Default)]
+#[derive(Copy, Clone, Eq)]
 #[pyclass]
 pub struct Price {
     pub raw: i64,
@@ -61,6 +76,48 @@ impl Price {
         Self { raw, precision }
     }
 
+    /// Creates a new [`Price`] from a `raw` fixed-point value, usable in `const` contexts.
+    ///
+    /// The `raw` value is scaled by 10^9 ([`FIXED_PRECISION`] decimal places) regardless
+    /// of `precision`, so e.g. a value of 1.5 has a `raw` of `1_500_000_000`. The `raw`
+    /// value should be a multiple of 10^(9 - `precision`) to be representable at
+    /// `precision`.
+    ///
+    /// # Panics
+    ///
+    /// If `precision` exceeds [`FIXED_PRECISION`] (at compile time in a `const` context).
+    #[must_use]
+    pub const fn from_raw_const(raw: i64, precision: u8) -> Self {
+        assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+        Self { raw, precision }
+    }
+
+    /// Creates a new [`Price`] from an integer number of minor `units` at the given
+    /// `precision`, e.g. 123450 at a precision of 5 is 1.23450.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `precision` exceeds [`FIXED_PRECISION`], or the value is
+    /// outside the range [`PRICE_MIN`] to [`PRICE_MAX`].
+    pub fn from_minor_units(units: i64, precision: u8) -> Result<Self, String> {
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "`Price` precision {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        let raw = 10_i64
+            .checked_pow(u32::from(FIXED_PRECISION - precision))
+            .and_then(|scale| units.checked_mul(scale))
+            .filter(|raw| (Price::min(0).raw..=Price::max(0).raw).contains(raw))
+            .ok_or_else(|| {
+                format!(
+                    "`Price` value {units}e-{precision} out of range [{PRICE_MIN}, {PRICE_MAX}]"
+                )
+            })?;
+
+        Ok(Self { raw, precision })
+    }
+
     #[must_use]
     pub fn max(precision: u8) -> Self {
         Self {
@@ -91,6 +148,136 @@ impl Price {
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    /// Returns the price offset by `bps` basis points in the given `direction`,
+    /// rounded to the precision of the price.
+    ///
+    /// A `Buy` direction offsets the price upwards as `price * (1 + bps / 10_000)`,
+    /// and a `Sell` direction downwards as `price * (1 - bps / 10_000)`, matching
+    /// the side a trailing stop trails the market from.
+    ///
+    /// # Panics
+    ///
+    /// If `direction` is `NoOrderSide`.
+    #[must_use]
+    pub fn apply_bps(&self, bps: f64, direction: OrderSide) -> Self {
+        let factor = match direction {
+            OrderSide::Buy => 1.0 + bps / 10_000.0,
+            OrderSide::Sell => 1.0 - bps / 10_000.0,
+            OrderSide::NoOrderSide => {
+                panic!("invalid `OrderSide` for `direction`, was {direction}")
+            }
+        };
+        Self::new(self.as_f64() * factor, self.precision)
+    }
+
+    /// Returns the price formatted at its precision with the digits of the integer
+    /// part grouped in thousands by the `separator` (typically `,` or `_`), e.g.
+    /// `1,234,567.50`.
+    ///
+    /// The `Display` format is not separated, so that it can be parsed.
+    #[must_use]
+    pub fn to_formatted_string(&self, separator: char) -> String {
+        separate_thousands(&self.to_string(), separator)
+    }
+
+    /// Returns the absolute difference between this price and `other`, at the greater
+    /// of their precisions.
+    #[must_use]
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            raw: (self.raw - other.raw).abs(),
+            precision: self.precision.max(other.precision),
+        }
+    }
+
+    /// Returns whether this price is within `tol` of `other` (inclusive).
+    ///
+    /// The comparison is on the fixed-point values, so prices of differing precision
+    /// are compared by value.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tol: Self) -> bool {
+        self.abs_diff(other).raw <= tol.raw
+    }
+
+    /// Adds `other` to this price, checking the precisions match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PriceError::PrecisionMismatch`] if the precisions differ, or
+    /// [`PriceError::Overflow`] if the sum overflows.
+    pub fn checked_add(&self, other: Self) -> Result<Self, PriceError> {
+        self.check_precision(&other)?;
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or(PriceError::Overflow)?;
+        Ok(Self::from_raw(raw, self.precision))
+    }
+
+    /// Subtracts `other` from this price, checking the precisions match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PriceError::PrecisionMismatch`] if the precisions differ, or
+    /// [`PriceError::Overflow`] if the difference overflows.
+    pub fn checked_sub(&self, other: Self) -> Result<Self, PriceError> {
+        self.check_precision(&other)?;
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or(PriceError::Overflow)?;
+        Ok(Self::from_raw(raw, self.precision))
+    }
+
+    /// Returns the price stepped by `n` ticks of `increment`, upwards for a positive
+    /// `n` and downwards for a negative `n`, at the precision of this price.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows.
+    #[must_use]
+    pub fn add_ticks(&self, n: i64, increment: Self) -> Self {
+        let raw = n
+            .checked_mul(increment.raw)
+            .and_then(|offset| self.raw.checked_add(offset))
+            .unwrap_or_else(|| panic!("`Price` overflowed adding {n} ticks of {increment}"));
+        Self::from_raw(raw, self.precision)
+    }
+
+    /// Divides this price by `other`.
+    ///
+    /// The result is at the precision of this price (the dividend), rounded half away
+    /// from zero, e.g. 10.00 / 3.0 is 3.33.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PriceError::DivisionByZero`] if `other` is zero, or
+    /// [`PriceError::Overflow`] if the result is out of range.
+    pub fn checked_div(&self, other: Self) -> Result<Self, PriceError> {
+        if other.raw == 0 {
+            return Err(PriceError::DivisionByZero);
+        }
+        let step = 10_i128.pow(u32::from(FIXED_PRECISION - self.precision));
+        let numerator = i128::from(self.raw) * FIXED_SCALAR as i128;
+        let denominator = i128::from(other.raw) * step;
+        let mut units = numerator / denominator;
+        if 2 * (numerator % denominator).abs() >= denominator.abs() {
+            units += numerator.signum() * denominator.signum();
+        }
+        let raw = i64::try_from(units * step).map_err(|_| PriceError::Overflow)?;
+        Ok(Self::from_raw(raw, self.precision))
+    }
+
+    fn check_precision(&self, other: &Self) -> Result<(), PriceError> {
+        if self.precision != other.precision {
+            return Err(PriceError::PrecisionMismatch(
+                self.precision,
+                other.precision,
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl FromStr for Price {
@@ -111,6 +298,16 @@ impl From<&str> for Price {
     }
 }
 
+/// Provides a default [`Price`] of zero at precision 0.
+///
+/// As the precision is zero, a default value should be replaced (rather than
+/// added to) when the precision of an instrument is known.
+impl Default for Price {
+    fn default() -> Self {
+        Self::zero(0)
+    }
+}
+
 impl From<Price> for f64 {
     fn from(value: Price) -> Self {
         value.as_f64()
@@ -123,6 +320,44 @@ impl From<&Price> for f64 {
     }
 }
 
+impl TryFrom<Decimal> for Price {
+    type Error = String;
+
+    /// Converts the `value`, using its scale as the precision.
+    ///
+    /// Returns an error if the scale exceeds [`FIXED_PRECISION`], or the value is outside
+    /// the range [`PRICE_MIN`] to [`PRICE_MAX`].
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let precision = value.scale();
+        if precision > u32::from(FIXED_PRECISION) {
+            return Err(format!(
+                "`Price` precision {precision} exceeded maximum {FIXED_PRECISION} for {value}"
+            ));
+        }
+        let raw = value
+            .checked_mul(Decimal::from(10_i64.pow(u32::from(FIXED_PRECISION))))
+            .and_then(|raw| raw.to_i64())
+            .filter(|raw| (Price::min(0).raw..=Price::max(0).raw).contains(raw))
+            .ok_or_else(|| {
+                format!("`Price` value {value} out of range [{PRICE_MIN}, {PRICE_MAX}]")
+            })?;
+
+        Ok(Self {
+            raw,
+            precision: precision as u8,
+        })
+    }
+}
+
+impl From<Price> for Decimal {
+    /// Converts the `value` with its precision as the scale.
+    fn from(value: Price) -> Self {
+        let mut decimal = Decimal::new(value.raw, u32::from(FIXED_PRECISION));
+        decimal.rescale(u32::from(value.precision));
+        decimal
+    }
+}
+
 impl Hash for Price {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.raw.hash(state)
@@ -272,13 +507,12 @@ impl Serialize for Price {
 }
 
 impl<'de> Deserialize<'de> for Price {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let price_str: &str = Deserialize::deserialize(_deserializer)?;
-        let price: Price = price_str.into();
-        Ok(price)
+        let price_str = String::deserialize(deserializer)?;
+        Price::from_str(&price_str).map_err(serde::de::Error::custom)
     }
 }
 
@@ -319,6 +553,77 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_from_raw_const() {
+        const PRICE: Price = Price::from_raw_const(1_000_010_000, 5);
+
+        assert_eq!(PRICE, Price::new(1.00001, 5));
+        assert_eq!(PRICE.precision, 5);
+        assert_eq!(PRICE.to_string(), "1.00001");
+    }
+
+    #[test]
+    #[should_panic(expected = "precision exceeded maximum 9")]
+    fn test_from_raw_const_with_precision_over_max() {
+        let _ = Price::from_raw_const(1, 10);
+    }
+
+    #[test]
+    fn test_apply_bps() {
+        let price = Price::from("100.00");
+
+        assert_eq!(price.apply_bps(10.0, OrderSide::Buy), Price::from("100.10"));
+        assert_eq!(price.apply_bps(10.0, OrderSide::Sell), Price::from("99.90"));
+        assert_eq!(price.apply_bps(10.0, OrderSide::Buy).precision, 2);
+    }
+
+    #[test]
+    fn test_apply_bps_rounds_to_precision() {
+        let price = Price::from("1.2345");
+
+        assert_eq!(price.apply_bps(10.0, OrderSide::Buy), Price::from("1.2357"));
+        assert_eq!(
+            price.apply_bps(10.0, OrderSide::Sell),
+            Price::from("1.2333")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `OrderSide` for `direction`")]
+    fn test_apply_bps_with_no_order_side() {
+        let _ = Price::from("100.00").apply_bps(10.0, OrderSide::NoOrderSide);
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        let a = Price::from("1.0010");
+        let b = Price::from("1.00");
+
+        assert_eq!(a.abs_diff(&b), Price::from("0.0010"));
+        assert_eq!(b.abs_diff(&a), Price::from("0.0010"));
+        assert_eq!(a.abs_diff(&b).precision, 4);
+        assert_eq!(a.abs_diff(&a), Price::zero(4));
+    }
+
+    #[test]
+    fn test_approx_eq_within_tolerance() {
+        let computed = Price::from("1.00001") + Price::from("0.00002");
+        let tol = Price::from("0.00001");
+
+        assert!(computed.approx_eq(&Price::from("1.00003"), tol));
+        assert!(computed.approx_eq(&Price::from("1.00004"), tol));
+        assert!(computed.approx_eq(&Price::from("1.00002"), tol));
+        assert!(Price::from("1.0").approx_eq(&Price::from("1.00000"), Price::zero(0)));
+    }
+
+    #[test]
+    fn test_approx_eq_outside_tolerance() {
+        let tol = Price::from("0.00001");
+
+        assert!(!Price::from("1.00003").approx_eq(&Price::from("1.00005"), tol));
+        assert!(!Price::from("-1.00000").approx_eq(&Price::from("1.00000"), tol));
+    }
+
     #[test]
     fn test_new() {
         let price = Price::new(0.00812, 8);
@@ -373,6 +678,15 @@ mod tests {
         assert!(price.is_zero());
     }
 
+    #[test]
+    fn test_default() {
+        let price = Price::default();
+        assert_eq!(price.raw, 0);
+        assert_eq!(price.precision, 0);
+        assert!(price.is_zero());
+        assert_eq!(price.to_string(), "0");
+    }
+
     #[test]
     fn test_is_zero() {
         let price = Price::new(0.0, 8);
@@ -496,4 +810,186 @@ mod tests {
         assert_eq!(price.as_f64(), 44.123_456_000_000_004);
         assert_eq!(price.to_string(), input_string);
     }
+
+    #[test]
+    fn test_serde_json_round_trip() {
+        for (input, precision) in [("1.10", 2), ("1.10000", 5), ("0", 0), ("0.000000001", 9)] {
+            let price = Price::from(input);
+            let json = serde_json::to_string(&price).unwrap();
+            assert_eq!(json, format!("\"{input}\""));
+
+            let deserialized: Price = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, price);
+            assert_eq!(deserialized.precision, precision);
+            assert_eq!(deserialized.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_serde_json_deserialize_invalid_string() {
+        let result: Result<Price, _> = serde_json::from_str("\"abc\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_from_decimal() {
+        let price = Price::try_from(Decimal::from_str("-1.23450").unwrap()).unwrap();
+
+        assert_eq!(price, Price::from("-1.23450"));
+        assert_eq!(price.precision, 5);
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        let decimal = Decimal::from_str("9876.543210001").unwrap();
+
+        let price = Price::try_from(decimal).unwrap();
+
+        assert_eq!(price.raw, 9_876_543_210_001);
+        assert_eq!(Decimal::from(price), decimal);
+        assert_eq!(Decimal::from(price).to_string(), "9876.543210001");
+        assert_eq!(Decimal::from(Price::from("1.50")).to_string(), "1.50");
+    }
+
+    #[test]
+    fn test_try_from_decimal_with_excess_precision() {
+        let result = Price::try_from(Decimal::from_str("1.0000000001").unwrap());
+
+        assert!(result
+            .unwrap_err()
+            .contains("precision 10 exceeded maximum 9"));
+    }
+
+    #[test]
+    fn test_try_from_decimal_out_of_range() {
+        let result = Price::try_from(Decimal::from_str("9223372037").unwrap());
+
+        assert!(result.unwrap_err().contains("out of range"));
+    }
+
+    #[test]
+    fn test_from_minor_units() {
+        let price = Price::from_minor_units(123_450, 5).unwrap();
+
+        assert_eq!(price, Price::from("1.23450"));
+        assert_eq!(price.precision, 5);
+        assert_eq!(price.to_string(), "1.23450");
+        assert_eq!(
+            Price::from_minor_units(-123_450, 5).unwrap(),
+            Price::from("-1.23450")
+        );
+    }
+
+    #[test]
+    fn test_from_minor_units_with_excess_precision() {
+        let result = Price::from_minor_units(1, 10);
+
+        assert!(result
+            .unwrap_err()
+            .contains("precision 10 exceeded maximum 9"));
+    }
+
+    #[test]
+    fn test_from_minor_units_overflow() {
+        assert!(Price::from_minor_units(i64::MAX, 5)
+            .unwrap_err()
+            .contains("out of range"));
+        assert!(Price::from_minor_units(i64::MAX, 9)
+            .unwrap_err()
+            .contains("out of range"));
+    }
+
+    #[test]
+    fn test_checked_add_and_sub_with_matching_precision() {
+        let a = Price::from("1.00010");
+        let b = Price::from("0.00005");
+
+        assert_eq!(a.checked_add(b).unwrap(), Price::from("1.00015"));
+        assert_eq!(a.checked_sub(b).unwrap(), Price::from("1.00005"));
+        assert_eq!(a.checked_add(b).unwrap().precision, 5);
+    }
+
+    #[test]
+    fn test_checked_add_and_sub_with_mismatched_precision() {
+        let a = Price::from("1.00010");
+        let b = Price::from("0.01");
+
+        assert_eq!(a.checked_add(b), Err(PriceError::PrecisionMismatch(5, 2)));
+        assert_eq!(a.checked_sub(b), Err(PriceError::PrecisionMismatch(5, 2)));
+        assert_eq!(
+            a.checked_add(b).unwrap_err().to_string(),
+            "Precision mismatch: 5 and 2"
+        );
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        let a = Price::from_raw(i64::MAX, 9);
+        let b = Price::from_raw(1, 9);
+
+        assert_eq!(a.checked_add(b), Err(PriceError::Overflow));
+        assert_eq!((-a - b).checked_sub(b), Err(PriceError::Overflow));
+    }
+
+    #[test]
+    fn test_add_ticks() {
+        let price = Price::from("1.00010");
+        let increment = Price::from("0.00005");
+
+        assert_eq!(price.add_ticks(3, increment), Price::from("1.00025"));
+        assert_eq!(price.add_ticks(-3, increment), Price::from("0.99995"));
+        assert_eq!(price.add_ticks(0, increment), price);
+        assert_eq!(price.add_ticks(-3, increment).to_string(), "0.99995");
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn test_add_ticks_overflow() {
+        let _ = Price::from("1.0").add_ticks(i64::MAX, Price::from("0.1"));
+    }
+
+    #[test]
+    fn test_checked_div() {
+        let notional = Price::from("10.00");
+
+        assert_eq!(
+            notional.checked_div(Price::from("3.0")).unwrap(),
+            Price::from("3.33")
+        );
+        assert_eq!(
+            notional.checked_div(Price::from("6.0")).unwrap(),
+            Price::from("1.67")
+        );
+        assert_eq!(
+            (-notional).checked_div(Price::from("6.0")).unwrap(),
+            Price::from("-1.67")
+        );
+        assert_eq!(notional.checked_div(Price::from("4")).unwrap().precision, 2);
+    }
+
+    #[test]
+    fn test_checked_div_by_zero() {
+        assert_eq!(
+            Price::from("1.00").checked_div(Price::zero(2)),
+            Err(PriceError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_checked_div_overflow() {
+        assert_eq!(
+            Price::max(9).checked_div(Price::from("0.5")),
+            Err(PriceError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_to_formatted_string() {
+        let price = Price::from("1234567.12345");
+
+        assert_eq!(price.to_formatted_string(','), "1,234,567.12345");
+        assert_eq!(price.to_formatted_string('_'), "1_234_567.12345");
+        assert_eq!((-price).to_formatted_string(','), "-1,234,567.12345");
+        assert_eq!(price.to_string(), "1234567.12345");
+    }
 }
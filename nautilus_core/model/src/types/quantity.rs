@@ -23,16 +23,26 @@ use std::{
 
 use nautilus_core::{correctness, parsing::precision_from_str};
 use pyo3::prelude::*;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Deserializer, Serialize};
+use thiserror;
 
-use super::fixed::FIXED_SCALAR;
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+use super::fixed::{FIXED_PRECISION, FIXED_SCALAR};
+use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64, separate_thousands};
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 pub const QUANTITY_MIN: f64 = 0.0;
 
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum QuantityError {
+    #[error("`Quantity` value overflowed")]
+    Overflow,
+    #[error("`Quantity` division by zero")]
+    DivisionByZero,
+}
+
 #[repr(C)]
-#[derive(Copy, Clone, Eq, Default)]
+#[derive(Copy, Clone, Eq)]
 #[pyclass]
 pub struct Quantity {
     pub raw: u64,
@@ -55,6 +65,48 @@ impl Quantity {
         Self { raw, precision }
     }
 
+    /// Creates a new [`Quantity`] from a `raw` fixed-point value, usable in `const` contexts.
+    ///
+    /// The `raw` value is scaled by 10^9 ([`FIXED_PRECISION`] decimal places) regardless
+    /// of `precision`, so e.g. a value of 1.5 has a `raw` of `1_500_000_000`. The `raw`
+    /// value should be a multiple of 10^(9 - `precision`) to be representable at
+    /// `precision`.
+    ///
+    /// # Panics
+    ///
+    /// If `precision` exceeds [`FIXED_PRECISION`] (at compile time in a `const` context).
+    #[must_use]
+    pub const fn from_raw_const(raw: u64, precision: u8) -> Self {
+        assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+        Self { raw, precision }
+    }
+
+    /// Creates a new [`Quantity`] from an integer number of minor `units` at the given
+    /// `precision`, e.g. 150 at a precision of 2 is 1.50.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `precision` exceeds [`FIXED_PRECISION`], or the value is
+    /// outside the range [`QUANTITY_MIN`] to [`QUANTITY_MAX`].
+    pub fn from_minor_units(units: u64, precision: u8) -> Result<Self, String> {
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "`Quantity` precision {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        let raw = 10_u64
+            .checked_pow(u32::from(FIXED_PRECISION - precision))
+            .and_then(|scale| units.checked_mul(scale))
+            .filter(|raw| *raw <= (QUANTITY_MAX * FIXED_SCALAR) as u64)
+            .ok_or_else(|| {
+                format!(
+                    "`Quantity` value {units}e-{precision} out of range [{QUANTITY_MIN}, {QUANTITY_MAX}]"
+                )
+            })?;
+
+        Ok(Self { raw, precision })
+    }
+
     #[must_use]
     pub fn zero(precision: u8) -> Self {
         Self { raw: 0, precision }
@@ -74,6 +126,169 @@ impl Quantity {
     pub fn as_f64(&self) -> f64 {
         fixed_u64_to_f64(self.raw)
     }
+
+    /// Returns the given `pct` fraction (in the range [0, 1]) of this quantity, rounded to
+    /// this quantity's precision.
+    ///
+    /// If a `lot` size is given then the result is rounded down to a multiple of it. If
+    /// `clamp` is set then a `pct` outside the range [0, 1] is clamped to it, otherwise it
+    /// is an error.
+    ///
+    /// # Panics
+    ///
+    /// If `pct` is not in the range [0, 1] and `clamp` is not set (or `pct` is NaN), or if
+    /// `lot` is zero.
+    #[must_use]
+    pub fn percent(&self, pct: f64, lot: Option<Quantity>, clamp: bool) -> Self {
+        let pct = if clamp { pct.clamp(0.0, 1.0) } else { pct };
+        correctness::f64_in_range_inclusive(pct, 0.0, 1.0, "`pct`");
+
+        let mut result = Self::new(self.as_f64() * pct, self.precision);
+        if let Some(lot) = lot {
+            correctness::u64_in_range_inclusive(lot.raw, 1, u64::MAX, "`lot` raw");
+            result.raw -= result.raw % lot.raw;
+        }
+        result
+    }
+
+    /// Creates a new [`Quantity`] of `lots` multiples of the `lot_size`, at the precision
+    /// of the `lot_size`.
+    ///
+    /// # Panics
+    ///
+    /// If the resulting quantity exceeds [`QUANTITY_MAX`].
+    #[must_use]
+    pub fn from_lots(lots: u64, lot_size: Quantity) -> Self {
+        let raw = lots.saturating_mul(lot_size.raw);
+        correctness::f64_in_range_inclusive(
+            fixed_u64_to_f64(raw),
+            QUANTITY_MIN,
+            QUANTITY_MAX,
+            "`Quantity` value",
+        );
+        Self::from_raw(raw, lot_size.precision)
+    }
+
+    /// Returns the number of lots of `lot_size` in this quantity, or `None` if this
+    /// quantity is not an exact multiple of the `lot_size` (or the `lot_size` is zero).
+    #[must_use]
+    pub fn to_lots(&self, lot_size: Quantity) -> Option<u64> {
+        if lot_size.is_zero() {
+            return None;
+        }
+        let lots = self.raw / lot_size.raw;
+        (lots * lot_size.raw == self.raw).then_some(lots)
+    }
+
+    /// Splits this quantity into at most `n` slices of whole multiples of the `lot` size,
+    /// which sum exactly to this quantity (such as for TWAP or iceberg child orders).
+    ///
+    /// The lots are distributed evenly, with any remaining lots going one each to the
+    /// first slices. Any remainder smaller than a lot is added to the first slice, so
+    /// the sum is exact even when this quantity is not a multiple of the `lot`. Fewer
+    /// than `n` slices are returned when there are fewer lots than `n` (no slice is
+    /// ever zero), and none are returned for a zero quantity.
+    ///
+    /// The slices are at the greater of this quantity's and the `lot` precision.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero, or if `lot` is zero.
+    #[must_use]
+    pub fn split_into(&self, n: usize, lot: Quantity) -> Vec<Self> {
+        correctness::u64_in_range_inclusive(n as u64, 1, u64::MAX, "`n`");
+        correctness::u64_in_range_inclusive(lot.raw, 1, u64::MAX, "`lot` raw");
+
+        let precision = self.precision.max(lot.precision);
+        let lots = self.raw / lot.raw;
+        let odd_lot = self.raw % lot.raw;
+        if lots == 0 {
+            return match odd_lot {
+                0 => Vec::new(),
+                raw => vec![Self::from_raw(raw, precision)],
+            };
+        }
+
+        let n = (n as u64).min(lots);
+        let (lots_per_slice, extra_lots) = (lots / n, lots % n);
+        (0..n)
+            .map(|i| {
+                let slice_lots = lots_per_slice + u64::from(i < extra_lots);
+                let odd_lot = if i == 0 { odd_lot } else { 0 };
+                Self::from_raw(slice_lots * lot.raw + odd_lot, precision)
+            })
+            .collect()
+    }
+
+    /// Returns this quantity minus `other`, floored at zero, at the precision of this
+    /// quantity.
+    #[must_use]
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        Self::from_raw(self.raw.saturating_sub(other.raw), self.precision)
+    }
+
+    /// Returns the quantity formatted at its precision with the digits of the integer
+    /// part grouped in thousands by the `separator` (typically `,` or `_`), e.g.
+    /// `1,234,567.50`.
+    ///
+    /// The `Display` format is not separated, so that it can be parsed.
+    #[must_use]
+    pub fn to_formatted_string(&self, separator: char) -> String {
+        separate_thousands(&self.to_string(), separator)
+    }
+
+    /// Returns the absolute difference between this quantity and `other`, at the
+    /// greater of their precisions.
+    #[must_use]
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            raw: self.raw.abs_diff(other.raw),
+            precision: self.precision.max(other.precision),
+        }
+    }
+
+    /// Returns whether this quantity is within `tol` of `other` (inclusive).
+    ///
+    /// The comparison is on the fixed-point values, so quantities of differing
+    /// precision are compared by value.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tol: Self) -> bool {
+        self.abs_diff(other).raw <= tol.raw
+    }
+
+    /// Divides this quantity by `other`.
+    ///
+    /// The result is at the precision of this quantity (the dividend), rounded half
+    /// up, e.g. 10.00 / 3.0 is 3.33.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::DivisionByZero`] if `other` is zero, or
+    /// [`QuantityError::Overflow`] if the result is out of range.
+    pub fn checked_div(&self, other: Self) -> Result<Self, QuantityError> {
+        if other.raw == 0 {
+            return Err(QuantityError::DivisionByZero);
+        }
+        let step = 10_u128.pow(u32::from(FIXED_PRECISION - self.precision));
+        let numerator = u128::from(self.raw) * FIXED_SCALAR as u128;
+        let denominator = u128::from(other.raw) * step;
+        let mut units = numerator / denominator;
+        if 2 * (numerator % denominator) >= denominator {
+            units += 1;
+        }
+        let raw = u64::try_from(units * step).map_err(|_| QuantityError::Overflow)?;
+        Ok(Self::from_raw(raw, self.precision))
+    }
+}
+
+/// Provides a default [`Quantity`] of zero at precision 0.
+///
+/// As the precision is zero, a default value should be replaced (rather than
+/// added to) when the precision of an instrument is known.
+impl Default for Quantity {
+    fn default() -> Self {
+        Self::zero(0)
+    }
 }
 
 impl From<Quantity> for f64 {
@@ -88,6 +303,45 @@ impl From<&Quantity> for f64 {
     }
 }
 
+impl TryFrom<Decimal> for Quantity {
+    type Error = String;
+
+    /// Converts the `value`, using its scale as the precision.
+    ///
+    /// Returns an error if the scale exceeds [`FIXED_PRECISION`], or the value is outside
+    /// the range [`QUANTITY_MIN`] to [`QUANTITY_MAX`].
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let precision = value.scale();
+        if precision > u32::from(FIXED_PRECISION) {
+            return Err(format!(
+                "`Quantity` precision {precision} exceeded maximum {FIXED_PRECISION} for {value}"
+            ));
+        }
+        let raw = value
+            .checked_mul(Decimal::from(10_i64.pow(u32::from(FIXED_PRECISION))))
+            .and_then(|raw| raw.to_u64())
+            .filter(|raw| *raw <= (QUANTITY_MAX * FIXED_SCALAR) as u64)
+            .ok_or_else(|| {
+                format!("`Quantity` value {value} out of range [{QUANTITY_MIN}, {QUANTITY_MAX}]")
+            })?;
+
+        Ok(Self {
+            raw,
+            precision: precision as u8,
+        })
+    }
+}
+
+impl From<Quantity> for Decimal {
+    /// Converts the `value` with its precision as the scale.
+    fn from(value: Quantity) -> Self {
+        let mut decimal =
+            Decimal::from_i128_with_scale(i128::from(value.raw), u32::from(FIXED_PRECISION));
+        decimal.rescale(u32::from(value.precision));
+        decimal
+    }
+}
+
 impl FromStr for Quantity {
     type Err = String;
 
@@ -242,13 +496,12 @@ impl Serialize for Quantity {
 }
 
 impl<'de> Deserialize<'de> for Quantity {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let qty_str: &str = Deserialize::deserialize(_deserializer)?;
-        let qty: Quantity = qty_str.into();
-        Ok(qty)
+        let qty_str = String::deserialize(deserializer)?;
+        Quantity::from_str(&qty_str).map_err(serde::de::Error::custom)
     }
 }
 
@@ -299,6 +552,15 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_from_raw_const() {
+        const QTY: Quantity = Quantity::from_raw_const(100_500_000_000, 1);
+
+        assert_eq!(QTY, Quantity::new(100.5, 1));
+        assert_eq!(QTY.precision, 1);
+        assert_eq!(QTY.to_string(), "100.5");
+    }
+
     #[test]
     fn test_new() {
         let qty = Quantity::new(0.00812, 8);
@@ -349,6 +611,186 @@ mod tests {
         assert_eq!(qty.to_string(), "0.000000000");
     }
 
+    #[test]
+    fn test_percent() {
+        let qty = Quantity::new(100.0, 0);
+        assert_eq!(qty.percent(0.5, None, false), Quantity::new(50.0, 0));
+        assert_eq!(qty.percent(0.33, None, false), Quantity::new(33.0, 0));
+        assert_eq!(qty.percent(0.0, None, false), Quantity::zero(0));
+        assert_eq!(qty.percent(1.0, None, false), qty);
+    }
+
+    #[test]
+    fn test_percent_rounds_to_precision() {
+        let qty = Quantity::new(1.0, 2);
+        let result = qty.percent(1.0 / 3.0, None, false);
+        assert_eq!(result.to_string(), "0.33");
+        assert_eq!(result.precision, 2);
+    }
+
+    #[test]
+    fn test_percent_with_lot() {
+        let qty = Quantity::new(1000.0, 0);
+        let lot = Quantity::new(100.0, 0);
+        assert_eq!(qty.percent(0.5, Some(lot), false), Quantity::new(500.0, 0));
+        assert_eq!(qty.percent(0.33, Some(lot), false), Quantity::new(300.0, 0));
+        assert_eq!(qty.percent(0.05, Some(lot), false), Quantity::zero(0));
+    }
+
+    #[test]
+    fn test_from_lots() {
+        let lot_size = Quantity::new(0.25, 2);
+
+        let qty = Quantity::from_lots(7, lot_size);
+
+        assert_eq!(qty, Quantity::new(1.75, 2));
+        assert_eq!(qty.precision, 2);
+        assert_eq!(Quantity::from_lots(0, lot_size), Quantity::zero(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid f64 for `Quantity` value")]
+    fn test_from_lots_when_out_of_range() {
+        let _ = Quantity::from_lots(u64::MAX, Quantity::new(100.0, 0));
+    }
+
+    #[test]
+    fn test_split_into() {
+        let qty = Quantity::from(1_000);
+        let lot = Quantity::from(100);
+
+        let slices = qty.split_into(3, lot);
+
+        assert_eq!(
+            slices,
+            vec![
+                Quantity::from(400),
+                Quantity::from(300),
+                Quantity::from(300)
+            ]
+        );
+        assert_eq!(slices.iter().map(|q| q.raw).sum::<u64>(), qty.raw);
+    }
+
+    #[test]
+    fn test_split_into_with_odd_lot() {
+        let qty = Quantity::from("10.05");
+        let lot = Quantity::from("1.00");
+
+        let slices = qty.split_into(4, lot);
+
+        assert_eq!(
+            slices,
+            vec![
+                Quantity::from("3.05"),
+                Quantity::from("3.00"),
+                Quantity::from("2.00"),
+                Quantity::from("2.00"),
+            ]
+        );
+        assert_eq!(slices.iter().map(|q| q.raw).sum::<u64>(), qty.raw);
+        assert!(slices.iter().all(|q| q.precision == 2));
+    }
+
+    #[test]
+    fn test_split_into_more_slices_than_lots() {
+        let qty = Quantity::from(300);
+        let lot = Quantity::from(100);
+
+        let slices = qty.split_into(10, lot);
+
+        assert_eq!(slices, vec![Quantity::from(100); 3]);
+        assert_eq!(slices.iter().map(|q| q.raw).sum::<u64>(), qty.raw);
+    }
+
+    #[test]
+    fn test_split_into_less_than_one_lot() {
+        let lot = Quantity::from(100);
+
+        assert_eq!(
+            Quantity::from(50).split_into(5, lot),
+            vec![Quantity::from(50)]
+        );
+        assert!(Quantity::zero(0).split_into(5, lot).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_into_zero_slices() {
+        let _ = Quantity::from(100).split_into(0, Quantity::from(1));
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        let a = Quantity::from("10.5");
+        let b = Quantity::from("12");
+
+        assert_eq!(a.abs_diff(&b), Quantity::from("1.5"));
+        assert_eq!(b.abs_diff(&a), Quantity::from("1.5"));
+        assert_eq!(a.abs_diff(&b).precision, 1);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let tol = Quantity::from("0.01");
+
+        assert!(Quantity::from("1.005").approx_eq(&Quantity::from("1.00"), tol));
+        assert!(Quantity::from("1.01").approx_eq(&Quantity::from("1.00"), tol));
+        assert!(!Quantity::from("1.02").approx_eq(&Quantity::from("1.00"), tol));
+        assert!(!Quantity::from("0.98").approx_eq(&Quantity::from("1.00"), tol));
+    }
+
+    #[test]
+    fn test_to_lots_exact() {
+        let lot_size = Quantity::new(100.0, 0);
+
+        assert_eq!(Quantity::new(500.0, 0).to_lots(lot_size), Some(5));
+        assert_eq!(Quantity::zero(0).to_lots(lot_size), Some(0));
+        assert_eq!(
+            Quantity::from_lots(42, lot_size).to_lots(lot_size),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn test_to_lots_inexact() {
+        assert_eq!(
+            Quantity::new(550.0, 0).to_lots(Quantity::new(100.0, 0)),
+            None
+        );
+        assert_eq!(Quantity::new(1.0, 2).to_lots(Quantity::new(0.3, 2)), None);
+        assert_eq!(Quantity::new(1.0, 0).to_lots(Quantity::zero(0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid f64 for `pct` not in range [0, 1], was 1.5")]
+    fn test_percent_when_pct_out_of_range() {
+        let _ = Quantity::new(100.0, 0).percent(1.5, None, false);
+    }
+
+    #[test]
+    fn test_percent_when_pct_out_of_range_with_clamp() {
+        let qty = Quantity::new(100.0, 0);
+        assert_eq!(qty.percent(1.5, None, true), qty);
+        assert_eq!(qty.percent(-0.5, None, true), Quantity::zero(0));
+        assert_eq!(qty.percent(0.5, None, true), Quantity::new(50.0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_percent_with_zero_lot() {
+        let _ = Quantity::new(100.0, 0).percent(0.5, Some(Quantity::zero(0)), false);
+    }
+
+    #[test]
+    fn test_default() {
+        let qty = Quantity::default();
+        assert_eq!(qty.raw, 0);
+        assert_eq!(qty.precision, 0);
+        assert!(qty.is_zero());
+        assert_eq!(qty.to_string(), "0");
+    }
+
     #[test]
     fn test_is_zero() {
         let qty = Quantity::zero(8);
@@ -465,4 +907,150 @@ mod tests {
         assert_eq!(res, input_string);
         assert_eq!(qty.to_string(), input_string);
     }
+
+    #[test]
+    fn test_serde_json_round_trip() {
+        for (input, precision) in [("1.10", 2), ("1.10000", 5), ("0", 0), ("0.000000001", 9)] {
+            let qty = Quantity::from(input);
+            let json = serde_json::to_string(&qty).unwrap();
+            assert_eq!(json, format!("\"{input}\""));
+
+            let deserialized: Quantity = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, qty);
+            assert_eq!(deserialized.precision, precision);
+            assert_eq!(deserialized.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_serde_json_deserialize_invalid_string() {
+        let result: Result<Quantity, _> = serde_json::from_str("\"abc\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_from_decimal() {
+        let quantity = Quantity::try_from(Decimal::from_str("100.50").unwrap()).unwrap();
+
+        assert_eq!(quantity, Quantity::from("100.50"));
+        assert_eq!(quantity.precision, 2);
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        let decimal = Decimal::from_str("12345678.900000001").unwrap();
+
+        let quantity = Quantity::try_from(decimal).unwrap();
+
+        assert_eq!(quantity.raw, 12_345_678_900_000_001);
+        assert_eq!(Decimal::from(quantity), decimal);
+        assert_eq!(Decimal::from(Quantity::from(5)).to_string(), "5");
+    }
+
+    #[test]
+    fn test_try_from_decimal_with_excess_precision() {
+        let result = Quantity::try_from(Decimal::from_str("0.0000000001").unwrap());
+
+        assert!(result
+            .unwrap_err()
+            .contains("precision 10 exceeded maximum 9"));
+    }
+
+    #[test]
+    fn test_try_from_decimal_out_of_range() {
+        let negative = Quantity::try_from(Decimal::from_str("-1").unwrap());
+        let too_large = Quantity::try_from(Decimal::from_str("18446744074").unwrap());
+
+        assert!(negative.unwrap_err().contains("out of range"));
+        assert!(too_large.unwrap_err().contains("out of range"));
+    }
+
+    #[test]
+    fn test_from_minor_units() {
+        let qty = Quantity::from_minor_units(123_450, 5).unwrap();
+
+        assert_eq!(qty, Quantity::from("1.23450"));
+        assert_eq!(qty.precision, 5);
+        assert_eq!(qty.to_string(), "1.23450");
+    }
+
+    #[test]
+    fn test_from_minor_units_with_excess_precision() {
+        let result = Quantity::from_minor_units(1, 10);
+
+        assert!(result
+            .unwrap_err()
+            .contains("precision 10 exceeded maximum 9"));
+    }
+
+    #[test]
+    fn test_from_minor_units_overflow() {
+        assert!(Quantity::from_minor_units(u64::MAX, 5)
+            .unwrap_err()
+            .contains("out of range"));
+        assert!(Quantity::from_minor_units(u64::MAX, 9)
+            .unwrap_err()
+            .contains("out of range"));
+    }
+
+    #[test]
+    fn test_checked_div() {
+        let notional = Quantity::from("10.00");
+
+        assert_eq!(
+            notional.checked_div(Quantity::from("3.0")).unwrap(),
+            Quantity::from("3.33")
+        );
+        assert_eq!(
+            notional.checked_div(Quantity::from("6.0")).unwrap(),
+            Quantity::from("1.67")
+        );
+        assert_eq!(
+            notional.checked_div(Quantity::from(4)).unwrap().precision,
+            2
+        );
+    }
+
+    #[test]
+    fn test_checked_div_by_zero() {
+        assert_eq!(
+            Quantity::from("1.00").checked_div(Quantity::zero(2)),
+            Err(QuantityError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_saturating_sub() {
+        let qty = Quantity::from("1.50");
+
+        assert_eq!(
+            qty.saturating_sub(Quantity::from("0.25")),
+            Quantity::from("1.25")
+        );
+        assert_eq!(qty.saturating_sub(qty), Quantity::zero(2));
+    }
+
+    #[test]
+    fn test_saturating_sub_when_underflowing() {
+        let result = Quantity::from("1.50").saturating_sub(Quantity::from("2.000"));
+
+        assert!(result.is_zero());
+        assert!(!result.is_positive());
+        assert_eq!(result.precision, 2);
+        assert_eq!(result.to_string(), "0.00");
+    }
+
+    #[test]
+    fn test_to_formatted_string() {
+        let qty = Quantity::new(100_000.0, 0);
+
+        assert_eq!(qty.to_formatted_string(','), "100,000");
+        assert_eq!(qty.to_formatted_string('_'), "100_000");
+        assert_eq!(qty.to_string(), "100000");
+        assert_eq!(
+            Quantity::from("1234.500").to_formatted_string(','),
+            "1,234.500"
+        );
+        assert_eq!(Quantity::from("0.001").to_formatted_string(','), "0.001");
+    }
 }
@@ -24,9 +24,14 @@ use std::{
 };
 
 use datafusion::arrow::{
-    datatypes::SchemaRef, ipc::writer::StreamWriter, record_batch::RecordBatch,
+    array::{Array, StringArray},
+    compute::{filter_record_batch, kernels::comparison::eq_utf8_scalar},
+    datatypes::SchemaRef,
+    error::ArrowError,
+    ipc::writer::StreamWriter,
+    record_batch::RecordBatch,
 };
-use nautilus_model::data::Data;
+use nautilus_model::{data::Data, identifiers::instrument_id::InstrumentId};
 use pyo3::prelude::*;
 use thiserror;
 
@@ -51,6 +56,37 @@ pub enum DataStreamingError {
     PythonError(#[from] PyErr),
 }
 
+/// Filters the `record_batch` down to the rows for the given `instrument_id`, so
+/// that only those rows need to be decoded.
+///
+/// Multi-instrument batches carry a per-row `instrument_id` column, which is
+/// filtered with the Arrow `filter` kernel. Batches without the column hold a
+/// single instrument given by their schema metadata, and are either returned
+/// whole or emptied.
+pub fn filter_instrument_id(
+    record_batch: &RecordBatch,
+    instrument_id: &InstrumentId,
+) -> Result<RecordBatch, ArrowError> {
+    let instrument_id = instrument_id.to_string();
+    match record_batch.schema().index_of("instrument_id") {
+        Ok(index) => {
+            let values = record_batch
+                .column(index)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(|| {
+                    ArrowError::CastError("`instrument_id` column was not Utf8".to_string())
+                })?;
+            let mask = eq_utf8_scalar(values, &instrument_id)?;
+            filter_record_batch(record_batch, &mask)
+        }
+        Err(_) => match record_batch.schema().metadata().get("instrument_id") {
+            Some(value) if value != &instrument_id => Ok(record_batch.slice(0, 0)),
+            _ => Ok(record_batch.clone()),
+        },
+    }
+}
+
 pub trait ArrowSchemaProvider {
     fn get_schema(metadata: HashMap<String, String>) -> SchemaRef;
 }
@@ -107,6 +107,13 @@ impl EncodeToRecordBatch for TradeTick {
 }
 
 impl DecodeFromRecordBatch for TradeTick {
+    /// Decodes the `record_batch` into trade ticks.
+    ///
+    /// Some historical files do not contain a `ts_init` column, in which case
+    /// `ts_init` is decoded as zero for every tick.
+    ///
+    /// Multi-instrument files contain an additional `instrument_id` column, which
+    /// takes precedence over the `instrument_id` metadata for each row.
     fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Self> {
         // Parse and validate metadata
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata);
@@ -118,7 +125,33 @@ impl DecodeFromRecordBatch for TradeTick {
         let aggressor_side_values = cols[2].as_any().downcast_ref::<UInt8Array>().unwrap();
         let trade_id_values_values = cols[3].as_any().downcast_ref::<StringArray>().unwrap();
         let ts_event_values = cols[4].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_init_values = cols[5].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_init_values = match record_batch.schema().index_of("ts_init") {
+            Ok(index) => cols[index]
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap()
+                .clone(),
+            Err(_) => UInt64Array::from(vec![0; record_batch.num_rows()]),
+        };
+        let instrument_ids: Vec<InstrumentId> =
+            match record_batch.schema().index_of("instrument_id") {
+                Ok(index) => {
+                    let mut parsed: HashMap<&str, InstrumentId> = HashMap::new();
+                    cols[index]
+                        .as_any()
+                        .downcast_ref::<StringArray>()
+                        .unwrap()
+                        .iter()
+                        .map(|value| {
+                            let value = value.unwrap();
+                            *parsed
+                                .entry(value)
+                                .or_insert_with(|| InstrumentId::from_str(value).unwrap())
+                        })
+                        .collect()
+                }
+                Err(_) => vec![instrument_id; record_batch.num_rows()],
+            };
 
         // Construct iterator of values from arrays
         let values = price_values
@@ -127,9 +160,13 @@ impl DecodeFromRecordBatch for TradeTick {
             .zip(aggressor_side_values)
             .zip(trade_id_values_values)
             .zip(ts_event_values)
-            .zip(ts_init_values)
+            .zip(&ts_init_values)
+            .zip(instrument_ids)
             .map(
-                |(((((price, size), aggressor_side), trade_id), ts_event), ts_init)| Self {
+                |(
+                    (((((price, size), aggressor_side), trade_id), ts_event), ts_init),
+                    instrument_id,
+                )| Self {
                     instrument_id,
                     price: Price::from_raw(price.unwrap(), price_precision),
                     size: Quantity::from_raw(size.unwrap(), size_precision),
@@ -168,6 +205,7 @@ mod tests {
     };
 
     use super::*;
+    use crate::arrow::filter_instrument_id;
 
     #[test]
     fn test_get_schema() {
@@ -273,4 +311,105 @@ mod tests {
         let decoded_data = TradeTick::decode_batch(&metadata, record_batch);
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[test]
+    fn test_decode_batch_without_ts_init() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let metadata = TradeTick::get_metadata(&instrument_id, 2, 0);
+
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("price", DataType::Int64, false),
+                Field::new("size", DataType::UInt64, false),
+                Field::new("aggressor_side", DataType::UInt8, false),
+                Field::new("trade_id", DataType::Utf8, false),
+                Field::new("ts_event", DataType::UInt64, false),
+            ],
+            metadata.clone(),
+        );
+
+        let record_batch = RecordBatch::try_new(
+            schema.into(),
+            vec![
+                Arc::new(Int64Array::from(vec![1_000_000_000_000, 1_010_000_000_000])),
+                Arc::new(UInt64Array::from(vec![1000, 900])),
+                Arc::new(UInt8Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["1", "2"])),
+                Arc::new(UInt64Array::from(vec![1, 2])),
+            ],
+        )
+        .unwrap();
+
+        let decoded_data = TradeTick::decode_batch(&metadata, record_batch);
+        assert_eq!(decoded_data.len(), 2);
+        assert_eq!(decoded_data[0].ts_event, 1);
+        assert_eq!(decoded_data[0].ts_init, 0);
+        assert_eq!(decoded_data[1].ts_init, 0);
+    }
+
+    #[test]
+    fn test_decode_batch_after_instrument_id_filter() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let metadata = TradeTick::get_metadata(&instrument_id, 2, 0);
+
+        let mut fields = TradeTick::get_schema(metadata.clone()).fields().to_vec();
+        fields.push(Arc::new(Field::new("instrument_id", DataType::Utf8, false)));
+        let schema = Schema::new_with_metadata(fields, metadata.clone());
+
+        let record_batch = RecordBatch::try_new(
+            schema.into(),
+            vec![
+                Arc::new(Int64Array::from(vec![
+                    1_000_000_000_000,
+                    2_000_000_000_000,
+                    1_010_000_000_000,
+                ])),
+                Arc::new(UInt64Array::from(vec![1000, 2000, 900])),
+                Arc::new(UInt8Array::from(vec![1, 2, 1])),
+                Arc::new(StringArray::from(vec!["1", "2", "3"])),
+                Arc::new(UInt64Array::from(vec![1, 2, 3])),
+                Arc::new(UInt64Array::from(vec![4, 5, 6])),
+                Arc::new(StringArray::from(vec![
+                    "AAPL.NASDAQ",
+                    "MSFT.NASDAQ",
+                    "AAPL.NASDAQ",
+                ])),
+            ],
+        )
+        .unwrap();
+
+        let filtered = filter_instrument_id(&record_batch, &instrument_id).unwrap();
+        assert_eq!(filtered.num_rows(), 2);
+
+        let decoded_data = TradeTick::decode_batch(&metadata, filtered);
+        assert_eq!(decoded_data.len(), 2);
+        assert!(decoded_data
+            .iter()
+            .all(|tick| tick.instrument_id == instrument_id));
+        assert_eq!(decoded_data[0].trade_id, TradeId::new("1"));
+        assert_eq!(decoded_data[1].trade_id, TradeId::new("3"));
+    }
+
+    #[test]
+    fn test_instrument_id_filter_on_single_instrument_batch() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let metadata = TradeTick::get_metadata(&instrument_id, 2, 0);
+        let tick = TradeTick {
+            instrument_id,
+            price: Price::new(100.10, 2),
+            size: Quantity::new(1000.0, 0),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new("1"),
+            ts_event: 1,
+            ts_init: 2,
+        };
+        let record_batch = TradeTick::encode_batch(&metadata, &[tick]);
+
+        let matching = filter_instrument_id(&record_batch, &instrument_id).unwrap();
+        let other = InstrumentId::from_str("MSFT.NASDAQ").unwrap();
+        let non_matching = filter_instrument_id(&record_batch, &other).unwrap();
+
+        assert_eq!(matching.num_rows(), 1);
+        assert_eq!(non_matching.num_rows(), 0);
+    }
 }
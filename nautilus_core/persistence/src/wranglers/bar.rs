@@ -29,21 +29,53 @@ pub struct BarDataWrangler {
     metadata: HashMap<String, String>,
 }
 
-#[pymethods]
-impl BarDataWrangler {
-    #[new]
-    fn py_new(bar_type: &str, price_precision: u8, size_precision: u8) -> PyResult<Self> {
-        let bar_type =
-            BarType::from_str(bar_type).map_err(|e| PyValueError::new_err(e.to_string()))?;
+/// Decodes the bars from the given Arrow IPC `data`.
+fn decode_ipc_bytes(data: &[u8], metadata: &HashMap<String, String>) -> PyResult<Vec<Bar>> {
+    // Create a StreamReader (from Arrow IPC)
+    let cursor = Cursor::new(data);
+    let reader = match StreamReader::try_new(cursor, None) {
+        Ok(reader) => reader,
+        Err(e) => return Err(PyValueError::new_err(e.to_string())),
+    };
+
+    let mut bars = Vec::new();
+
+    // Read the record batches
+    for maybe_batch in reader {
+        let record_batch = match maybe_batch {
+            Ok(record_batch) => record_batch,
+            Err(e) => return Err(PyValueError::new_err(e.to_string())),
+        };
 
+        let batch_bars = Bar::decode_batch(metadata, record_batch);
+        bars.extend(batch_bars);
+    }
+
+    Ok(bars)
+}
+
+impl BarDataWrangler {
+    #[must_use]
+    pub fn new(bar_type: BarType, price_precision: u8, size_precision: u8) -> Self {
         let metadata = Bar::get_metadata(&bar_type, price_precision, size_precision);
 
-        Ok(Self {
+        Self {
             bar_type,
             price_precision,
             size_precision,
             metadata,
-        })
+        }
+    }
+}
+
+#[pymethods]
+impl BarDataWrangler {
+    #[new]
+    fn py_new(bar_type: &str, price_precision: u8, size_precision: u8) -> PyResult<Self> {
+        let bar_type =
+            BarType::from_str(bar_type).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self::new(bar_type, price_precision, size_precision))
     }
 
     #[getter]
@@ -62,26 +94,66 @@ impl BarDataWrangler {
     }
 
     fn process_record_batches_bytes(&self, _py: Python, data: &[u8]) -> PyResult<Vec<Bar>> {
-        // Create a StreamReader (from Arrow IPC)
-        let cursor = Cursor::new(data);
-        let reader = match StreamReader::try_new(cursor, None) {
-            Ok(reader) => reader,
-            Err(e) => return Err(PyValueError::new_err(e.to_string())),
-        };
+        decode_ipc_bytes(data, &self.metadata)
+    }
+}
 
-        let mut bars = Vec::new();
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::ipc::writer::StreamWriter;
+    use nautilus_model::types::{price::Price, quantity::Quantity};
 
-        // Read the record batches
-        for maybe_batch in reader {
-            let record_batch = match maybe_batch {
-                Ok(record_batch) => record_batch,
-                Err(e) => return Err(PyValueError::new_err(e.to_string())),
-            };
+    use super::*;
+    use crate::arrow::EncodeToRecordBatch;
 
-            let batch_bars = Bar::decode_batch(&self.metadata, record_batch);
-            bars.extend(batch_bars);
+    fn bar_type() -> BarType {
+        BarType::from_str("AAPL.NASDAQ-1-MINUTE-LAST-INTERNAL").unwrap()
+    }
+
+    fn bar(close: &str, ts: u64) -> Bar {
+        Bar {
+            bar_type: bar_type(),
+            open: Price::from("100.10"),
+            high: Price::from("102.00"),
+            low: Price::from("100.00"),
+            close: Price::from(close),
+            volume: Quantity::from(1100),
+            ts_event: ts,
+            ts_init: ts,
         }
+    }
+
+    #[test]
+    fn test_py_new_parses_bar_type() {
+        let wrangler = BarDataWrangler::py_new("AAPL.NASDAQ-1-MINUTE-LAST-INTERNAL", 2, 0).unwrap();
+
+        assert_eq!(wrangler.bar_type, bar_type());
+        assert_eq!(
+            wrangler.bar_type(),
+            "AAPL.NASDAQ-1-MINUTE-LAST-INTERNAL".to_string()
+        );
+        assert_eq!(wrangler.metadata, Bar::get_metadata(&bar_type(), 2, 0));
+    }
+
+    #[test]
+    fn test_py_new_with_malformed_bar_type() {
+        assert!(BarDataWrangler::py_new("AAPL.NASDAQ-1-MINUTE", 2, 0).is_err());
+    }
+
+    #[test]
+    fn test_decode_ipc_bytes_round_trip() {
+        let wrangler = BarDataWrangler::new(bar_type(), 2, 0);
+        let bars = vec![bar("101.50", 60), bar("101.75", 120)];
+        let record_batch = Bar::encode_batch(&wrangler.metadata, &bars);
+        let mut writer = StreamWriter::try_new(Vec::new(), &record_batch.schema()).unwrap();
+        writer.write(&record_batch).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let decoded = decode_ipc_bytes(&data, &wrangler.metadata).unwrap();
 
-        Ok(bars)
+        assert_eq!(decoded, bars);
     }
 }
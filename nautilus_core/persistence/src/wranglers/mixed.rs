@@ -0,0 +1,486 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, io::Cursor, str::FromStr};
+
+use datafusion::arrow::{
+    array::{Array, BooleanArray, UInt8Array},
+    compute::filter_record_batch,
+    error::ArrowError,
+    ipc::reader::StreamReader,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data,
+    },
+    identifiers::instrument_id::InstrumentId,
+};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::arrow::{ArrowSchemaProvider, DecodeDataFromRecordBatch, NautilusDataType};
+
+/// Decodes the rows of the given `data_type` from the mixed `record_batch` into `slots`,
+/// placing each decoded item at the index of its row.
+///
+/// The rows are filtered by the `data_type` column, then projected down to the columns
+/// of the schema for `T` (by name), so they can be decoded as a batch of `T` alone.
+fn decode_rows<T: DecodeDataFromRecordBatch>(
+    metadata: &HashMap<String, String>,
+    record_batch: &RecordBatch,
+    data_types: &UInt8Array,
+    data_type: NautilusDataType,
+    slots: &mut [Option<Data>],
+) -> Result<(), ArrowError> {
+    let selected: Vec<bool> = (0..data_types.len())
+        .map(|row| data_types.is_valid(row) && data_types.value(row) == data_type as u8)
+        .collect();
+    let rows: Vec<usize> = (0..selected.len()).filter(|&row| selected[row]).collect();
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let mask = BooleanArray::from(selected);
+    let filtered = filter_record_batch(record_batch, &mask)?;
+    let schema = filtered.schema();
+    let indices = T::get_schema(metadata.clone())
+        .fields()
+        .iter()
+        .map(|field| schema.index_of(field.name()))
+        .collect::<Result<Vec<usize>, ArrowError>>()?;
+    let projected = filtered.project(&indices)?;
+
+    for (row, data) in rows
+        .into_iter()
+        .zip(T::decode_data_batch(metadata, projected))
+    {
+        slots[row] = Some(data);
+    }
+    Ok(())
+}
+
+/// Decodes the mixed `record_batch` into data, in chronological order.
+///
+/// Each row is decoded as the type given by its `data_type` column (a
+/// [`NautilusDataType`] value), from the columns of that type's schema. Rows are
+/// stably sorted by `ts_init`, so rows with equal timestamps keep their order in the
+/// batch.
+///
+/// # Errors
+///
+/// Returns an error if the `data_type` column is missing or not `UInt8`, if a row has
+/// a missing or unsupported data type, if bars are present without a `bar_type` in the
+/// `metadata`, or if a column required to decode a row is missing.
+pub fn decode_mixed_batch(
+    metadata: &HashMap<String, String>,
+    record_batch: &RecordBatch,
+) -> Result<Vec<Data>, ArrowError> {
+    let index = record_batch.schema().index_of("data_type")?;
+    let data_types = record_batch
+        .column(index)
+        .as_any()
+        .downcast_ref::<UInt8Array>()
+        .ok_or_else(|| ArrowError::CastError("`data_type` column was not UInt8".to_string()))?;
+
+    for row in 0..data_types.len() {
+        if data_types.is_null(row) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "missing `data_type` at row {row}"
+            )));
+        }
+        let data_type = data_types.value(row);
+        if data_type == NautilusDataType::Bar as u8 && !metadata.contains_key("bar_type") {
+            return Err(ArrowError::InvalidArgumentError(
+                "bar rows require a `bar_type` in the metadata".to_string(),
+            ));
+        }
+        if ![
+            NautilusDataType::QuoteTick as u8,
+            NautilusDataType::TradeTick as u8,
+            NautilusDataType::Bar as u8,
+        ]
+        .contains(&data_type)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "unsupported `data_type` {data_type} at row {row}"
+            )));
+        }
+    }
+
+    let mut slots = vec![None; record_batch.num_rows()];
+    decode_rows::<QuoteTick>(
+        metadata,
+        record_batch,
+        data_types,
+        NautilusDataType::QuoteTick,
+        &mut slots,
+    )?;
+    decode_rows::<TradeTick>(
+        metadata,
+        record_batch,
+        data_types,
+        NautilusDataType::TradeTick,
+        &mut slots,
+    )?;
+    decode_rows::<Bar>(
+        metadata,
+        record_batch,
+        data_types,
+        NautilusDataType::Bar,
+        &mut slots,
+    )?;
+
+    let mut data: Vec<Data> = slots.into_iter().flatten().collect();
+    data.sort_by_key(Data::get_ts_init);
+    Ok(data)
+}
+
+/// Provides a wrangler for Arrow data which interleaves quotes, trades and bars (for
+/// a single instrument) in one file, with a `data_type` column giving the type of
+/// each row.
+#[pyclass]
+pub struct MixedDataWrangler {
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    bar_type: Option<BarType>,
+    metadata: HashMap<String, String>,
+}
+
+impl MixedDataWrangler {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        bar_type: Option<BarType>,
+    ) -> Self {
+        let mut metadata = TradeTick::get_metadata(&instrument_id, price_precision, size_precision);
+        if let Some(bar_type) = &bar_type {
+            metadata.extend(Bar::get_metadata(bar_type, price_precision, size_precision));
+        }
+
+        Self {
+            instrument_id,
+            price_precision,
+            size_precision,
+            bar_type,
+            metadata,
+        }
+    }
+}
+
+#[pymethods]
+impl MixedDataWrangler {
+    #[new]
+    #[pyo3(signature=(instrument_id, price_precision, size_precision, bar_type=None))]
+    fn py_new(
+        instrument_id: &str,
+        price_precision: u8,
+        size_precision: u8,
+        bar_type: Option<&str>,
+    ) -> PyResult<Self> {
+        let instrument_id = InstrumentId::from_str(instrument_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let bar_type = bar_type
+            .map(BarType::from_str)
+            .transpose()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self::new(
+            instrument_id,
+            price_precision,
+            size_precision,
+            bar_type,
+        ))
+    }
+
+    #[getter]
+    fn instrument_id(&self) -> String {
+        self.instrument_id.to_string()
+    }
+
+    #[getter]
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    #[getter]
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    #[getter]
+    fn bar_type(&self) -> Option<String> {
+        self.bar_type.as_ref().map(BarType::to_string)
+    }
+
+    /// Process the given Arrow IPC `data` into quotes, trades and bars, in
+    /// chronological order across all the record batches.
+    fn process_record_batches_bytes(&self, py: Python, data: &[u8]) -> PyResult<Vec<PyObject>> {
+        // Create a StreamReader (from Arrow IPC)
+        let cursor = Cursor::new(data);
+        let reader = match StreamReader::try_new(cursor, None) {
+            Ok(reader) => reader,
+            Err(e) => return Err(PyValueError::new_err(e.to_string())),
+        };
+
+        let mut items = Vec::new();
+
+        // Read the record batches
+        for maybe_batch in reader {
+            let record_batch = match maybe_batch {
+                Ok(record_batch) => record_batch,
+                Err(e) => return Err(PyValueError::new_err(e.to_string())),
+            };
+
+            let batch_items = decode_mixed_batch(&self.metadata, &record_batch)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            items.extend(batch_items);
+        }
+        items.sort_by_key(Data::get_ts_init);
+
+        Ok(items
+            .into_iter()
+            .map(|item| match item {
+                Data::Quote(quote) => quote.into_py(py),
+                Data::Trade(trade) => trade.into_py(py),
+                Data::Bar(bar) => bar.into_py(py),
+                Data::Delta(delta) => delta.into_py(py),
+            })
+            .collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::{
+        array::{ArrayRef, Int64Array, StringArray, UInt64Array},
+        datatypes::{DataType, Field, Schema},
+    };
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::trade_id::TradeId,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+
+    fn wrangler() -> MixedDataWrangler {
+        MixedDataWrangler::new(InstrumentId::from_str("AAPL.NASDAQ").unwrap(), 2, 0, None)
+    }
+
+    fn quote(ts: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            bid: Price::from("100.00"),
+            ask: Price::from("100.02"),
+            bid_size: Quantity::from(500),
+            ask_size: Quantity::from(300),
+            ts_event: ts,
+            ts_init: ts,
+        }
+    }
+
+    fn trade(ts: u64) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            price: Price::from("100.01"),
+            size: Quantity::from(100),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new(&ts.to_string()),
+            ts_event: ts,
+            ts_init: ts,
+        }
+    }
+
+    /// Encodes the interleaved quotes and trades as a single mixed record batch, with
+    /// the columns of the other type null for each row.
+    fn mixed_batch(metadata: &HashMap<String, String>, data: &[Data]) -> RecordBatch {
+        let mut data_type = Vec::new();
+        let mut bid = Vec::new();
+        let mut ask = Vec::new();
+        let mut bid_size = Vec::new();
+        let mut ask_size = Vec::new();
+        let mut price = Vec::new();
+        let mut size = Vec::new();
+        let mut aggressor_side = Vec::new();
+        let mut trade_id = Vec::new();
+        let mut ts_event = Vec::new();
+        let mut ts_init = Vec::new();
+
+        for item in data {
+            match item {
+                Data::Quote(q) => {
+                    data_type.push(NautilusDataType::QuoteTick as u8);
+                    bid.push(Some(q.bid.raw));
+                    ask.push(Some(q.ask.raw));
+                    bid_size.push(Some(q.bid_size.raw));
+                    ask_size.push(Some(q.ask_size.raw));
+                    price.push(None);
+                    size.push(None);
+                    aggressor_side.push(None);
+                    trade_id.push(None);
+                }
+                Data::Trade(t) => {
+                    data_type.push(NautilusDataType::TradeTick as u8);
+                    bid.push(None);
+                    ask.push(None);
+                    bid_size.push(None);
+                    ask_size.push(None);
+                    price.push(Some(t.price.raw));
+                    size.push(Some(t.size.raw));
+                    aggressor_side.push(Some(t.aggressor_side as u8));
+                    trade_id.push(Some(t.trade_id.to_string()));
+                }
+                _ => unreachable!(),
+            }
+            ts_event.push(item.get_ts_init());
+            ts_init.push(item.get_ts_init());
+        }
+
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("data_type", DataType::UInt8, false),
+                Field::new("bid", DataType::Int64, true),
+                Field::new("ask", DataType::Int64, true),
+                Field::new("bid_size", DataType::UInt64, true),
+                Field::new("ask_size", DataType::UInt64, true),
+                Field::new("price", DataType::Int64, true),
+                Field::new("size", DataType::UInt64, true),
+                Field::new("aggressor_side", DataType::UInt8, true),
+                Field::new("trade_id", DataType::Utf8, true),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ts_init", DataType::UInt64, false),
+            ],
+            metadata.clone(),
+        );
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt8Array::from(data_type)),
+            Arc::new(Int64Array::from(bid)),
+            Arc::new(Int64Array::from(ask)),
+            Arc::new(UInt64Array::from(bid_size)),
+            Arc::new(UInt64Array::from(ask_size)),
+            Arc::new(Int64Array::from(price)),
+            Arc::new(UInt64Array::from(size)),
+            Arc::new(UInt8Array::from(aggressor_side)),
+            Arc::new(StringArray::from(trade_id)),
+            Arc::new(UInt64Array::from(ts_event)),
+            Arc::new(UInt64Array::from(ts_init)),
+        ];
+        RecordBatch::try_new(Arc::new(schema), columns).unwrap()
+    }
+
+    fn ts_and_kind(data: &[Data]) -> Vec<(u64, &'static str)> {
+        data.iter()
+            .map(|item| match item {
+                Data::Quote(q) => (q.ts_init, "quote"),
+                Data::Trade(t) => (t.ts_init, "trade"),
+                Data::Bar(b) => (b.ts_init, "bar"),
+                Data::Delta(d) => (d.ts_init, "delta"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_mixed_batch_with_interleaved_trades_and_quotes() {
+        let wrangler = wrangler();
+        let input = vec![
+            Data::Quote(quote(1)),
+            Data::Trade(trade(2)),
+            Data::Quote(quote(3)),
+            Data::Quote(quote(4)),
+            Data::Trade(trade(5)),
+        ];
+        let record_batch = mixed_batch(&wrangler.metadata, &input);
+
+        let data = decode_mixed_batch(&wrangler.metadata, &record_batch).unwrap();
+
+        assert_eq!(
+            ts_and_kind(&data),
+            vec![
+                (1, "quote"),
+                (2, "trade"),
+                (3, "quote"),
+                (4, "quote"),
+                (5, "trade"),
+            ]
+        );
+        match (&data[1], &data[3]) {
+            (Data::Trade(t), Data::Quote(q)) => {
+                assert_eq!(t, &trade(2));
+                assert_eq!(q, &quote(4));
+            }
+            _ => panic!("unexpected data types"),
+        }
+    }
+
+    #[test]
+    fn test_decode_mixed_batch_sorts_by_ts_init() {
+        let wrangler = wrangler();
+        let input = vec![
+            Data::Trade(trade(3)),
+            Data::Quote(quote(1)),
+            Data::Quote(quote(3)),
+            Data::Trade(trade(2)),
+        ];
+        let record_batch = mixed_batch(&wrangler.metadata, &input);
+
+        let data = decode_mixed_batch(&wrangler.metadata, &record_batch).unwrap();
+
+        // Equal timestamps keep their order in the batch
+        assert_eq!(
+            ts_and_kind(&data),
+            vec![(1, "quote"), (2, "trade"), (3, "trade"), (3, "quote")]
+        );
+    }
+
+    #[test]
+    fn test_decode_mixed_batch_without_data_type_column() {
+        let wrangler = wrangler();
+        let record_batch = mixed_batch(&wrangler.metadata, &[Data::Quote(quote(1))]);
+        let record_batch = record_batch
+            .project(&(1..record_batch.num_columns()).collect::<Vec<_>>())
+            .unwrap();
+
+        assert!(decode_mixed_batch(&wrangler.metadata, &record_batch).is_err());
+    }
+
+    #[test]
+    fn test_decode_mixed_batch_with_unsupported_data_type() {
+        let wrangler = wrangler();
+        let record_batch = mixed_batch(&wrangler.metadata, &[Data::Quote(quote(1))]);
+        let mut columns = record_batch.columns().to_vec();
+        columns[0] = Arc::new(UInt8Array::from(vec![
+            NautilusDataType::OrderBookDelta as u8,
+        ]));
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+
+        let result = decode_mixed_batch(&wrangler.metadata, &record_batch);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("unsupported `data_type` 1 at row 0"));
+    }
+}
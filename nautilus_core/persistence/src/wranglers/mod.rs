@@ -15,5 +15,6 @@
 
 pub mod bar;
 pub mod delta;
+pub mod mixed;
 pub mod quote;
 pub mod trade;
@@ -29,21 +29,53 @@ pub struct QuoteTickDataWrangler {
     metadata: HashMap<String, String>,
 }
 
-#[pymethods]
-impl QuoteTickDataWrangler {
-    #[new]
-    fn py_new(instrument_id: &str, price_precision: u8, size_precision: u8) -> PyResult<Self> {
-        let instrument_id = InstrumentId::from_str(instrument_id)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+/// Decodes the quote ticks from the given Arrow IPC `data`.
+fn decode_ipc_bytes(data: &[u8], metadata: &HashMap<String, String>) -> PyResult<Vec<QuoteTick>> {
+    // Create a StreamReader (from Arrow IPC)
+    let cursor = Cursor::new(data);
+    let reader = match StreamReader::try_new(cursor, None) {
+        Ok(reader) => reader,
+        Err(e) => return Err(PyValueError::new_err(e.to_string())),
+    };
+
+    let mut quotes = Vec::new();
+
+    // Read the record batches
+    for maybe_batch in reader {
+        let record_batch = match maybe_batch {
+            Ok(record_batch) => record_batch,
+            Err(e) => return Err(PyValueError::new_err(e.to_string())),
+        };
+
+        let batch_quotes = QuoteTick::decode_batch(metadata, record_batch);
+        quotes.extend(batch_quotes);
+    }
+
+    Ok(quotes)
+}
 
+impl QuoteTickDataWrangler {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, price_precision: u8, size_precision: u8) -> Self {
         let metadata = QuoteTick::get_metadata(&instrument_id, price_precision, size_precision);
 
-        Ok(Self {
+        Self {
             instrument_id,
             price_precision,
             size_precision,
             metadata,
-        })
+        }
+    }
+}
+
+#[pymethods]
+impl QuoteTickDataWrangler {
+    #[new]
+    fn py_new(instrument_id: &str, price_precision: u8, size_precision: u8) -> PyResult<Self> {
+        let instrument_id = InstrumentId::from_str(instrument_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self::new(instrument_id, price_precision, size_precision))
     }
 
     #[getter]
@@ -62,26 +94,84 @@ impl QuoteTickDataWrangler {
     }
 
     fn process_record_batches_bytes(&self, _py: Python, data: &[u8]) -> PyResult<Vec<QuoteTick>> {
-        // Create a StreamReader (from Arrow IPC)
-        let cursor = Cursor::new(data);
-        let reader = match StreamReader::try_new(cursor, None) {
-            Ok(reader) => reader,
-            Err(e) => return Err(PyValueError::new_err(e.to_string())),
-        };
+        decode_ipc_bytes(data, &self.metadata)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::ipc::writer::StreamWriter;
+    use nautilus_model::types::{price::Price, quantity::Quantity};
 
-        let mut quotes = Vec::new();
+    use super::*;
+    use crate::arrow::EncodeToRecordBatch;
 
-        // Read the record batches
-        for maybe_batch in reader {
-            let record_batch = match maybe_batch {
-                Ok(record_batch) => record_batch,
-                Err(e) => return Err(PyValueError::new_err(e.to_string())),
-            };
+    fn quote(bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_str("EUR/USD.SIM").unwrap(),
+            bid: Price::from(bid),
+            ask: Price::from(ask),
+            bid_size: Quantity::from(100_000),
+            ask_size: Quantity::from(75_000),
+            ts_event: ts,
+            ts_init: ts,
+        }
+    }
 
-            let batch_deltas = QuoteTick::decode_batch(&self.metadata, record_batch);
-            quotes.extend(batch_deltas);
+    fn ipc_bytes(metadata: &HashMap<String, String>, batches: &[Vec<QuoteTick>]) -> Vec<u8> {
+        let record_batches: Vec<_> = batches
+            .iter()
+            .map(|quotes| QuoteTick::encode_batch(metadata, quotes))
+            .collect();
+        let mut writer = StreamWriter::try_new(Vec::new(), &record_batches[0].schema()).unwrap();
+        for record_batch in &record_batches {
+            writer.write(record_batch).unwrap();
         }
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_new_builds_metadata() {
+        let instrument_id = InstrumentId::from_str("EUR/USD.SIM").unwrap();
+
+        let wrangler = QuoteTickDataWrangler::new(instrument_id, 5, 0);
+
+        assert_eq!(wrangler.instrument_id, instrument_id);
+        assert_eq!(
+            wrangler.metadata,
+            QuoteTick::get_metadata(&instrument_id, 5, 0)
+        );
+    }
+
+    #[test]
+    fn test_decode_ipc_bytes() {
+        let wrangler =
+            QuoteTickDataWrangler::new(InstrumentId::from_str("EUR/USD.SIM").unwrap(), 5, 0);
+        let quotes = vec![
+            quote("1.10000", "1.10002", 1),
+            quote("1.10001", "1.10003", 2),
+            quote("1.10002", "1.10005", 3),
+        ];
+        let data = ipc_bytes(
+            &wrangler.metadata,
+            &[quotes[..2].to_vec(), quotes[2..].to_vec()],
+        );
+
+        let decoded = decode_ipc_bytes(&data, &wrangler.metadata).unwrap();
+
+        assert_eq!(decoded, quotes);
+        assert_eq!(decoded[2].bid, Price::from("1.10002"));
+        assert_eq!(decoded[2].ask, Price::from("1.10005"));
+    }
+
+    #[test]
+    fn test_decode_ipc_bytes_with_invalid_data() {
+        let wrangler =
+            QuoteTickDataWrangler::new(InstrumentId::from_str("EUR/USD.SIM").unwrap(), 5, 0);
 
-        Ok(quotes)
+        assert!(decode_ipc_bytes(&[0, 1, 2, 3], &wrangler.metadata).is_err());
     }
 }
@@ -16,34 +16,214 @@
 use std::{collections::HashMap, io::Cursor, str::FromStr};
 
 use datafusion::arrow::ipc::reader::StreamReader;
-use nautilus_model::{data::trade::TradeTick, identifiers::instrument_id::InstrumentId};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::trade::TradeTick, identifiers::instrument_id::InstrumentId, instruments::Instrument,
+    types::price::Price,
+};
 use pyo3::{exceptions::PyValueError, prelude::*};
 
-use crate::arrow::DecodeFromRecordBatch;
+use crate::arrow::{filter_instrument_id, DecodeFromRecordBatch};
 
 #[pyclass]
 pub struct TradeTickDataWrangler {
     instrument_id: InstrumentId,
     price_precision: u8,
     size_precision: u8,
+    ts_init_delta: Option<UnixNanos>,
     metadata: HashMap<String, String>,
+    sanitized_count: usize,
+}
+
+/// Sets a synthetic `ts_init` of `ts_event + ts_init_delta` for each tick which
+/// was decoded without one (a `ts_init` of zero).
+fn fill_missing_ts_init(ticks: &mut [TradeTick], ts_init_delta: UnixNanos) {
+    for tick in ticks.iter_mut().filter(|tick| tick.ts_init == 0) {
+        tick.ts_init = tick.ts_event + ts_init_delta;
+    }
+}
+
+/// The handling of invalid trade ticks (see `is_valid_trade`) when processing.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum InvalidTradePolicy {
+    /// Keeps invalid ticks unchanged, as before ticks were validated.
+    Keep,
+    /// Raises a `ValueError` if any tick is invalid.
+    #[default]
+    Raise,
+    /// Drops invalid ticks, counting them in `sanitized_count`.
+    Drop,
+}
+
+impl FromStr for InvalidTradePolicy {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "keep" => Ok(Self::Keep),
+            "raise" => Ok(Self::Raise),
+            "drop" => Ok(Self::Drop),
+            _ => Err(PyValueError::new_err(format!(
+                "invalid `InvalidTradePolicy`, was '{s}' (expected 'keep', 'raise' or 'drop')"
+            ))),
+        }
+    }
+}
+
+/// Returns whether the `tick` has a valid price and a positive size.
+///
+/// Prices are decoded from raw fixed-point values, so a NaN or infinite price in the
+/// source data can only appear as a raw value outside the valid price range (NumPy
+/// casts NaN to `i64::MIN`).
+fn is_valid_trade(tick: &TradeTick) -> bool {
+    let precision = tick.price.precision;
+    (Price::min(precision).raw..=Price::max(precision).raw).contains(&tick.price.raw)
+        && tick.size.raw > 0
+}
+
+/// Removes any invalid ticks from `ticks`, returning the number removed.
+fn sanitize_trades(ticks: &mut Vec<TradeTick>) -> usize {
+    let len = ticks.len();
+    ticks.retain(is_valid_trade);
+    len - ticks.len()
+}
+
+/// Applies the invalid tick `policy` to the `ticks`, returning the number dropped.
+fn apply_invalid_policy(ticks: &mut Vec<TradeTick>, policy: InvalidTradePolicy) -> PyResult<usize> {
+    match policy {
+        InvalidTradePolicy::Keep => Ok(0),
+        InvalidTradePolicy::Raise => {
+            let count = ticks.iter().filter(|tick| !is_valid_trade(tick)).count();
+            if count > 0 {
+                return Err(PyValueError::new_err(format!(
+                    "{count} invalid trade tick(s) with an out of range price or a non-positive size"
+                )));
+            }
+            Ok(0)
+        }
+        InvalidTradePolicy::Drop => Ok(sanitize_trades(ticks)),
+    }
+}
+
+/// Decodes the trade ticks from the given Arrow IPC `data`, calling `on_batch` after
+/// each record batch is decoded with the batch index and the number of rows so far.
+fn decode_ipc_bytes(
+    data: &[u8],
+    metadata: &HashMap<String, String>,
+    mut on_batch: impl FnMut(usize, usize) -> PyResult<()>,
+) -> PyResult<Vec<TradeTick>> {
+    // Create a StreamReader (from Arrow IPC)
+    let cursor = Cursor::new(data);
+    let reader = match StreamReader::try_new(cursor, None) {
+        Ok(reader) => reader,
+        Err(e) => return Err(PyValueError::new_err(e.to_string())),
+    };
+
+    let mut ticks = Vec::new();
+
+    // Read the record batches
+    for (batch_index, maybe_batch) in reader.enumerate() {
+        let record_batch = match maybe_batch {
+            Ok(record_batch) => record_batch,
+            Err(e) => return Err(PyValueError::new_err(e.to_string())),
+        };
+
+        let batch_ticks = TradeTick::decode_batch(metadata, record_batch);
+        ticks.extend(batch_ticks);
+        on_batch(batch_index, ticks.len())?;
+    }
+
+    Ok(ticks)
+}
+
+/// Sorts the `ticks` into chronological order by `ts_event`.
+///
+/// The sort is stable, so ticks with equal timestamps keep their input order (the
+/// original row order is the tie-break), which keeps backtests reproducible.
+fn sort_by_ts_event(ticks: &mut [TradeTick]) {
+    ticks.sort_by_key(|tick| tick.ts_event);
+}
+
+/// Buckets the given `ticks` into `groups` by instrument, preserving the order
+/// of the ticks within each instrument.
+fn group_by_instrument(
+    ticks: impl IntoIterator<Item = TradeTick>,
+    groups: &mut HashMap<InstrumentId, Vec<TradeTick>>,
+) {
+    for tick in ticks {
+        groups.entry(tick.instrument_id).or_default().push(tick);
+    }
+}
+
+impl TradeTickDataWrangler {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        ts_init_delta: Option<UnixNanos>,
+    ) -> Self {
+        let metadata = TradeTick::get_metadata(&instrument_id, price_precision, size_precision);
+
+        Self {
+            instrument_id,
+            price_precision,
+            size_precision,
+            ts_init_delta,
+            metadata,
+            sanitized_count: 0,
+        }
+    }
+
+    /// Creates a new wrangler for the given `instrument`, deriving the price and size
+    /// precisions from it rather than requiring them explicitly.
+    #[must_use]
+    pub fn from_instrument(instrument: &dyn Instrument, ts_init_delta: Option<UnixNanos>) -> Self {
+        Self::new(
+            *instrument.id(),
+            instrument.price_precision(),
+            instrument.size_precision(),
+            ts_init_delta,
+        )
+    }
+
+    /// Creates a new wrangler for the given `instrument_id`, deriving the price and
+    /// size precisions from the instrument returned by `lookup`.
+    ///
+    /// Returns `None` if `lookup` has no instrument for the ID.
+    pub fn from_instrument_lookup<'a>(
+        instrument_id: &InstrumentId,
+        lookup: impl FnOnce(&InstrumentId) -> Option<&'a dyn Instrument>,
+        ts_init_delta: Option<UnixNanos>,
+    ) -> Option<Self> {
+        lookup(instrument_id).map(|instrument| Self::from_instrument(instrument, ts_init_delta))
+    }
 }
 
 #[pymethods]
 impl TradeTickDataWrangler {
+    /// Creates a new wrangler.
+    ///
+    /// Ticks decoded without a `ts_init` have it set to zero by default. When a
+    /// `ts_init_delta` is given, those ticks instead have `ts_init` set to
+    /// `ts_event + ts_init_delta` (a delta of zero sets `ts_init = ts_event`).
     #[new]
-    fn py_new(instrument_id: &str, price_precision: u8, size_precision: u8) -> PyResult<Self> {
+    #[pyo3(signature=(instrument_id, price_precision, size_precision, ts_init_delta=None))]
+    fn py_new(
+        instrument_id: &str,
+        price_precision: u8,
+        size_precision: u8,
+        ts_init_delta: Option<UnixNanos>,
+    ) -> PyResult<Self> {
         let instrument_id = InstrumentId::from_str(instrument_id)
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
-        let metadata = TradeTick::get_metadata(&instrument_id, price_precision, size_precision);
-
-        Ok(Self {
+        Ok(Self::new(
             instrument_id,
             price_precision,
             size_precision,
-            metadata,
-        })
+            ts_init_delta,
+        ))
     }
 
     #[getter]
@@ -61,7 +241,103 @@ impl TradeTickDataWrangler {
         self.size_precision
     }
 
-    fn process_record_batches_bytes(&self, _py: Python, data: &[u8]) -> PyResult<Vec<TradeTick>> {
+    #[getter]
+    fn ts_init_delta(&self) -> Option<UnixNanos> {
+        self.ts_init_delta
+    }
+
+    /// The number of invalid ticks dropped by the last call to
+    /// `process_record_batches_bytes` or `process_record_batches_grouped`.
+    #[getter]
+    fn sanitized_count(&self) -> usize {
+        self.sanitized_count
+    }
+
+    /// Process the given Arrow IPC `data` into trade ticks, sorted by `ts_event`.
+    ///
+    /// Ticks with equal timestamps are returned in the order they were read.
+    ///
+    /// Ticks with an out of range price (such as a NaN in the source data), or a
+    /// non-positive size, are invalid. The `invalid` policy is one of 'raise' (the
+    /// default, raising a `ValueError` if there are any), 'keep' (returning them
+    /// unchanged as before they were validated), or 'drop' (dropping them, with the
+    /// number dropped reported by `sanitized_count`).
+    ///
+    /// If a `progress` callable is given, it is called after each record batch is
+    /// decoded with `(batch_index, rows_so_far)`. Any exception it raises aborts
+    /// the processing and is propagated.
+    #[pyo3(signature=(data, invalid="raise", progress=None))]
+    fn process_record_batches_bytes(
+        &mut self,
+        py: Python,
+        data: &[u8],
+        invalid: &str,
+        progress: Option<PyObject>,
+    ) -> PyResult<Vec<TradeTick>> {
+        let policy = InvalidTradePolicy::from_str(invalid)?;
+        self.sanitized_count = 0;
+
+        // The GIL is held throughout (via `py`), so the callback can be called directly
+        let on_batch = |batch_index: usize, rows_so_far: usize| match &progress {
+            Some(callback) => callback.call1(py, (batch_index, rows_so_far)).map(|_| ()),
+            None => Ok(()),
+        };
+        let mut ticks = decode_ipc_bytes(data, &self.metadata, on_batch)?;
+
+        if let Some(ts_init_delta) = self.ts_init_delta {
+            fill_missing_ts_init(&mut ticks, ts_init_delta);
+        }
+
+        self.sanitized_count = apply_invalid_policy(&mut ticks, policy)?;
+
+        sort_by_ts_event(&mut ticks);
+        Ok(ticks)
+    }
+
+    /// Process the given Arrow IPC `data` lazily, returning an iterator which decodes
+    /// one record batch per iteration and yields the list of its trade ticks.
+    ///
+    /// This avoids holding every tick in memory at once for large files. The ticks
+    /// are otherwise processed as by `process_record_batches_bytes`, except that they
+    /// are yielded in the order they were read rather than sorted.
+    #[pyo3(signature=(data, invalid="raise"))]
+    fn process_record_batches_stream(
+        &self,
+        data: Vec<u8>,
+        invalid: &str,
+    ) -> PyResult<TradeTickStream> {
+        TradeTickStream::new(
+            data,
+            self.metadata.clone(),
+            self.ts_init_delta,
+            InvalidTradePolicy::from_str(invalid)?,
+        )
+    }
+
+    /// Process the given Arrow IPC `data` into trade ticks grouped by instrument ID,
+    /// with each group sorted by `ts_event` (ticks with equal timestamps in the order
+    /// they were read).
+    ///
+    /// When an `instrument_id` is given, each record batch is first filtered down
+    /// to the rows for that instrument, so that only matching rows are decoded.
+    ///
+    /// Invalid ticks are handled by the `invalid` policy, as by
+    /// `process_record_batches_bytes`.
+    #[pyo3(signature=(data, instrument_id=None, invalid="raise"))]
+    fn process_record_batches_grouped(
+        &mut self,
+        _py: Python,
+        data: &[u8],
+        instrument_id: Option<&str>,
+        invalid: &str,
+    ) -> PyResult<HashMap<String, Vec<TradeTick>>> {
+        let policy = InvalidTradePolicy::from_str(invalid)?;
+        self.sanitized_count = 0;
+        let instrument_id = instrument_id
+            .map(InstrumentId::from_str)
+            .transpose()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
         // Create a StreamReader (from Arrow IPC)
         let cursor = Cursor::new(data);
         let reader = match StreamReader::try_new(cursor, None) {
@@ -69,19 +345,495 @@ impl TradeTickDataWrangler {
             Err(e) => return Err(PyValueError::new_err(e.to_string())),
         };
 
-        let mut ticks = Vec::new();
+        let mut groups: HashMap<InstrumentId, Vec<TradeTick>> = HashMap::new();
 
-        // Read the record batches
+        // Read the record batches, bucketing the ticks as they are decoded
         for maybe_batch in reader {
             let record_batch = match maybe_batch {
                 Ok(record_batch) => record_batch,
                 Err(e) => return Err(PyValueError::new_err(e.to_string())),
             };
 
-            let batch_deltas = TradeTick::decode_batch(&self.metadata, record_batch);
-            ticks.extend(batch_deltas);
+            let record_batch = match &instrument_id {
+                Some(instrument_id) => filter_instrument_id(&record_batch, instrument_id)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?,
+                None => record_batch,
+            };
+
+            let mut batch_ticks = TradeTick::decode_batch(&self.metadata, record_batch);
+            if let Some(ts_init_delta) = self.ts_init_delta {
+                fill_missing_ts_init(&mut batch_ticks, ts_init_delta);
+            }
+            self.sanitized_count += apply_invalid_policy(&mut batch_ticks, policy)?;
+            group_by_instrument(batch_ticks, &mut groups);
         }
 
-        Ok(ticks)
+        Ok(groups
+            .into_iter()
+            .map(|(instrument_id, mut ticks)| {
+                sort_by_ts_event(&mut ticks);
+                (instrument_id.to_string(), ticks)
+            })
+            .collect())
+    }
+}
+
+/// Provides an iterator over the trade ticks of an Arrow IPC stream, yielding the
+/// ticks of one record batch per iteration.
+#[pyclass]
+pub struct TradeTickStream {
+    reader: StreamReader<Cursor<Vec<u8>>>,
+    metadata: HashMap<String, String>,
+    ts_init_delta: Option<UnixNanos>,
+    invalid_policy: InvalidTradePolicy,
+    sanitized_count: usize,
+}
+
+impl TradeTickStream {
+    fn new(
+        data: Vec<u8>,
+        metadata: HashMap<String, String>,
+        ts_init_delta: Option<UnixNanos>,
+        invalid_policy: InvalidTradePolicy,
+    ) -> PyResult<Self> {
+        // Create a StreamReader (from Arrow IPC)
+        let reader = StreamReader::try_new(Cursor::new(data), None)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self {
+            reader,
+            metadata,
+            ts_init_delta,
+            invalid_policy,
+            sanitized_count: 0,
+        })
+    }
+
+    /// Decodes the next record batch, returning `None` once the stream is exhausted.
+    fn next_batch(&mut self) -> PyResult<Option<Vec<TradeTick>>> {
+        let record_batch = match self.reader.next() {
+            Some(Ok(record_batch)) => record_batch,
+            Some(Err(e)) => return Err(PyValueError::new_err(e.to_string())),
+            None => return Ok(None),
+        };
+
+        let mut ticks = TradeTick::decode_batch(&self.metadata, record_batch);
+        if let Some(ts_init_delta) = self.ts_init_delta {
+            fill_missing_ts_init(&mut ticks, ts_init_delta);
+        }
+
+        self.sanitized_count += apply_invalid_policy(&mut ticks, self.invalid_policy)?;
+
+        Ok(Some(ticks))
+    }
+}
+
+#[pymethods]
+impl TradeTickStream {
+    /// The number of invalid ticks dropped so far.
+    #[getter]
+    fn sanitized_count(&self) -> usize {
+        self.sanitized_count
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Each iteration returns the trade ticks of the next record batch.
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<Vec<TradeTick>>> {
+        slf.next_batch()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::{
+        array::{Int64Array, StringArray, UInt64Array, UInt8Array},
+        ipc::writer::StreamWriter,
+        record_batch::RecordBatch,
+    };
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::trade_id::TradeId,
+        instruments::options_contract::OptionsContract,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+    use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+
+    fn tick(ts_event: UnixNanos, ts_init: UnixNanos) -> TradeTick {
+        tick_for("AAPL.NASDAQ", ts_event, ts_init)
+    }
+
+    fn tick_for(instrument_id: &str, ts_event: UnixNanos, ts_init: UnixNanos) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from_str(instrument_id).unwrap(),
+            price: Price::new(100.10, 2),
+            size: Quantity::new(1000.0, 0),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new("1"),
+            ts_event,
+            ts_init,
+        }
+    }
+
+    #[test]
+    fn test_from_instrument_derives_precisions() {
+        let instrument = OptionsContract::default();
+
+        let wrangler = TradeTickDataWrangler::from_instrument(&instrument, None);
+
+        assert_eq!(wrangler.instrument_id, instrument.id);
+        assert_eq!(wrangler.price_precision, 2);
+        assert_eq!(wrangler.size_precision, 0);
+        assert_eq!(
+            wrangler.metadata,
+            TradeTick::get_metadata(&instrument.id, 2, 0)
+        );
+    }
+
+    #[test]
+    fn test_from_instrument_lookup() {
+        let instrument = OptionsContract::default();
+        let instruments: HashMap<InstrumentId, &dyn Instrument> =
+            HashMap::from([(instrument.id, &instrument as &dyn Instrument)]);
+
+        let wrangler = TradeTickDataWrangler::from_instrument_lookup(
+            &instrument.id,
+            |id| instruments.get(id).copied(),
+            Some(0),
+        )
+        .unwrap();
+        let unknown = TradeTickDataWrangler::from_instrument_lookup(
+            &InstrumentId::from_str("MSFT.XNAS").unwrap(),
+            |id| instruments.get(id).copied(),
+            None,
+        );
+
+        assert_eq!(wrangler.price_precision, instrument.price_precision);
+        assert_eq!(wrangler.size_precision, 0);
+        assert_eq!(wrangler.ts_init_delta, Some(0));
+        assert!(unknown.is_none());
+    }
+
+    #[test]
+    fn test_fill_missing_ts_init_with_zero_delta() {
+        let mut ticks = vec![tick(10, 0)];
+
+        fill_missing_ts_init(&mut ticks, 0);
+
+        assert_eq!(ticks[0].ts_init, 10);
+    }
+
+    #[test]
+    fn test_fill_missing_ts_init_with_delta() {
+        let mut ticks = vec![tick(10, 0)];
+
+        fill_missing_ts_init(&mut ticks, 5);
+
+        assert_eq!(ticks[0].ts_init, 15);
+    }
+
+    #[test]
+    fn test_fill_missing_ts_init_keeps_existing_values() {
+        let mut ticks = vec![tick(10, 12)];
+
+        fill_missing_ts_init(&mut ticks, 5);
+
+        assert_eq!(ticks[0].ts_init, 12);
+    }
+
+    fn ipc_bytes(metadata: &HashMap<String, String>, batches: &[Vec<TradeTick>]) -> Vec<u8> {
+        let record_batches: Vec<_> = batches
+            .iter()
+            .map(|ticks| TradeTick::encode_batch(metadata, ticks))
+            .collect();
+        let mut writer = StreamWriter::try_new(Vec::new(), &record_batches[0].schema()).unwrap();
+        for record_batch in &record_batches {
+            writer.write(record_batch).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_decode_ipc_bytes_calls_back_per_batch() {
+        let metadata =
+            TradeTick::get_metadata(&InstrumentId::from_str("AAPL.NASDAQ").unwrap(), 2, 0);
+        let data = ipc_bytes(
+            &metadata,
+            &[
+                vec![tick(1, 1), tick(2, 2)],
+                vec![tick(3, 3)],
+                vec![tick(4, 4), tick(5, 5), tick(6, 6)],
+            ],
+        );
+        let mut calls = Vec::new();
+
+        let ticks = decode_ipc_bytes(&data, &metadata, |batch_index, rows_so_far| {
+            calls.push((batch_index, rows_so_far));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(ticks.len(), 6);
+        assert_eq!(calls, vec![(0, 2), (1, 3), (2, 6)]);
+    }
+
+    #[test]
+    fn test_stream_matches_eager_decoding() {
+        let metadata =
+            TradeTick::get_metadata(&InstrumentId::from_str("AAPL.NASDAQ").unwrap(), 2, 0);
+        let data = ipc_bytes(
+            &metadata,
+            &[
+                vec![tick(1, 1), tick(2, 2)],
+                vec![tick(3, 3)],
+                vec![tick(4, 4), tick(5, 5), tick(6, 6)],
+            ],
+        );
+        let eager = decode_ipc_bytes(&data, &metadata, |_, _| Ok(())).unwrap();
+        let mut stream =
+            TradeTickStream::new(data, metadata, None, InvalidTradePolicy::Keep).unwrap();
+
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_batch().unwrap() {
+            batches.push(batch);
+        }
+
+        assert_eq!(
+            batches.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 1, 3]
+        );
+        assert_eq!(batches.concat(), eager);
+        assert!(stream.next_batch().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stream_fills_missing_ts_init() {
+        let metadata =
+            TradeTick::get_metadata(&InstrumentId::from_str("AAPL.NASDAQ").unwrap(), 2, 0);
+        let data = ipc_bytes(&metadata, &[vec![tick(10, 0), tick(20, 21)]]);
+        let mut stream =
+            TradeTickStream::new(data, metadata, Some(5), InvalidTradePolicy::Keep).unwrap();
+
+        let ticks = stream.next_batch().unwrap().unwrap();
+
+        assert_eq!(ticks[0].ts_init, 15);
+        assert_eq!(ticks[1].ts_init, 21);
+    }
+
+    #[test]
+    fn test_stream_with_invalid_data() {
+        let result = TradeTickStream::new(
+            vec![0, 1, 2, 3],
+            HashMap::new(),
+            None,
+            InvalidTradePolicy::Keep,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_ipc_bytes_aborts_on_callback_error() {
+        let metadata =
+            TradeTick::get_metadata(&InstrumentId::from_str("AAPL.NASDAQ").unwrap(), 2, 0);
+        let data = ipc_bytes(&metadata, &[vec![tick(1, 1)], vec![tick(2, 2)]]);
+        let mut call_count = 0;
+
+        let result = decode_ipc_bytes(&data, &metadata, |_, _| {
+            call_count += 1;
+            Err(PyValueError::new_err("cancelled"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(call_count, 1);
+    }
+
+    /// Returns Arrow IPC bytes of trade ticks with the given raw `prices`, as written
+    /// by a producer rather than encoded from valid ticks.
+    fn ipc_bytes_with_raw_prices(metadata: &HashMap<String, String>, prices: Vec<i64>) -> Vec<u8> {
+        let len = prices.len();
+        let ts: Vec<u64> = (1..=len as u64).collect();
+        let record_batch = RecordBatch::try_new(
+            TradeTick::get_schema(metadata.clone()),
+            vec![
+                Arc::new(Int64Array::from(prices)),
+                Arc::new(UInt64Array::from(vec![1_000_000_000_000; len])),
+                Arc::new(UInt8Array::from(vec![1; len])),
+                Arc::new(StringArray::from(vec!["1"; len])),
+                Arc::new(UInt64Array::from(ts.clone())),
+                Arc::new(UInt64Array::from(ts)),
+            ],
+        )
+        .unwrap();
+        let mut writer = StreamWriter::try_new(Vec::new(), &record_batch.schema()).unwrap();
+        writer.write(&record_batch).unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_sanitize_trades_with_out_of_range_source_prices() {
+        let metadata =
+            TradeTick::get_metadata(&InstrumentId::from_str("AAPL.NASDAQ").unwrap(), 2, 0);
+        // NumPy casts NaN (and -inf) to `i64::MIN`, and +inf saturates to `i64::MAX`
+        let data = ipc_bytes_with_raw_prices(
+            &metadata,
+            vec![
+                Price::new(100.10, 2).raw,
+                i64::MIN,
+                i64::MAX,
+                Price::max(2).raw + 1,
+                Price::new(100.20, 2).raw,
+            ],
+        );
+        let mut ticks = decode_ipc_bytes(&data, &metadata, |_, _| Ok(())).unwrap();
+
+        let sanitized_count = sanitize_trades(&mut ticks);
+
+        assert_eq!(sanitized_count, 3);
+        assert_eq!(
+            ticks.iter().map(|tick| tick.ts_event).collect::<Vec<_>>(),
+            vec![1, 5]
+        );
+    }
+
+    #[test]
+    fn test_apply_invalid_policy() {
+        let mut zero_size = tick(2, 2);
+        zero_size.size = Quantity::from_raw(0, 0);
+        let ticks = vec![tick(1, 1), zero_size];
+
+        let mut kept = ticks.clone();
+        let mut raised = ticks.clone();
+        let mut dropped = ticks;
+
+        assert_eq!(
+            apply_invalid_policy(&mut kept, InvalidTradePolicy::Keep).unwrap(),
+            0
+        );
+        assert!(apply_invalid_policy(&mut raised, InvalidTradePolicy::Raise).is_err());
+        assert_eq!(
+            apply_invalid_policy(&mut dropped, InvalidTradePolicy::Drop).unwrap(),
+            1
+        );
+        assert_eq!(kept.len(), 2);
+        assert_eq!(dropped.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_trade_policy_from_str() {
+        assert_eq!(
+            InvalidTradePolicy::from_str("keep").unwrap(),
+            InvalidTradePolicy::Keep
+        );
+        assert_eq!(
+            InvalidTradePolicy::from_str("RAISE").unwrap(),
+            InvalidTradePolicy::Raise
+        );
+        assert_eq!(
+            InvalidTradePolicy::from_str("drop").unwrap(),
+            InvalidTradePolicy::Drop
+        );
+        assert!(InvalidTradePolicy::from_str("ignore").is_err());
+        assert_eq!(InvalidTradePolicy::default(), InvalidTradePolicy::Raise);
+    }
+
+    #[test]
+    fn test_sanitize_trades_with_zero_size() {
+        let mut zero_size = tick(2, 2);
+        zero_size.size = Quantity::from_raw(0, 0);
+        let mut ticks = vec![tick(1, 1), zero_size];
+
+        let sanitized_count = sanitize_trades(&mut ticks);
+
+        assert_eq!(sanitized_count, 1);
+        assert_eq!(ticks.len(), 1);
+    }
+
+    #[test]
+    fn test_sanitize_trades_when_all_valid() {
+        let mut ticks = vec![tick(1, 1), tick(2, 2)];
+
+        assert_eq!(sanitize_trades(&mut ticks), 0);
+        assert_eq!(ticks.len(), 2);
+    }
+
+    #[test]
+    fn test_group_by_instrument() {
+        let ticks = vec![
+            tick_for("AAPL.NASDAQ", 1, 1),
+            tick_for("MSFT.NASDAQ", 2, 2),
+            tick_for("AAPL.NASDAQ", 3, 3),
+            tick_for("MSFT.NASDAQ", 4, 4),
+            tick_for("AAPL.NASDAQ", 5, 5),
+        ];
+        let mut groups = HashMap::new();
+
+        group_by_instrument(ticks, &mut groups);
+
+        let aapl = &groups[&InstrumentId::from_str("AAPL.NASDAQ").unwrap()];
+        let msft = &groups[&InstrumentId::from_str("MSFT.NASDAQ").unwrap()];
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            aapl.iter().map(|t| t.ts_event).collect::<Vec<_>>(),
+            vec![1, 3, 5]
+        );
+        assert_eq!(
+            msft.iter().map(|t| t.ts_event).collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+    }
+
+    #[test]
+    fn test_group_by_instrument_extends_existing_groups() {
+        let mut groups = HashMap::new();
+
+        group_by_instrument(vec![tick_for("AAPL.NASDAQ", 1, 1)], &mut groups);
+        group_by_instrument(vec![tick_for("AAPL.NASDAQ", 2, 2)], &mut groups);
+
+        let aapl = &groups[&InstrumentId::from_str("AAPL.NASDAQ").unwrap()];
+        assert_eq!(
+            aapl.iter().map(|t| t.ts_event).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_ts_event_keeps_input_order_for_equal_timestamps() {
+        let with_trade_id = |ts_event: UnixNanos, trade_id: &str| TradeTick {
+            trade_id: TradeId::new(trade_id),
+            ..tick(ts_event, ts_event)
+        };
+        let mut ticks = vec![
+            with_trade_id(2, "C"),
+            with_trade_id(1, "B"),
+            with_trade_id(2, "A"),
+            with_trade_id(1, "D"),
+            with_trade_id(2, "B"),
+        ];
+
+        sort_by_ts_event(&mut ticks);
+
+        assert_eq!(
+            ticks
+                .iter()
+                .map(|t| (t.ts_event, t.trade_id.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "B".to_string()),
+                (1, "D".to_string()),
+                (2, "C".to_string()),
+                (2, "A".to_string()),
+                (2, "B".to_string()),
+            ]
+        );
     }
 }
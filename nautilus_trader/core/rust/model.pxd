@@ -354,6 +354,17 @@ cdef extern from "../includes/model.h":
     cdef struct InstrumentId_t:
         Symbol_t symbol;
         Venue_t venue;
+        # The cached `SYMBOL.VENUE` string, so that formatting the ID is a single borrow.
+        #
+        # This interns one additional string per distinct instrument ID (the symbol and venue are
+        # already interned), which lives for the duration of the process. This is negligible for
+        # typical instrument universes, though will grow with very high-cardinality sets of IDs
+        # (such as many option chains).
+        #
+        # The fields are private so the value is always derived from the symbol and venue. Note
+        # this field changes the FFI layout of `InstrumentId_t`, so C and Cython code must be
+        # rebuilt against the regenerated headers.
+        char* value;
 
     cdef struct Price_t:
         int64_t raw;